@@ -3,24 +3,45 @@ use std::{any::Any, rc::Rc};
 use alloc::{boxed::Box, vec::Vec};
 use crate::runtime::values::ValueType;
 
+// `Statment::Statment` wraps a nested statement, the name predates the lint.
+#[allow(clippy::enum_variant_names)]
 #[derive(Debug, Clone)]
 pub enum Statment {
     Program(Program),
     Statment(Box<Statment>),
+    BlockStatement(Box<BlockStatement>),
+    ThrowStatement(Box<ThrowStatement>),
+    ReturnStatement(Box<ReturnStatement>),
+    BreakStatement(BreakStatement),
+    ImportStatement(Box<ImportStatement>),
+    ExportStatement(Box<ExportStatement>),
+    TryStatement(Box<TryStatement>),
     Identifier(Identifier),
     VarDeclaration(Box<VarDeclaration>),
     AssignmentExpr(Box<AssignmentExpr>),
     BinaryExpr(Box<BinaryExpr>),
+    UnaryExpr(Box<UnaryExpr>),
+    ConditionalExpr(Box<ConditionalExpr>),
+    MatchExpr(Box<MatchExpr>),
     MemberExpr(Box<MemberExpr>),
+    SliceExpr(Box<SliceExpr>),
     CallExpr(Box<CallExpr>),
     FuncDeclaration(Box<FuncDeclaration>),
     ArrayLiteral(Box<ArrayLiteral>),
+    SpreadElement(Box<SpreadElement>),
+    ArrayPattern(Box<ArrayPattern>),
+    ObjectPattern(Box<ObjectPattern>),
+    AssignPattern(Box<AssignPattern>),
 
     PropertyLiteral(Box<PropertyLiteral>),
     ObjectLiteral(ObjectLiteral),
     NumericLiteral(NumericLiteral),
+    IntegerLiteral(IntegerLiteral),
+    BigIntLiteral(BigIntLiteral),
     NullLiteral(NullLiteral),
+    BooleanLiteral(BooleanLiteral),
     StringLiteral(StringLiteral),
+    InterpolatedString(Box<InterpolatedString>),
 
     None()
 }
@@ -29,6 +50,53 @@ pub struct Program {
     pub body: Vec<Statment>
 }
 
+#[derive(Debug, Clone)]
+pub struct BlockStatement {
+    pub kind: String,
+    pub body: Vec<Statment>
+}
+
+#[derive(Debug, Clone)]
+pub struct ThrowStatement {
+    pub kind: String,
+    pub argument: Statment
+}
+#[derive(Debug, Clone)]
+pub struct ReturnStatement {
+    pub kind: String,
+    pub argument: Statment
+}
+#[derive(Debug, Clone)]
+pub struct BreakStatement {
+    pub kind: String
+}
+// `import { name, other as alias } from "source"` fills `names` with (exported name, local name) pairs,
+// `import * as namespace from "source"` leaves them empty and sets `namespace`.
+#[derive(Debug, Clone)]
+pub struct ImportStatement {
+    pub kind: String,
+    pub source: String,
+    pub names: Vec<(String, String)>,
+    pub namespace: String,
+    pub line: usize,
+    pub column: usize
+}
+// A `let`, `const` or `fn` declaration whose names are part of the exports of its module.
+#[derive(Debug, Clone)]
+pub struct ExportStatement {
+    pub kind: String,
+    pub declaration: Statment
+}
+// `param`, `handler` and `finalizer` are Statment::None() when the clause or its binding is left out.
+#[derive(Debug, Clone)]
+pub struct TryStatement {
+    pub kind: String,
+    pub block: Statment,
+    pub param: Statment,
+    pub handler: Statment,
+    pub finalizer: Statment
+}
+
 #[derive(Debug, Clone)]
 pub struct Expression {
     pub kind: String
@@ -42,6 +110,56 @@ pub struct BinaryExpr {
     pub operator: String
 }
 
+#[derive(Debug, Clone)]
+pub struct ConditionalExpr {
+    pub kind: String,
+    pub test: Statment,
+    pub consequent: Statment,
+    pub alternate: Statment
+}
+#[derive(Debug, Clone)]
+pub struct MatchExpr {
+    pub kind: String,
+    pub subject: Statment,
+    pub arms: Vec<MatchArm>,
+    // Set for a `switch` of the vapor_script dialect, `break` leaves the arm and no arm has to match.
+    pub switch: bool
+}
+// `guard` is Statment::None() for arms without an `if` guard.
+#[derive(Debug, Clone)]
+pub struct MatchArm {
+    pub kind: String,
+    pub pattern: MatchPattern,
+    pub guard: Statment,
+    pub body: Statment
+}
+#[derive(Debug, Clone)]
+pub enum MatchPattern {
+    Wildcard,
+    Binding(String),
+    Literal(Statment),
+    Range(Statment, Statment, bool),
+    Array(Vec<MatchPattern>, Option<String>),
+    Object(Vec<(String, MatchPattern)>),
+    Type(String, Box<MatchPattern>),
+    Or(Vec<MatchPattern>)
+}
+// A type written after `:`, after `->` or before a function name. `Any` stands for a missing annotation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypeAnnotation {
+    Any,
+    Named(String),
+    Array(Box<TypeAnnotation>),
+    Function(Vec<TypeAnnotation>, Box<TypeAnnotation>),
+    Optional(Box<TypeAnnotation>)
+}
+#[derive(Debug, Clone)]
+pub struct UnaryExpr {
+    pub kind: String,
+    pub operator: String,
+    pub argument: Statment
+}
+
 #[derive(Debug, Clone)]
 pub struct Identifier {
     pub kind: String,
@@ -53,15 +171,33 @@ pub struct NumericLiteral {
     pub value: f64
 }
 #[derive(Debug, Clone)]
+pub struct IntegerLiteral {
+    pub kind: String,
+    pub value: i64
+}
+#[derive(Debug, Clone)]
+pub struct BigIntLiteral {
+    pub kind: String,
+    // The digits of the literal in base 10.
+    pub value: String
+}
+#[derive(Debug, Clone)]
 pub struct NullLiteral {
     pub kind: String,
     pub value: String
 }
 #[derive(Debug, Clone)]
+pub struct BooleanLiteral {
+    pub kind: String,
+    pub value: bool
+}
+#[derive(Debug, Clone)]
 pub struct VarDeclaration {
     pub kind: String,
     pub constant: bool,
     pub identifier: String,
+    pub pattern: Statment,
+    pub annotation: TypeAnnotation,
     pub value: Statment
 }
 #[derive(Debug, Clone)]
@@ -73,35 +209,79 @@ pub struct AssignmentExpr {
 #[derive(Debug, Clone)]
 pub struct PropertyLiteral {
     pub kind: String,
-    pub key: String,
-    pub value: Statment
+    pub key: Statment,
+    pub value: Statment,
+    pub computed: bool
 }
 #[derive(Debug, Clone)]
 pub struct ObjectLiteral {
     pub kind: String,
-    pub properties: Vec<PropertyLiteral>
+    pub properties: Vec<Statment>
+}
+#[derive(Debug, Clone)]
+pub struct SpreadElement {
+    pub kind: String,
+    pub argument: Statment
+}
+
+// Destructuring targets, `rest` is Statment::None() when the pattern has no `...rest` part.
+#[derive(Debug, Clone)]
+pub struct ArrayPattern {
+    pub kind: String,
+    pub elements: Vec<Statment>,
+    pub rest: Statment
+}
+#[derive(Debug, Clone)]
+pub struct ObjectPattern {
+    pub kind: String,
+    pub properties: Vec<PropertyLiteral>,
+    pub rest: Statment
+}
+#[derive(Debug, Clone)]
+pub struct AssignPattern {
+    pub kind: String,
+    pub target: Statment,
+    pub default: Statment
 }
 #[derive(Debug, Clone)]
 pub struct CallExpr {
     pub kind: String,
     pub args: Vec<Statment>,
-    pub caller: Statment
+    pub caller: Statment,
+    pub optional: bool,
+    // Position of the opening paranthesis, reported in stack traces.
+    pub line: usize,
+    pub column: usize
 }
 #[derive(Debug, Clone)]
 pub struct MemberExpr {
     pub kind: String,
     pub object: Statment,
     pub property: Statment,
-    pub computed: bool
+    pub computed: bool,
+    pub optional: bool
+}
+#[derive(Debug, Clone)]
+pub struct SliceExpr {
+    pub kind: String,
+    pub object: Statment,
+    pub start: Statment,
+    pub end: Statment,
+    pub optional: bool
 }
 #[derive(Debug, Clone)]
 pub struct FuncDeclaration {
     pub kind: String,
-    pub parameters: Vec<String>,
+    pub parameters: Vec<Statment>,
+    // One entry per parameter.
+    pub param_types: Vec<TypeAnnotation>,
+    pub return_type: TypeAnnotation,
     pub name: String,
     pub body: Vec<Statment>,
     pub sync: bool,
-    pub arrow: bool
+    pub arrow: bool,
+    // Declared with `v_entry`, the function the program starts in.
+    pub entry: bool
 }
 #[derive(Debug, Clone)]
 pub struct StringLiteral {
@@ -109,6 +289,11 @@ pub struct StringLiteral {
     pub value: String
 }
 #[derive(Debug, Clone)]
+pub struct InterpolatedString {
+    pub kind: String,
+    pub parts: Vec<Statment>
+}
+#[derive(Debug, Clone)]
 pub struct ArrayLiteral {
     pub kind: String,
     pub contents: Vec<Statment>
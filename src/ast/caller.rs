@@ -1,28 +1,45 @@
 use crate::{lexer::lexer::{Token, TokenBase}, logger::logger::{log, LogLevel}, utils::shift};
 
-use super::{ast::{CallExpr, MemberExpr, Statment}, parser::{expect, parse_assignment_expr, parse_expr, parse_primary_expr}};
+use super::{ast::{CallExpr, MemberExpr, SliceExpr, Statment}, parser::{expect, parse_assignment_expr, parse_expr, parse_primary_expr, parse_spread, reject_hole}};
 
+// Parses a primary expression followed by any chain of `.key`, `[key]`, `[start:end]`, `(args)`
+// and their optional `?.key`, `?.[key]`, `?.(args)` forms.
 pub fn parse_call_member(tokens: &mut Vec<Token>) -> Statment {
-    let member = parse_member_expr(tokens);
-    if (tokens[0].base == TokenBase::OpenParen) {
-        return parse_call_expr(tokens, &member);
+    let mut expr = parse_primary_expr(tokens);
+
+    loop {
+        // `(` and `[` at the start of a line would silently turn the previous line into a call or an index.
+        if (tokens[0].newline && (tokens[0].base == TokenBase::OpenParen || tokens[0].base == TokenBase::OpenBracket)) {
+            log(LogLevel::Error, format!("A line cannot start with '{}' (line {}, column {}), it would continue the expression on the line before. End that line with a semicolon (;) or move the '{}' up.", tokens[0].value, tokens[0].line, tokens[0].column, tokens[0].value).as_str());
+        }
+        expr = match tokens[0].base {
+            TokenBase::OpenParen => parse_call_expr(tokens, &expr, false),
+            TokenBase::Dot | TokenBase::OpenBracket => parse_member_expr(tokens, expr, false),
+            TokenBase::QuestionDot => {
+                shift(tokens);
+                match tokens[0].base {
+                    TokenBase::OpenParen => parse_call_expr(tokens, &expr, true),
+                    TokenBase::OpenBracket => parse_member_expr(tokens, expr, true),
+                    _ => parse_member_property(tokens, expr, true)
+                }
+            }
+            _ => break
+        };
     }
 
-    return member;
+    expr
 }
 
-pub fn parse_call_expr(tokens: &mut Vec<Token>, caller: &Statment) -> Statment {
-    let mut call: Statment = Statment::CallExpr(Box::new(CallExpr {
+pub fn parse_call_expr(tokens: &mut Vec<Token>, caller: &Statment, optional: bool) -> Statment {
+    let (line, column): (usize, usize) = (tokens[0].line, tokens[0].column);
+    Statment::CallExpr(Box::new(CallExpr {
         kind: "CallExpr".to_string(),
         caller: caller.clone(),
-        args: parse_args(tokens)
-    }));
-
-    if (tokens[0].base == TokenBase::OpenParen) {
-        call = parse_call_expr(tokens, &call);
-    }
-
-    call
+        args: parse_args(tokens),
+        optional,
+        line,
+        column
+    }))
 }
 
 pub fn parse_args(tokens: &mut Vec<Token>) -> Vec<Statment> {
@@ -37,47 +54,84 @@ pub fn parse_args(tokens: &mut Vec<Token>) -> Vec<Statment> {
     expect(tokens, TokenBase::CloseParen, "Expected a closing paranthesis while parsing caller arguments.".to_string()); 
     args   
 }
+// Arguments are separated by commas, may end with a trailing comma and may be spread with `...args`.
 fn parse_args_list(tokens: &mut Vec<Token>) -> Vec<Statment> {
-    let mut args: Vec<Statment> = vec![parse_assignment_expr(tokens)];
-    
-    while (tokens[0].base == TokenBase::Comma && shift(tokens).is_some()) {
-        args.push(parse_assignment_expr(tokens));
+    let mut args: Vec<Statment> = Vec::new();
+
+    while (tokens[0].base != TokenBase::EoF && tokens[0].base != TokenBase::CloseParen) {
+        reject_hole(tokens, "an argument");
+        if (tokens[0].base == TokenBase::Ellipsis) {
+            args.push(parse_spread(tokens));
+        } else {
+            args.push(parse_assignment_expr(tokens));
+        }
+
+        if (tokens[0].base != TokenBase::CloseParen) {
+            expect(tokens, TokenBase::Comma, "Expected a comma or closing paranthesis between arguments.".to_string());
+        }
     }
 
     args
 }
 
-pub fn parse_member_expr(tokens: &mut Vec<Token>) -> Statment {
-    let mut obj = parse_primary_expr(tokens);
-
-    while (tokens[0].base == TokenBase::Dot || tokens[0].base == TokenBase::OpenBracket) {
-        let mut property: Statment;
-        let mut computed: bool;
-        let operator = shift(tokens).unwrap_or(Token { value: "EndOfFile".to_string(), base: TokenBase::EoF });
+pub fn parse_member_expr(tokens: &mut Vec<Token>, object: Statment, optional: bool) -> Statment {
+    let operator = shift(tokens).unwrap_or(Token { value: "EndOfFile".to_string(), base: TokenBase::EoF, line: 0, column: 0, newline: false });
 
-        if (operator.base == TokenBase::Dot) {
-            computed = false;
-            property = parse_primary_expr(tokens);
+    if (operator.base == TokenBase::Dot) {
+        return parse_member_property(tokens, object, optional);
+    }
 
-            match property {
-                Statment::Identifier(_) => {}
-                _ => {
-                    log(LogLevel::Error, "Cannot use the dot operator without the right hand expression being an identifier.");
-                }
-            }
-        } else {
-            computed = true;
-            property = parse_expr(tokens);
-            expect(tokens, TokenBase::CloseBracket, "Expected a closing bracket while trying to access an object key.".to_string());
+    if (tokens[0].base != TokenBase::Colon && !starts_expr(&tokens[0])) {
+        log(LogLevel::Error, format!("Expected an index or a slice inside the brackets (line {}, column {}).", tokens[0].line, tokens[0].column).as_str());
+    }
+    let property = if (tokens[0].base == TokenBase::Colon) { Statment::None() } else { parse_expr(tokens) };
+    if (tokens[0].base == TokenBase::Colon) {
+        shift(tokens);
+        if (tokens[0].base != TokenBase::CloseBracket && !starts_expr(&tokens[0])) {
+            log(LogLevel::Error, format!("Expected the end of the slice or a closing bracket, a slice is written [start:end] (line {}, column {}).", tokens[0].line, tokens[0].column).as_str());
         }
-
-        obj = Statment::MemberExpr(Box::new(MemberExpr {
-            kind: "MemberExpr".to_string(),
-            object: obj,
-            property,
-            computed
+        let end = if (tokens[0].base == TokenBase::CloseBracket) { Statment::None() } else { parse_expr(tokens) };
+        expect(tokens, TokenBase::CloseBracket, "Expected a closing bracket after a slice, a slice is written [start:end].".to_string());
+
+        return Statment::SliceExpr(Box::new(SliceExpr {
+            kind: "SliceExpr".to_string(),
+            object,
+            start: property,
+            end,
+            optional
         }));
     }
+    expect(tokens, TokenBase::CloseBracket, "Expected a closing bracket while trying to access an object key.".to_string());
+
+    Statment::MemberExpr(Box::new(MemberExpr {
+        kind: "MemberExpr".to_string(),
+        object,
+        property,
+        computed: true,
+        optional
+    }))
+}
 
-    obj
-}
\ No newline at end of file
+// Tokens that only close or separate, none of them can begin the bound of a slice.
+fn starts_expr(token: &Token) -> bool {
+    !matches!(token.base, TokenBase::Colon | TokenBase::Comma | TokenBase::Semicolon | TokenBase::CloseParen | TokenBase::CloseBrace | TokenBase::CloseBracket | TokenBase::EoF)
+}
+
+fn parse_member_property(tokens: &mut Vec<Token>, object: Statment, optional: bool) -> Statment {
+    let property = parse_primary_expr(tokens);
+
+    match property {
+        Statment::Identifier(_) => {}
+        _ => {
+            log(LogLevel::Error, "Cannot use the dot operator without the right hand expression being an identifier.");
+        }
+    }
+
+    Statment::MemberExpr(Box::new(MemberExpr {
+        kind: "MemberExpr".to_string(),
+        object,
+        property,
+        computed: false,
+        optional
+    }))
+}
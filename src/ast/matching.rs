@@ -0,0 +1,225 @@
+// Author: ContentGamer
+// match expressions: `match value { pattern if guard => body, ... }`
+
+use alloc::{boxed::Box, string::{String, ToString}, vec::Vec};
+
+use crate::{lexer::lexer::{Token, TokenBase}, logger::logger::{log, LogLevel}, utils::shift};
+
+use super::{ast::{BlockStatement, MatchArm, MatchExpr, MatchPattern, Statment}, parser::{expect, parse_expr, parse_primary_expr, parse_stmt, parse_unary_expr}, types::is_type_name};
+
+
+pub fn parse_match(tokens: &mut Vec<Token>) -> Statment {
+    shift(tokens);
+    let subject: Statment = parse_expr(tokens);
+    expect(tokens, TokenBase::OpenBrace, "Expected an opening brace after the match subject.".to_string());
+
+    let mut arms: Vec<MatchArm> = Vec::new();
+    while (tokens[0].base != TokenBase::EoF && tokens[0].base != TokenBase::CloseBrace) {
+        let pattern: MatchPattern = parse_pattern(tokens);
+        let guard: Statment = if (tokens[0].base == TokenBase::IfCondition) {
+            shift(tokens);
+            parse_expr(tokens)
+        } else {
+            Statment::None()
+        };
+        expect(tokens, TokenBase::FatArrow, "Expected => after a match pattern.".to_string());
+        let body: Statment = parse_expr(tokens);
+
+        arms.push(MatchArm {
+            kind: "MatchArm".to_string(),
+            pattern,
+            guard,
+            body
+        });
+        while (tokens[0].base == TokenBase::Comma || tokens[0].base == TokenBase::Semicolon) {
+            shift(tokens);
+        }
+    }
+    expect(tokens, TokenBase::CloseBrace, "Expected a closing brace at the end of a match expression.".to_string());
+
+    Statment::MatchExpr(Box::new(MatchExpr {
+        kind: "MatchExpr".to_string(),
+        subject,
+        arms,
+        switch: false
+    }))
+}
+
+// switch (value) { is(pattern): statements ... } of the vapor_script dialect, turned into a match
+// whose arms are blocks. An arm runs until the next `is(...)`, `break` leaves it early and
+// `is(ndef)` matches anything.
+pub fn parse_switch(tokens: &mut Vec<Token>) -> Statment {
+    shift(tokens);
+    let subject: Statment = parse_expr(tokens);
+    expect(tokens, TokenBase::OpenBrace, "Expected an opening brace after the switch subject.".to_string());
+
+    let mut arms: Vec<MatchArm> = Vec::new();
+    while (tokens[0].base != TokenBase::EoF && tokens[0].base != TokenBase::CloseBrace) {
+        if (!is_case_start(tokens)) {
+            log(LogLevel::Error, format!("Expected is(...) to start a switch case, found '{}' (line {}, column {}).", tokens[0].value, tokens[0].line, tokens[0].column).as_str());
+        }
+        shift(tokens);
+        shift(tokens);
+        let pattern: MatchPattern = if (tokens[0].value == "ndef" && tokens[1].base == TokenBase::CloseParen) {
+            shift(tokens);
+            MatchPattern::Wildcard
+        } else {
+            parse_pattern(tokens)
+        };
+        expect(tokens, TokenBase::CloseParen, "Expected a closing paranthesis after the switch case.".to_string());
+        expect(tokens, TokenBase::Colon, "Expected a colon after is(...) in a switch.".to_string());
+
+        let mut body: Vec<Statment> = Vec::new();
+        loop {
+            while (tokens[0].base == TokenBase::Semicolon) {
+                shift(tokens);
+            }
+            if (tokens[0].base == TokenBase::EoF || tokens[0].base == TokenBase::CloseBrace || is_case_start(tokens)) {
+                break;
+            }
+            body.push(parse_stmt(tokens));
+        }
+
+        arms.push(MatchArm {
+            kind: "MatchArm".to_string(),
+            pattern,
+            guard: Statment::None(),
+            body: Statment::BlockStatement(Box::new(BlockStatement {
+                kind: "BlockStatement".to_string(),
+                body
+            }))
+        });
+    }
+    expect(tokens, TokenBase::CloseBrace, "Expected a closing brace at the end of a switch.".to_string());
+
+    Statment::MatchExpr(Box::new(MatchExpr {
+        kind: "MatchExpr".to_string(),
+        subject,
+        arms,
+        switch: true
+    }))
+}
+
+fn is_case_start(tokens: &[Token]) -> bool {
+    tokens[0].base == TokenBase::Identifier && tokens[0].value == "is" && tokens[1].base == TokenBase::OpenParen
+}
+
+// pattern := single ('|' single)*
+fn parse_pattern(tokens: &mut Vec<Token>) -> MatchPattern {
+    let mut alternatives: Vec<MatchPattern> = vec![parse_single_pattern(tokens)];
+    while (tokens[0].base == TokenBase::Pipe) {
+        shift(tokens);
+        alternatives.push(parse_single_pattern(tokens));
+    }
+
+    if (alternatives.len() == 1) {
+        return alternatives.remove(0);
+    }
+    MatchPattern::Or(alternatives)
+}
+
+fn parse_single_pattern(tokens: &mut Vec<Token>) -> MatchPattern {
+    let token: Token = tokens[0].clone();
+    match token.base {
+        TokenBase::Identifier if token.value == "_" => {
+            shift(tokens);
+            MatchPattern::Wildcard
+        }
+        TokenBase::Identifier | TokenBase::Null if tokens[1].base == TokenBase::OpenParen => {
+            shift(tokens);
+            // `type(pattern)` takes the same names as annotations, `str(s)` and `string(s)` alike.
+            if (token.base == TokenBase::Identifier && !is_type_name(&token.value)) {
+                log(LogLevel::Error, format!("Unknown type '{}' in a match pattern (line {}, column {}), expected a type such as str, num, int, float, bool, obj or arr.", token.value, token.line, token.column).as_str());
+            }
+            shift(tokens);
+            let inner: MatchPattern = parse_pattern(tokens);
+            expect(tokens, TokenBase::CloseParen, "Expected a closing paranthesis after a type pattern.".to_string());
+            MatchPattern::Type(token.value, Box::new(inner))
+        }
+        TokenBase::Identifier => {
+            shift(tokens);
+            MatchPattern::Binding(token.value)
+        }
+        TokenBase::OpenBracket => parse_array_pattern(tokens),
+        TokenBase::OpenBrace => parse_object_pattern(tokens),
+        TokenBase::Number | TokenBase::BigInt | TokenBase::String | TokenBase::Boolean | TokenBase::Null | TokenBase::Minus => {
+            let literal: Statment = parse_literal(tokens);
+            match tokens[0].base {
+                TokenBase::DoubleDot | TokenBase::DoubleDotEquals => {
+                    let inclusive: bool = shift(tokens).map(|token| token.base == TokenBase::DoubleDotEquals).unwrap_or(false);
+                    MatchPattern::Range(literal, parse_literal(tokens), inclusive)
+                }
+                _ => MatchPattern::Literal(literal)
+            }
+        }
+        _ => {
+            log(LogLevel::Error, format!("Expected a match pattern, found '{}' (line {}, column {}).", token.value, token.line, token.column).as_str());
+            MatchPattern::Wildcard
+        }
+    }
+}
+
+fn parse_literal(tokens: &mut Vec<Token>) -> Statment {
+    let token: &Token = &tokens[0];
+    match token.base {
+        TokenBase::Minus => parse_unary_expr(tokens),
+        TokenBase::Number | TokenBase::BigInt | TokenBase::String | TokenBase::Boolean | TokenBase::Null => parse_primary_expr(tokens),
+        _ => {
+            log(LogLevel::Error, format!("Expected a literal in a match pattern, found '{}' (line {}, column {}).", token.value, token.line, token.column).as_str());
+            Statment::None()
+        }
+    }
+}
+
+// [first, second, ...rest] where `...rest` (or `..._`) accepts any number of remaining elements.
+fn parse_array_pattern(tokens: &mut Vec<Token>) -> MatchPattern {
+    shift(tokens);
+    let mut elements: Vec<MatchPattern> = Vec::new();
+    let mut rest: Option<String> = None;
+
+    while (tokens[0].base != TokenBase::EoF && tokens[0].base != TokenBase::CloseBracket) {
+        if (tokens[0].base == TokenBase::Ellipsis) {
+            shift(tokens);
+            rest = Some(expect(tokens, TokenBase::Identifier, "Expected a name after ... in an array pattern.".to_string()).value);
+            if (tokens[0].base != TokenBase::CloseBracket) {
+                log(LogLevel::Error, format!("The ...rest of an array pattern must come last (line {}, column {}).", tokens[0].line, tokens[0].column).as_str());
+            }
+            break;
+        }
+        elements.push(parse_pattern(tokens));
+        if (tokens[0].base != TokenBase::CloseBracket) {
+            expect(tokens, TokenBase::Comma, "Expected a comma or closing bracket in an array pattern.".to_string());
+        }
+    }
+    expect(tokens, TokenBase::CloseBracket, "Expected a closing bracket at the end of an array pattern.".to_string());
+
+    MatchPattern::Array(elements, rest)
+}
+
+// { key, key: pattern, "key": pattern } matches objects that have every listed key.
+fn parse_object_pattern(tokens: &mut Vec<Token>) -> MatchPattern {
+    shift(tokens);
+    let mut properties: Vec<(String, MatchPattern)> = Vec::new();
+
+    while (tokens[0].base != TokenBase::EoF && tokens[0].base != TokenBase::CloseBrace) {
+        let key: Token = shift(tokens).unwrap_or(Token { value: "EndOfFile".to_string(), base: TokenBase::EoF, line: 0, column: 0, newline: false });
+        if (key.base != TokenBase::Identifier && key.base != TokenBase::String) {
+            log(LogLevel::Error, format!("Expected a key in an object pattern, found '{}' (line {}, column {}).", key.value, key.line, key.column).as_str());
+        }
+
+        let pattern: MatchPattern = if (tokens[0].base == TokenBase::Colon) {
+            shift(tokens);
+            parse_pattern(tokens)
+        } else {
+            MatchPattern::Binding(key.value.clone())
+        };
+        properties.push((key.value, pattern));
+
+        if (tokens[0].base != TokenBase::CloseBrace) {
+            expect(tokens, TokenBase::Comma, "Expected a comma or closing brace in an object pattern.".to_string());
+        }
+    }
+    expect(tokens, TokenBase::CloseBrace, "Expected a closing brace at the end of an object pattern.".to_string());
+
+    MatchPattern::Object(properties)
+}
@@ -1,3 +1,8 @@
+#[allow(clippy::module_inception)]
 pub mod ast;
 pub mod parser;
-pub mod caller;
\ No newline at end of file
+pub mod caller;
+pub mod matching;
+pub mod patterns;
+pub mod modules;
+pub mod types;
\ No newline at end of file
@@ -0,0 +1,114 @@
+// Author: ContentGamer
+// Modules: `import { name } from "./file.va"`, `import * as name from "./file.va"` and `export` declarations
+
+use alloc::{boxed::Box, string::{String, ToString}, vec::Vec};
+
+use crate::{lexer::lexer::{Token, TokenBase}, logger::logger::{log, LogLevel}, utils::shift};
+
+use super::{ast::{ExportStatement, ImportStatement, Statment}, parser::{expect, is_typed_fn, parse_declaration, parse_fn, parse_typed_fn}};
+
+// `from` and `as` are only words inside an import, they stay usable as names everywhere else.
+fn expect_word(tokens: &mut Vec<Token>, word: &str, err: &str) {
+    let token: Token = shift(tokens).unwrap_or(Token { value: "EndOfFile".to_string(), base: TokenBase::EoF, line: 0, column: 0, newline: false });
+    if (token.base != TokenBase::Identifier || token.value != word) {
+        log(LogLevel::Error, format!("{} (line {}, column {})", err, token.line, token.column).as_str());
+    }
+}
+
+fn is_word(tokens: &[Token], word: &str) -> bool {
+    tokens[0].base == TokenBase::Identifier && tokens[0].value == word
+}
+
+pub fn parse_import(tokens: &mut Vec<Token>) -> Statment {
+    let import: Token = shift(tokens).unwrap_or(Token { value: "EndOfFile".to_string(), base: TokenBase::EoF, line: 0, column: 0, newline: false });
+    let mut names: Vec<(String, String)> = Vec::new();
+    let mut namespace: String = String::new();
+
+    if (tokens[0].base == TokenBase::Asterick) {
+        shift(tokens);
+        expect_word(tokens, "as", "Expected 'as' and a name after 'import *'.");
+        namespace = expect(tokens, TokenBase::Identifier, "Expected the name of the namespace after 'import * as'.".to_string()).value;
+    } else {
+        expect(tokens, TokenBase::OpenBrace, "Expected '{' or '*' after import.".to_string());
+        while (tokens[0].base != TokenBase::EoF && tokens[0].base != TokenBase::CloseBrace) {
+            let name: String = expect(tokens, TokenBase::Identifier, "Expected the name of an export inside 'import { ... }'.".to_string()).value;
+            let local: String = if (is_word(tokens, "as")) {
+                shift(tokens);
+                expect(tokens, TokenBase::Identifier, format!("Expected a name after '{} as'.", name)).value
+            } else {
+                name.clone()
+            };
+            if (names.iter().any(|(_, other)| *other == local)) {
+                log(LogLevel::Error, format!("'{}' is imported twice (line {}, column {}).", local, import.line, import.column).as_str());
+            }
+            names.push((name, local));
+
+            if (tokens[0].base != TokenBase::CloseBrace) {
+                expect(tokens, TokenBase::Comma, "Expected a comma between imported names.".to_string());
+            }
+        }
+        expect(tokens, TokenBase::CloseBrace, "Expected '}' to close the imported names.".to_string());
+    }
+
+    expect_word(tokens, "from", "Expected 'from' and the module to import from.");
+    let source: String = expect(tokens, TokenBase::String, "Expected the module to import from as a plain string such as \"./math.va\".".to_string()).value;
+
+    Statment::ImportStatement(Box::new(ImportStatement {
+        kind: "ImportStatement".to_string(),
+        source,
+        names,
+        namespace,
+        line: import.line,
+        column: import.column
+    }))
+}
+
+pub fn parse_export(tokens: &mut Vec<Token>) -> Statment {
+    let export: Token = shift(tokens).unwrap_or(Token { value: "EndOfFile".to_string(), base: TokenBase::EoF, line: 0, column: 0, newline: false });
+    let declaration: Statment = match tokens[0].base {
+        _ if is_typed_fn(tokens) => parse_typed_fn(tokens),
+        TokenBase::Let | TokenBase::Const => parse_declaration(tokens),
+        TokenBase::Function | TokenBase::Entry => parse_fn(tokens),
+        _ => {
+            log(LogLevel::Error, format!("Expected a let, const or fn declaration after export (line {}, column {}).", export.line, export.column).as_str());
+            Statment::None()
+        }
+    };
+    if let Statment::FuncDeclaration(func) = &declaration && func.name.is_empty() {
+        log(LogLevel::Error, format!("An exported function needs a name (line {}, column {}).", export.line, export.column).as_str());
+    }
+
+    Statment::ExportStatement(Box::new(ExportStatement {
+        kind: "ExportStatement".to_string(),
+        declaration
+    }))
+}
+
+// The names an export makes available to importers.
+pub fn exported_names(export: &ExportStatement) -> Vec<String> {
+    match &export.declaration {
+        Statment::VarDeclaration(declaration) if matches!(declaration.pattern, Statment::None()) => vec![declaration.identifier.clone()],
+        Statment::VarDeclaration(declaration) => pattern_names(&declaration.pattern),
+        Statment::FuncDeclaration(func) => vec![func.name.clone()],
+        _ => Vec::new()
+    }
+}
+
+// Every name a destructuring pattern binds, in the order they appear.
+pub fn pattern_names(pattern: &Statment) -> Vec<String> {
+    match pattern {
+        Statment::Identifier(iden) => vec![iden.symbol.clone()],
+        Statment::SpreadElement(spread) => pattern_names(&spread.argument),
+        Statment::AssignPattern(assign) => pattern_names(&assign.target),
+        Statment::ArrayPattern(arr) => arr.elements.iter().chain([&arr.rest]).flat_map(pattern_names).collect(),
+        Statment::ObjectPattern(obj) => {
+            let mut names: Vec<String> = obj.properties.iter().flat_map(|prop| match (&prop.value, &prop.key) {
+                (Statment::None(), Statment::StringLiteral(key)) => vec![key.value.clone()],
+                (value, _) => pattern_names(value)
+            }).collect();
+            names.extend(pattern_names(&obj.rest));
+            names
+        }
+        _ => Vec::new()
+    }
+}
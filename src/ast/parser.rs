@@ -5,7 +5,7 @@ use std::{any::Any, rc::Rc};
 
 use alloc::{boxed::Box, string::{String, ToString}, vec::{self, Vec}};
 
-use super::{ast::{AssignmentExpr, BinaryExpr, Expression, FuncDeclaration, Identifier, NullLiteral, NumericLiteral, ObjectLiteral, Program, PropertyLiteral, Statment, StringLiteral, VarDeclaration}, caller::{parse_args, parse_call_member}};
+use super::{ast::{AssignmentExpr, BinaryExpr, Expression, FuncDeclaration, Identifier, NullLiteral, NumericLiteral, IntegerLiteral, BigIntLiteral, ObjectLiteral, Program, PropertyLiteral, Statment, StringLiteral, VarDeclaration, InterpolatedString, BooleanLiteral, ConditionalExpr, UnaryExpr, SpreadElement, AssignPattern, BlockStatement, ThrowStatement, TryStatement, ReturnStatement, BreakStatement, TypeAnnotation}, caller::parse_call_member, matching::{parse_match, parse_switch}, modules::{parse_export, parse_import}, patterns::{assigns_optional_chain, to_params, to_pattern}, types::{is_arrow, is_type_name, parse_type, try_parse_type}};
 use crate::{ast::ast::ArrayLiteral, funcs::print, lexer::lexer::{tokenize, Token, TokenBase}, logger::logger::{log, LogLevel}, runtime::{interpreter::evaluate, values::ValueType}, utils::shift};
 
 pub fn expect(tokens: &mut Vec<Token>, base: TokenBase, err: String) -> Token {
@@ -13,25 +13,174 @@ pub fn expect(tokens: &mut Vec<Token>, base: TokenBase, err: String) -> Token {
     match prev {
         Some(token) => {
             if (token.base != base) {
-                log(LogLevel::Error, err.as_str())
+                log(LogLevel::Error, format!("{} (line {}, column {})", err, token.line, token.column).as_str())
             }
             token
         },
         None => {
             log(LogLevel::Error, err.as_str());
-            Token { value: "NotAToken".to_string(), base: TokenBase::EoF }
+            Token { value: "NotAToken".to_string(), base: TokenBase::EoF, line: 0, column: 0, newline: false }
         }
     }
 }
 
+// Parses statements until a closing brace or the end of the file, empty `;` statements are skipped.
+pub fn parse_body(tokens: &mut Vec<Token>) -> Vec<Statment> {
+    let mut body: Vec<Statment> = Vec::new();
+
+    loop {
+        while (tokens[0].base == TokenBase::Semicolon) {
+            shift(tokens);
+        }
+        if (tokens[0].base == TokenBase::EoF || tokens[0].base == TokenBase::CloseBrace) {
+            break;
+        }
+        body.push(parse_stmt(tokens));
+    }
+
+    body
+}
+
 pub fn parse_stmt(tokens: &mut Vec<Token>) -> Statment {
-    match tokens[0].base {
+    let stmt: Statment = match tokens[0].base {
+        _ if is_typed_fn(tokens) => parse_typed_fn(tokens),
         TokenBase::Let => parse_declaration(tokens),
         TokenBase::Const => parse_declaration(tokens),
-        TokenBase::Function => parse_fn(tokens),
+        TokenBase::Function | TokenBase::Entry => parse_fn(tokens),
+        TokenBase::Throw => parse_throw(tokens),
+        TokenBase::Return => parse_return(tokens),
+        TokenBase::BreakLoop => parse_break(tokens),
+        TokenBase::Try => parse_try(tokens),
+        TokenBase::Import => parse_import(tokens),
+        TokenBase::Export => parse_export(tokens),
 
         TokenBase::ForLoop => parse_for_loop(tokens),
         _ => parse_expr(tokens)
+    };
+    end_stmt(tokens);
+
+    stmt
+}
+
+pub fn parse_throw(tokens: &mut Vec<Token>) -> Statment {
+    let throw: Token = shift(tokens).unwrap_or(Token { value: "EndOfFile".to_string(), base: TokenBase::EoF, line: 0, column: 0, newline: false });
+    if (tokens[0].newline || tokens[0].base == TokenBase::Semicolon || tokens[0].base == TokenBase::EoF) {
+        log(LogLevel::Error, format!("Expected a value to throw on the same line as throw (line {}, column {}).", throw.line, throw.column).as_str());
+    }
+
+    Statment::ThrowStatement(Box::new(ThrowStatement {
+        kind: "ThrowStatement".to_string(),
+        argument: parse_expr(tokens)
+    }))
+}
+
+// `return` leaves the function it is written in, a value on the same line is what the call gives back.
+pub fn parse_return(tokens: &mut Vec<Token>) -> Statment {
+    shift(tokens);
+    let argument: Statment = if (tokens[0].newline || matches!(tokens[0].base, TokenBase::Semicolon | TokenBase::CloseBrace | TokenBase::EoF)) {
+        Statment::NullLiteral(NullLiteral {
+            kind: "NullLiteral".to_string(),
+            value: "null".to_string()
+        })
+    } else {
+        parse_expr(tokens)
+    };
+
+    Statment::ReturnStatement(Box::new(ReturnStatement {
+        kind: "ReturnStatement".to_string(),
+        argument
+    }))
+}
+
+pub fn parse_break(tokens: &mut Vec<Token>) -> Statment {
+    shift(tokens);
+    Statment::BreakStatement(BreakStatement {
+        kind: "BreakStatement".to_string()
+    })
+}
+
+// if (test) { ... } else if (test) { ... } else { ... } evaluates to the block that ran, or to null
+// when no block ran. It is a conditional expression whose branches are always blocks.
+pub fn parse_if(tokens: &mut Vec<Token>) -> Statment {
+    shift(tokens);
+    let test: Statment = parse_expr(tokens);
+    let consequent: Statment = parse_clause_block(tokens, "the if condition");
+    let alternate: Statment = if (tokens[0].base == TokenBase::ElseCondition) {
+        shift(tokens);
+        match tokens[0].base {
+            TokenBase::IfCondition => parse_if(tokens),
+            _ => parse_clause_block(tokens, "else")
+        }
+    } else {
+        Statment::NullLiteral(NullLiteral {
+            kind: "NullLiteral".to_string(),
+            value: "null".to_string()
+        })
+    };
+
+    Statment::ConditionalExpr(Box::new(ConditionalExpr {
+        kind: "ConditionalExpr".to_string(),
+        test,
+        consequent,
+        alternate
+    }))
+}
+
+// try { ... } catch (e) { ... } finally { ... }, the catch binding may be a destructuring pattern
+// or left out as `catch { ... }`, and at least one of catch or finally has to follow the try block.
+pub fn parse_try(tokens: &mut Vec<Token>) -> Statment {
+    let try_token: Token = shift(tokens).unwrap_or(Token { value: "EndOfFile".to_string(), base: TokenBase::EoF, line: 0, column: 0, newline: false });
+    let block: Statment = parse_clause_block(tokens, "try");
+    let mut param: Statment = Statment::None();
+    let mut handler: Statment = Statment::None();
+    let mut finalizer: Statment = Statment::None();
+
+    if (tokens[0].base == TokenBase::Catch) {
+        shift(tokens);
+        if (tokens[0].base == TokenBase::OpenParen) {
+            shift(tokens);
+            param = to_pattern(parse_pattern(tokens), false);
+            expect(tokens, TokenBase::CloseParen, "Expected a closing paranthesis after the catch binding.".to_string());
+        }
+        handler = parse_clause_block(tokens, "catch");
+    }
+    if (tokens[0].base == TokenBase::Finally) {
+        shift(tokens);
+        finalizer = parse_clause_block(tokens, "finally");
+    }
+    if (matches!(handler, Statment::None()) && matches!(finalizer, Statment::None())) {
+        log(LogLevel::Error, format!("A try block needs a catch or a finally clause (line {}, column {}).", try_token.line, try_token.column).as_str());
+    }
+
+    Statment::TryStatement(Box::new(TryStatement {
+        kind: "TryStatement".to_string(),
+        block,
+        param,
+        handler,
+        finalizer
+    }))
+}
+
+// The braces after try, catch and finally always hold a block, even when they look like an object.
+fn parse_clause_block(tokens: &mut Vec<Token>, clause: &str) -> Statment {
+    if (tokens[0].base != TokenBase::OpenBrace) {
+        log(LogLevel::Error, format!("Expected an opening brace after {} (line {}, column {}).", clause, tokens[0].line, tokens[0].column).as_str());
+    }
+    parse_block(tokens)
+}
+
+// A statement ends at a semicolon, a line break, a closing brace or the end of the file.
+fn end_stmt(tokens: &mut Vec<Token>) {
+    let next: &Token = &tokens[0];
+    match next.base {
+        TokenBase::Semicolon => {
+            shift(tokens);
+        }
+        TokenBase::EoF | TokenBase::CloseBrace => {}
+        _ if next.newline => {}
+        _ => {
+            log(LogLevel::Error, format!("Expected a semicolon (;) or a new line after the statement, found '{}' (line {}, column {}).", next.value, next.line, next.column).as_str());
+        }
     }
 }
 
@@ -49,101 +198,195 @@ pub fn parse_for_loop(tokens: &mut Vec<Token>) -> Statment {
     Statment::None()
 }
 
+// `fn name(...) { ... }`, or `v_entry name(...) { ... }` for the entry point of a vapor_script program.
 pub fn parse_fn(tokens: &mut Vec<Token>) -> Statment {
-    shift(tokens);
+    let entry: bool = shift(tokens).is_some_and(|token| token.base == TokenBase::Entry);
     let name: String = expect(tokens, TokenBase::Identifier, "Expected a function identifier while parsing.".to_string()).value;
-    let args: Vec<Statment> = parse_args(tokens);
-    let mut params: Vec<String> = Vec::new();
+    let mut func: Statment = parse_fn_body(tokens, name, TypeAnnotation::Any);
+    if let Statment::FuncDeclaration(declaration) = &mut func {
+        declaration.entry = entry;
+    }
+    func
+}
 
-    for arg in args {
-        match arg {
-            Statment::Identifier(iden) => {
-                params.push(iden.symbol);
-            }
-            _ => {
-                log(LogLevel::Info, format!("Got Statment: {:#?}", arg).as_str());
-                log(LogLevel::Error, "Expected an identifier inside the function declaration parameters.");
-            }
-        };
+// A statement starting with a type followed by a name and `(` on the same line declares a
+// function with that return type, `str greet(name: str) { ... }`. The few tokens needed are
+// copied so a failed look ahead leaves the statement untouched.
+pub fn is_typed_fn(tokens: &[Token]) -> bool {
+    let starts_type: bool = match tokens[0].base {
+        TokenBase::Identifier => is_type_name(&tokens[0].value),
+        TokenBase::Function => tokens[1].base == TokenBase::OpenParen,
+        _ => false
+    };
+    if (!starts_type) {
+        return false;
     }
 
-    expect(tokens, TokenBase::OpenBrace, "Expected an FnBody while parsing the function.".to_string());
-    let mut body: Vec<Statment> = Vec::new();
+    let mut probe: Vec<Token> = tokens.iter().take_while(|token| token.base != TokenBase::EoF).take(32).cloned().collect();
+    probe.push(Token { value: "EndOfFile".to_string(), base: TokenBase::EoF, line: 0, column: 0, newline: false });
+    try_parse_type(&mut probe).is_some()
+        && probe[0].base == TokenBase::Identifier && !probe[0].newline
+        && probe[1].base == TokenBase::OpenParen
+}
 
-    while (tokens[0].base != TokenBase::EoF && tokens[0].base != TokenBase::CloseBrace) {
-        body.push(parse_stmt(tokens));
+pub fn parse_typed_fn(tokens: &mut Vec<Token>) -> Statment {
+    let return_type: TypeAnnotation = parse_type(tokens);
+    let name: String = expect(tokens, TokenBase::Identifier, "Expected a function identifier after the return type.".to_string()).value;
+    parse_fn_body(tokens, name, return_type)
+}
+
+// `(a, b: int, c: str = "x", ...rest: arr<num>)`, each parameter may carry a type before its default.
+fn parse_params(tokens: &mut Vec<Token>) -> (Vec<Statment>, Vec<TypeAnnotation>) {
+    expect(tokens, TokenBase::OpenParen, "Expected an open paranthesis before the function parameters.".to_string());
+    let mut params: Vec<Statment> = Vec::new();
+    let mut types: Vec<TypeAnnotation> = Vec::new();
+
+    while (tokens[0].base != TokenBase::EoF && tokens[0].base != TokenBase::CloseParen) {
+        let spread: bool = tokens[0].base == TokenBase::Ellipsis;
+        if (spread) {
+            shift(tokens);
+        }
+        let mut param: Statment = parse_pattern(tokens);
+        types.push(if (tokens[0].base == TokenBase::Colon) {
+            shift(tokens);
+            parse_type(tokens)
+        } else {
+            TypeAnnotation::Any
+        });
+        if (tokens[0].base == TokenBase::Equals) {
+            shift(tokens);
+            param = Statment::AssignmentExpr(Box::new(AssignmentExpr {
+                kind: "AssignmentExpr".to_string(),
+                assigne: param,
+                value: parse_expr(tokens)
+            }));
+        }
+        if (spread) {
+            param = Statment::SpreadElement(Box::new(SpreadElement {
+                kind: "SpreadElement".to_string(),
+                argument: param
+            }));
+        }
+        params.push(param);
+
+        if (tokens[0].base != TokenBase::CloseParen) {
+            expect(tokens, TokenBase::Comma, "Expected a comma or closing paranthesis between parameters.".to_string());
+        }
     }
+    expect(tokens, TokenBase::CloseParen, "Expected a closing paranthesis after the function parameters.".to_string());
+
+    (to_params(params), types)
+}
 
+// Parses the parameter list and body that follow a function name, shared by `fn` declarations and object methods.
+// A return type may be written before the name (`return_type`) or after the parameters as `-> type`.
+pub fn parse_fn_body(tokens: &mut Vec<Token>, name: String, return_type: TypeAnnotation) -> Statment {
+    let (params, param_types): (Vec<Statment>, Vec<TypeAnnotation>) = parse_params(tokens);
+    let mut return_type: TypeAnnotation = return_type;
+    if (is_arrow(tokens)) {
+        if (return_type != TypeAnnotation::Any) {
+            log(LogLevel::Error, format!("The function '{}' already has a return type before its name (line {}, column {}).", name, tokens[0].line, tokens[0].column).as_str());
+        }
+        shift(tokens);
+        shift(tokens);
+        return_type = parse_type(tokens);
+    }
+
+    expect(tokens, TokenBase::OpenBrace, "Expected an FnBody while parsing the function.".to_string());
+    let body: Vec<Statment> = parse_body(tokens);
     expect(tokens, TokenBase::CloseBrace, "Expected a closing brace while building a function body.".to_string());
     let func: Statment = Statment::FuncDeclaration(Box::new(FuncDeclaration {
         kind: "FuncDeclaration".to_string(),
         body,
         parameters: params,
+        param_types,
+        return_type,
         name,
         sync: false,
-        arrow: false
+        arrow: false,
+        entry: false
     }));
 
     func
 }
 
 pub fn parse_declaration(tokens: &mut Vec<Token>) -> Statment {
-    let is_constant: bool = shift(tokens).unwrap_or(Token { value: "EndOfFile".to_string(), base: TokenBase::EoF }).base == TokenBase::Const;
-    let identifier: String = expect(tokens, TokenBase::Identifier, "Expected an identifier while building a variable declaration.".to_string()).value;
-    
-    if (tokens[0].base == TokenBase::Semicolon) {
+    let is_constant: bool = shift(tokens).unwrap_or(Token { value: "EndOfFile".to_string(), base: TokenBase::EoF, line: 0, column: 0, newline: false }).base == TokenBase::Const;
+    let (identifier, pattern): (String, Statment) = match tokens[0].base {
+        TokenBase::OpenBracket | TokenBase::OpenBrace => (String::new(), to_pattern(parse_pattern(tokens), false)),
+        _ => (expect(tokens, TokenBase::Identifier, "Expected an identifier while building a variable declaration.".to_string()).value, Statment::None())
+    };
+    let annotation: TypeAnnotation = if (tokens[0].base == TokenBase::Colon) {
         shift(tokens);
+        parse_type(tokens)
+    } else {
+        TypeAnnotation::Any
+    };
+    
+    if (tokens[0].base != TokenBase::Equals) {
         if (is_constant)
         {
             log(LogLevel::Error, "Must assign a value to a constant expression, No value was provided while searching.");
             return Statment::None();
         }
+        if (!matches!(pattern, Statment::None())) {
+            log(LogLevel::Error, "Must assign a value to a destructuring declaration, No value was provided while searching.");
+            return Statment::None();
+        }
 
         return Statment::VarDeclaration(Box::new(VarDeclaration {
             kind: "VarDeclaration".to_string(),
             identifier,
+            pattern,
+            annotation,
             constant: false,
             value: Statment::None()
         }));
     }
 
     expect(tokens, TokenBase::Equals, "Expected an equals token while declaring a variable.".to_string());
-    let declaration = Statment::VarDeclaration(Box::new(VarDeclaration {
+    Statment::VarDeclaration(Box::new(VarDeclaration {
         kind: "VarDeclaration".to_string(),
         identifier,
+        pattern,
+        annotation,
         constant: is_constant,
         value: parse_expr(tokens)
-    }));
-    expect(tokens, TokenBase::Semicolon, "Expected a semicolon (;) while building a variable declaration.".to_string());
-
-    declaration
+    }))
 }
 
 pub fn parse_expr(tokens: &mut Vec<Token>) -> Statment {
     parse_assignment_expr(tokens)
 }
 
-pub fn parse_array(tokens: &mut Vec<Token>) -> Statment {
-    if (tokens[0].base != TokenBase::OpenBracket) {
-        return parse_additive_expr(tokens);
+// A comma where an element should be, as in `[,]` or `f(a,,b)`, leaves a hole in the list.
+pub fn reject_hole(tokens: &[Token], what: &str) {
+    if (tokens[0].base == TokenBase::Comma) {
+        log(LogLevel::Error, format!("Expected {} before the comma (line {}, column {}).", what, tokens[0].line, tokens[0].column).as_str());
     }
+}
 
+// Parses `[a, b, ...rest]`, a trailing comma before the closing bracket is allowed.
+// `pattern` parses the elements as destructuring targets, see parse_pattern.
+pub fn parse_array(tokens: &mut Vec<Token>, pattern: bool) -> Statment {
     shift(tokens);
     let mut contents: Vec<Statment> = Vec::new();
 
     while (tokens[0].base != TokenBase::EoF && tokens[0].base != TokenBase::CloseBracket) {
-        let value = parse_expr(tokens);
+        reject_hole(tokens, "an array element");
+        if (tokens[0].base == TokenBase::Ellipsis) {
+            contents.push(parse_spread(tokens));
+        } else if (pattern) {
+            contents.push(parse_target(tokens));
+        } else {
+            contents.push(parse_expr(tokens));
+        }
 
-        if (tokens[0].base == TokenBase::Comma) {
-            shift(tokens);
-            contents.push(value);
-            continue;
-        } else if (tokens[0].base == TokenBase::CloseBracket) {
-            contents.push(value);
-            continue;
+        if (tokens[0].base != TokenBase::CloseBracket) {
+            expect(tokens, TokenBase::Comma, "Expected a comma or closing bracket between array elements.".to_string());
         }
     }
-    expect(tokens, TokenBase::CloseBracket, "Array litreal missing a closing bracket [.".to_string());
+    expect(tokens, TokenBase::CloseBracket, "Array litreal missing a closing bracket ].".to_string());
 
     Statment::ArrayLiteral(Box::new(ArrayLiteral {
         kind: "ArrayLiteral".to_string(),
@@ -151,42 +394,111 @@ pub fn parse_array(tokens: &mut Vec<Token>) -> Statment {
     }))
 }
 
-pub fn parse_object_expr(tokens: &mut Vec<Token>) -> Statment {
-    if (tokens[0].base != TokenBase::OpenBrace) {
-        return parse_array(tokens);
+// Decides whether a `{` opens an object literal or a block, the same rule applies at statement
+// and expression position. It is an object when the brace is followed by:
+//   `}`                                  the empty object `{}`
+//   `...`                                a spread `{ ...base }`
+//   a key and then `:` `,` or `}`        `{ a: 1 }`, `{ "a": 1 }`, `{ a, b }`
+//   `[...]` and then `:`                 a computed key `{ [k]: v }`
+//   an identifier, `(...)` and then `{`  a method `{ greet() { ... } }`
+// and a block otherwise, so `{ let a = 1; a }`, `{ print(a) }` and `{ a = 1 }` are blocks. A
+// `{ a = 1 }` pattern is only read where a pattern is expected, see parse_pattern.
+pub fn is_object_start(tokens: &[Token]) -> bool {
+    let peek = |indx: usize| tokens.get(indx).map(|token| token.base.clone()).unwrap_or(TokenBase::EoF);
+
+    match peek(1) {
+        TokenBase::CloseBrace | TokenBase::Ellipsis => true,
+        TokenBase::Identifier | TokenBase::String => match peek(2) {
+            TokenBase::Colon | TokenBase::Comma | TokenBase::CloseBrace => true,
+            TokenBase::OpenParen if peek(1) == TokenBase::Identifier => matching_close(tokens, 2).is_some_and(|close| peek(close + 1) == TokenBase::OpenBrace || is_arrow(&tokens[close + 1..])),
+            _ => false
+        },
+        TokenBase::OpenBracket => matching_close(tokens, 1).is_some_and(|close| peek(close + 1) == TokenBase::Colon),
+        _ => false
     }
+}
 
-    shift(tokens);
-    let mut properties: Vec<PropertyLiteral> = Vec::new();
+// A `{...}` or `[...]` followed by `=`, the left side of a destructuring assignment.
+fn is_pattern_start(tokens: &[Token]) -> bool {
+    matching_close(tokens, 0).is_some_and(|close| tokens.get(close + 1).is_some_and(|next| next.base == TokenBase::Equals))
+}
 
-    while (tokens[0].base != TokenBase::EoF && tokens[0].base != TokenBase::CloseBrace) {
-        let key = expect(tokens, TokenBase::Identifier, "Object literal was missing a key.".to_string()).value;
+// Parses a destructuring pattern in a `let`, a parameter, a catch binding or on the left of `=`.
+// Inside a pattern `{ a = 1 }` is an object with a default, everywhere else it is a block.
+pub fn parse_pattern(tokens: &mut Vec<Token>) -> Statment {
+    match tokens[0].base {
+        TokenBase::OpenBrace => parse_object_expr(tokens, true),
+        TokenBase::OpenBracket => parse_array(tokens, true),
+        _ => parse_conditional_expr(tokens)
+    }
+}
 
-        if (tokens[0].base == TokenBase::Comma) {
-            shift(tokens);
-            properties.push(PropertyLiteral {
-                kind: "PropertyLiteral".to_string(),
-                key,
-                value: Statment::None()
-            });
-            continue;
-        } else if (tokens[0].base == TokenBase::CloseBrace) {
-            properties.push(PropertyLiteral {
-                kind: "PropertyLiteral".to_string(),
-                key,
-                value: Statment::None()
-            });
-            continue;
+// An element or property value of a pattern, which may have a default after `=`.
+fn parse_target(tokens: &mut Vec<Token>) -> Statment {
+    let target: Statment = parse_pattern(tokens);
+    if (tokens[0].base != TokenBase::Equals) {
+        return target;
+    }
+    shift(tokens);
+    Statment::AssignmentExpr(Box::new(AssignmentExpr {
+        kind: "AssignmentExpr".to_string(),
+        assigne: target,
+        value: parse_expr(tokens)
+    }))
+}
+
+// Finds the bracket or paranthesis that closes the one at `open`.
+fn matching_close(tokens: &[Token], open: usize) -> Option<usize> {
+    let mut depth: usize = 0;
+    for (indx, token) in tokens.iter().enumerate().skip(open) {
+        match token.base {
+            TokenBase::OpenParen | TokenBase::OpenBracket | TokenBase::OpenBrace => depth += 1,
+            TokenBase::CloseParen | TokenBase::CloseBracket | TokenBase::CloseBrace => {
+                depth -= 1;
+                if (depth == 0) {
+                    return Some(indx);
+                }
+            }
+            TokenBase::EoF => return None,
+            _ => {}
         }
+    }
+    None
+}
 
-        expect(tokens, TokenBase::Colon, "Expected a colon while building an object property.".to_string());
-        let value = parse_expr(tokens);
+pub fn parse_block(tokens: &mut Vec<Token>) -> Statment {
+    shift(tokens);
+    let body: Vec<Statment> = parse_body(tokens);
+    expect(tokens, TokenBase::CloseBrace, "Expected a closing brace at the end of a block.".to_string());
+
+    Statment::BlockStatement(Box::new(BlockStatement {
+        kind: "BlockStatement".to_string(),
+        body
+    }))
+}
+
+pub fn parse_spread(tokens: &mut Vec<Token>) -> Statment {
+    shift(tokens);
+    Statment::SpreadElement(Box::new(SpreadElement {
+        kind: "SpreadElement".to_string(),
+        argument: parse_expr(tokens)
+    }))
+}
+
+// Parses `{ key: value }` where a key is an identifier, a string literal or a computed `[expr]`,
+// along with the `{ key }` shorthand, `{ ...other }` spreads and `{ key() { ... } }` methods.
+// `pattern` parses the values as destructuring targets and allows `{ key = default }`.
+pub fn parse_object_expr(tokens: &mut Vec<Token>, pattern: bool) -> Statment {
+    shift(tokens);
+    let mut properties: Vec<Statment> = Vec::new();
+
+    while (tokens[0].base != TokenBase::EoF && tokens[0].base != TokenBase::CloseBrace) {
+        if (tokens[0].base == TokenBase::Ellipsis) {
+            properties.push(parse_spread(tokens));
+        } else {
+            properties.push(parse_property(tokens, pattern));
+        }
 
-        properties.push(PropertyLiteral {
-            kind: "PropertyLiteral".to_string(),
-            value,
-            key
-        });
         if (tokens[0].base != TokenBase::CloseBrace) {
             expect(tokens, TokenBase::Comma, "Expected a comma or closing bracket while building an object property.".to_string());
         }
@@ -199,31 +511,93 @@ pub fn parse_object_expr(tokens: &mut Vec<Token>) -> Statment {
     })
 }
 
+fn parse_property(tokens: &mut Vec<Token>, pattern: bool) -> Statment {
+    let token: Token = tokens[0].clone();
+    let computed: bool = token.base == TokenBase::OpenBracket;
+    let key: Statment = match token.base {
+        TokenBase::OpenBracket => {
+            shift(tokens);
+            let key: Statment = parse_expr(tokens);
+            expect(tokens, TokenBase::CloseBracket, "Expected a closing bracket after a computed object key.".to_string());
+            key
+        }
+        TokenBase::Identifier | TokenBase::String => {
+            shift(tokens);
+            Statment::StringLiteral(StringLiteral {
+                kind: "StringLiteral".to_string(),
+                value: token.value.clone()
+            })
+        }
+        _ => {
+            log(LogLevel::Error, format!("Object literal was missing a key (line {}, column {}).", token.line, token.column).as_str());
+            Statment::None()
+        }
+    };
+
+    let value: Statment = match tokens[0].base {
+        TokenBase::Colon => {
+            shift(tokens);
+            if (pattern) { parse_target(tokens) } else { parse_expr(tokens) }
+        }
+        TokenBase::OpenParen if !computed => parse_fn_body(tokens, token.value.clone(), TypeAnnotation::Any),
+        TokenBase::Comma | TokenBase::CloseBrace if token.base == TokenBase::Identifier => Statment::None(),
+        TokenBase::Equals if pattern && token.base == TokenBase::Identifier => {
+            shift(tokens);
+            Statment::AssignPattern(Box::new(AssignPattern {
+                kind: "AssignPattern".to_string(),
+                target: Statment::Identifier(Identifier {
+                    kind: "Identifier".to_string(),
+                    symbol: token.value.clone()
+                }),
+                default: parse_expr(tokens)
+            }))
+        }
+        _ => {
+            let next: &Token = &tokens[0];
+            log(LogLevel::Error, format!("Expected a colon while building an object property (line {}, column {}).", next.line, next.column).as_str());
+            Statment::None()
+        }
+    };
+
+    Statment::PropertyLiteral(Box::new(PropertyLiteral {
+        kind: "PropertyLiteral".to_string(),
+        key,
+        value,
+        computed
+    }))
+}
+
 pub fn parse_assignment_expr(tokens: &mut Vec<Token>) -> Statment {
-    let left = parse_object_expr(tokens);
+    let left = parse_conditional_expr(tokens);
 
     if (tokens[0].base == TokenBase::Equals) {
-        shift(tokens);
+        let equals: Token = shift(tokens).unwrap();
         let value = parse_assignment_expr(tokens);
+        let assigne: Statment = match left {
+            Statment::ArrayLiteral(_) | Statment::ObjectLiteral(_) => to_pattern(left, true),
+            _ => left
+        };
+        if (assigns_optional_chain(&assigne)) {
+            log(LogLevel::Error, format!("Cannot assign to an optional chain (?.) (line {}, column {}).", equals.line, equals.column).as_str());
+        }
 
         return Statment::AssignmentExpr(Box::new(AssignmentExpr {
             kind: "AssignmentExpr".to_string(),
             value,
-            assigne: left,
+            assigne,
         }));
     }
 
     left
 }
 
-pub fn parse_multiplicative_expr(tokens: &mut Vec<Token>) -> Statment {
-    let mut left_stat = parse_call_member(tokens);
+// Parses a left associative chain of binary operators, `next` parses the operands.
+fn parse_binary_expr(tokens: &mut Vec<Token>, operators: &[TokenBase], next: fn(&mut Vec<Token>) -> Statment) -> Statment {
+    let mut left_stat = next(tokens);
 
-    while tokens.get(0).map_or(false, |token| {
-        token.base == TokenBase::Asterick || token.base == TokenBase::Divide || token.base == TokenBase::Modulus
-    }) {
+    while (operators.contains(&tokens[0].base)) {
         let operator = shift(tokens).unwrap();
-        let right = parse_call_member(tokens);
+        let right = next(tokens);
         left_stat = Statment::BinaryExpr(Box::new(BinaryExpr {
             kind: "BinaryExpr".to_string(),
             left: left_stat,
@@ -235,27 +609,114 @@ pub fn parse_multiplicative_expr(tokens: &mut Vec<Token>) -> Statment {
     left_stat
 }
 
+// cond ? a : b, both branches may hold any expression and the alternate nests to the right.
+pub fn parse_conditional_expr(tokens: &mut Vec<Token>) -> Statment {
+    let test = parse_nullish_expr(tokens);
+    if (tokens[0].base != TokenBase::QuestionMark) {
+        return test;
+    }
+
+    shift(tokens);
+    let consequent = parse_assignment_expr(tokens);
+    expect(tokens, TokenBase::Colon, "Expected a colon (:) between the branches of a conditional expression.".to_string());
+    let alternate = parse_assignment_expr(tokens);
+
+    Statment::ConditionalExpr(Box::new(ConditionalExpr {
+        kind: "ConditionalExpr".to_string(),
+        test,
+        consequent,
+        alternate
+    }))
+}
+
+pub fn parse_nullish_expr(tokens: &mut Vec<Token>) -> Statment {
+    parse_binary_expr(tokens, &[TokenBase::DoubleQuestion], parse_logical_or_expr)
+}
+
+pub fn parse_logical_or_expr(tokens: &mut Vec<Token>) -> Statment {
+    parse_binary_expr(tokens, &[TokenBase::Or], parse_logical_and_expr)
+}
+
+pub fn parse_logical_and_expr(tokens: &mut Vec<Token>) -> Statment {
+    parse_binary_expr(tokens, &[TokenBase::And], parse_equality_expr)
+}
+
+pub fn parse_equality_expr(tokens: &mut Vec<Token>) -> Statment {
+    parse_binary_expr(tokens, &[TokenBase::DoubleEquals, TokenBase::NotEquals], parse_comparison_expr)
+}
+
+pub fn parse_comparison_expr(tokens: &mut Vec<Token>) -> Statment {
+    parse_binary_expr(tokens, &[TokenBase::LessThan, TokenBase::GreaterThan, TokenBase::LessEquals, TokenBase::GreaterEquals], parse_bitwise_or_expr)
+}
+
+// Bitwise operators bind tighter than comparisons, so `flags & mask == 0` tests the masked bits.
+// From loosest to tightest: |, ^, &, then the shifts << and >>.
+pub fn parse_bitwise_or_expr(tokens: &mut Vec<Token>) -> Statment {
+    parse_binary_expr(tokens, &[TokenBase::Pipe], parse_bitwise_xor_expr)
+}
+
+pub fn parse_bitwise_xor_expr(tokens: &mut Vec<Token>) -> Statment {
+    parse_binary_expr(tokens, &[TokenBase::Caret], parse_bitwise_and_expr)
+}
+
+pub fn parse_bitwise_and_expr(tokens: &mut Vec<Token>) -> Statment {
+    parse_binary_expr(tokens, &[TokenBase::Ampersand], parse_shift_expr)
+}
+
+pub fn parse_shift_expr(tokens: &mut Vec<Token>) -> Statment {
+    parse_binary_expr(tokens, &[TokenBase::ShiftLeft, TokenBase::ShiftRight], parse_additive_expr)
+}
+
 pub fn parse_additive_expr(tokens: &mut Vec<Token>) -> Statment {
-    let mut left_stat = parse_multiplicative_expr(tokens);
+    parse_binary_expr(tokens, &[TokenBase::Plus, TokenBase::Minus], parse_multiplicative_expr)
+}
 
-    while tokens.get(0).map_or(false, |token| {
-        token.base == TokenBase::Plus || token.base == TokenBase::Minus
-    }) {
+pub fn parse_multiplicative_expr(tokens: &mut Vec<Token>) -> Statment {
+    parse_binary_expr(tokens, &[TokenBase::Asterick, TokenBase::Divide, TokenBase::IntDivide, TokenBase::Modulus], parse_unary_expr)
+}
+
+pub fn parse_unary_expr(tokens: &mut Vec<Token>) -> Statment {
+    if (tokens[0].base == TokenBase::ExclamationMark || tokens[0].base == TokenBase::Minus || tokens[0].base == TokenBase::Tilde) {
         let operator = shift(tokens).unwrap();
-        let right = parse_multiplicative_expr(tokens);
-        left_stat = Statment::BinaryExpr(Box::new(BinaryExpr {
-            kind: "BinaryExpr".to_string(),
-            left: left_stat,
-            right,
+        return Statment::UnaryExpr(Box::new(UnaryExpr {
+            kind: "UnaryExpr".to_string(),
             operator: operator.value,
+            argument: parse_unary_expr(tokens)
         }));
     }
 
-    left_stat
+    parse_power_expr(tokens)
+}
+
+// `**` binds tighter than unary operators on its left and groups to the right, so
+// -2 ** 2 is -(2 ** 2) and 2 ** 3 ** 2 is 2 ** (3 ** 2). The exponent may be negated.
+pub fn parse_power_expr(tokens: &mut Vec<Token>) -> Statment {
+    let base = parse_call_member(tokens);
+    if (tokens[0].base != TokenBase::Power) {
+        return base;
+    }
+
+    let operator = shift(tokens).unwrap();
+    Statment::BinaryExpr(Box::new(BinaryExpr {
+        kind: "BinaryExpr".to_string(),
+        left: base,
+        right: parse_unary_expr(tokens),
+        operator: operator.value,
+    }))
 }
 
 pub fn parse_primary_expr(tokens: &mut Vec<Token>) -> Statment {
-    let token: Token = shift(tokens).unwrap_or(Token { value: "EndOfFile".to_string(), base: TokenBase::EoF });
+    match tokens[0].base {
+        TokenBase::OpenBrace | TokenBase::OpenBracket if is_pattern_start(tokens) => return parse_pattern(tokens),
+        TokenBase::OpenBrace if is_object_start(tokens) => return parse_object_expr(tokens, false),
+        TokenBase::OpenBrace => return parse_block(tokens),
+        TokenBase::Match => return parse_match(tokens),
+        TokenBase::Switch => return parse_switch(tokens),
+        TokenBase::IfCondition => return parse_if(tokens),
+        TokenBase::OpenBracket => return parse_array(tokens, false),
+        _ => {}
+    }
+    let token: Token = shift(tokens).unwrap_or(Token { value: "EndOfFile".to_string(), base: TokenBase::EoF, line: 0, column: 0, newline: false });
 
     match token.base {
         TokenBase::Identifier => {
@@ -264,12 +725,40 @@ pub fn parse_primary_expr(tokens: &mut Vec<Token>) -> Statment {
                 symbol: token.value
             })
         }
+        // Numbers written without a decimal point are ints.
+        TokenBase::Number if !token.value.contains('.') => {
+            let value: i64 = token.value.parse::<i64>().unwrap_or_else(|_| {
+                log(LogLevel::Error, format!("The number {} is too large for an int (line {}, column {}), write it as {}.0 to use a float.", token.value, token.line, token.column, token.value).as_str());
+                0
+            });
+            Statment::IntegerLiteral(IntegerLiteral {
+                kind: "IntegerLiteral".to_string(),
+                value
+            })
+        }
+        TokenBase::BigInt => {
+            Statment::BigIntLiteral(BigIntLiteral {
+                kind: "BigIntLiteral".to_string(),
+                value: token.value
+            })
+        }
         TokenBase::Number => {
             Statment::NumericLiteral(NumericLiteral {
                 kind: "NumericLiteral".to_string(),
                 value: token.value.parse::<f64>().unwrap_or(0.0)
             })
         }
+        TokenBase::Boolean => {
+            Statment::BooleanLiteral(BooleanLiteral {
+                kind: "BooleanLiteral".to_string(),
+                value: token.value == "true"
+            })
+        }
+        TokenBase::OpenParen => {
+            let expression = parse_expr(tokens);
+            expect(tokens, TokenBase::CloseParen, "Expected a closing paranthesis after a grouped expression.".to_string());
+            expression
+        }
         TokenBase::Null => {
             Statment::NullLiteral(NullLiteral {
                 kind: "NullLiteral".to_string(),
@@ -282,6 +771,9 @@ pub fn parse_primary_expr(tokens: &mut Vec<Token>) -> Statment {
                 value: token.value
             })
         }
+        TokenBase::TemplateStart => {
+            parse_interpolation(tokens)
+        }
         _ => {
             log(LogLevel::Warn, format!("Unexpected token while parsing! {:#?}", token).as_str());
             Statment::None()
@@ -289,14 +781,63 @@ pub fn parse_primary_expr(tokens: &mut Vec<Token>) -> Statment {
     }
 }
 
-pub fn produce_ast(source_code: &String) -> Statment {
+pub fn parse_interpolation(tokens: &mut Vec<Token>) -> Statment {
+    let mut parts: Vec<Statment> = Vec::new();
+
+    while (tokens[0].base != TokenBase::EoF && tokens[0].base != TokenBase::TemplateEnd) {
+        let part: Token = shift(tokens).unwrap_or(Token { value: "EndOfFile".to_string(), base: TokenBase::EoF, line: 0, column: 0, newline: false });
+        match part.base {
+            TokenBase::String => {
+                parts.push(Statment::StringLiteral(StringLiteral {
+                    kind: "StringLiteral".to_string(),
+                    value: part.value
+                }));
+            }
+            TokenBase::InterpolationStart => {
+                let expression = parse_expr(tokens);
+                if let Statment::None() = expression {
+                    log(LogLevel::Error, format!("Expected an expression inside the string interpolation (line {}, column {}).", part.line, part.column).as_str());
+                }
+                expect(tokens, TokenBase::InterpolationEnd, "Expected the end of the string interpolation, found another token instead".to_string());
+                parts.push(expression);
+            }
+            _ => {
+                log(LogLevel::Error, format!("Unexpected token {:?} inside a string literal (line {}, column {}).", part.base, part.line, part.column).as_str());
+            }
+        }
+    }
+    expect(tokens, TokenBase::TemplateEnd, "Expected the end of an interpolated string.".to_string());
+
+    Statment::InterpolatedString(Box::new(InterpolatedString {
+        kind: "InterpolatedString".to_string(),
+        parts
+    }))
+}
+
+// A function declared at the top level of a program, exported or not.
+pub fn top_level_fn(stmt: &Statment) -> Option<&FuncDeclaration> {
+    match stmt {
+        Statment::FuncDeclaration(func) => Some(func.as_ref()),
+        Statment::ExportStatement(export) => match &export.declaration {
+            Statment::FuncDeclaration(func) => Some(func.as_ref()),
+            _ => None
+        },
+        _ => None
+    }
+}
+
+pub fn produce_ast(source_code: &str) -> Statment {
     let mut tokens = tokenize(source_code);
     let mut program: Program = Program { 
         body: vec![],
     };
 
-    while (tokens[0].base != TokenBase::EoF) {
-        program.body.push(parse_stmt(&mut tokens));
+    program.body = parse_body(&mut tokens);
+    if (program.body.iter().filter(|stmt| matches!(top_level_fn(stmt), Some(func) if func.entry)).count() > 1) {
+        log(LogLevel::Error, "A program can only have one v_entry function.");
+    }
+    if (tokens[0].base != TokenBase::EoF) {
+        log(LogLevel::Error, format!("Unexpected '{}' outside of a block (line {}, column {}).", tokens[0].value, tokens[0].line, tokens[0].column).as_str());
     }
 
     Statment::Program(program)
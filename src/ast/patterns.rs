@@ -0,0 +1,148 @@
+// Author: ContentGamer
+// Destructuring patterns, parsed as array / object literals and then converted
+
+use alloc::{boxed::Box, string::{String, ToString}, vec::Vec};
+
+use crate::logger::logger::{log, LogLevel};
+
+use super::ast::{ArrayPattern, AssignPattern, ObjectPattern, PropertyLiteral, Statment};
+
+// Turns an expression on the left of `=` (or in a parameter list) into a binding pattern.
+// `members` allows `obj.key` and `arr[i]` targets, which only make sense when assigning.
+pub fn to_pattern(expr: Statment, members: bool) -> Statment {
+    match expr {
+        Statment::Identifier(_) => expr,
+        Statment::MemberExpr(_) if members => expr,
+        Statment::AssignPattern(_) | Statment::ArrayPattern(_) | Statment::ObjectPattern(_) => expr,
+        Statment::AssignmentExpr(assignment) => Statment::AssignPattern(Box::new(AssignPattern {
+            kind: "AssignPattern".to_string(),
+            target: to_pattern(assignment.assigne, members),
+            default: assignment.value
+        })),
+        Statment::ArrayLiteral(arr) => {
+            let (elements, rest) = split_rest(arr.contents, members);
+            Statment::ArrayPattern(Box::new(ArrayPattern {
+                kind: "ArrayPattern".to_string(),
+                elements,
+                rest
+            }))
+        }
+        Statment::ObjectLiteral(obj) => {
+            let (properties, rest) = split_rest(obj.properties, members);
+            let properties: Vec<PropertyLiteral> = properties.into_iter().filter_map(|prop| match prop {
+                Statment::PropertyLiteral(prop) => Some(*prop),
+                _ => None
+            }).collect();
+            Statment::ObjectPattern(Box::new(ObjectPattern {
+                kind: "ObjectPattern".to_string(),
+                properties,
+                rest
+            }))
+        }
+        Statment::PropertyLiteral(mut prop) => {
+            if let Statment::FuncDeclaration(_) = prop.value {
+                log(LogLevel::Error, "A method cannot be used as a destructuring target.");
+            }
+            if (!matches!(prop.value, Statment::None())) {
+                prop.value = to_pattern(prop.value, members);
+            }
+            Statment::PropertyLiteral(prop)
+        }
+        _ => {
+            log(LogLevel::Error, "Invalid destructuring target, expected an identifier, an array pattern or an object pattern.");
+            Statment::None()
+        }
+    }
+}
+
+// Converts the entries of a pattern, a `...rest` entry is only allowed in the last position.
+fn split_rest(entries: Vec<Statment>, members: bool) -> (Vec<Statment>, Statment) {
+    let mut converted: Vec<Statment> = Vec::new();
+    let mut rest: Statment = Statment::None();
+    let count: usize = entries.len();
+
+    for (i, entry) in entries.into_iter().enumerate() {
+        match entry {
+            Statment::SpreadElement(spread) => {
+                if (i + 1 != count) {
+                    log(LogLevel::Error, "A rest element (...) must be the last element of a destructuring pattern.");
+                }
+                rest = to_pattern(spread.argument, members);
+            }
+            _ => converted.push(to_pattern(entry, members))
+        }
+    }
+
+    (converted, rest)
+}
+
+// Converts the parameter list of a function, the last parameter may collect the remaining arguments with `...rest`.
+pub fn to_params(args: Vec<Statment>) -> Vec<Statment> {
+    let count: usize = args.len();
+    args.into_iter().enumerate().map(|(i, arg)| match arg {
+        Statment::SpreadElement(mut spread) => {
+            if (i + 1 != count) {
+                log(LogLevel::Error, "A rest parameter (...) must be the last parameter of a function.");
+            }
+            spread.argument = to_pattern(spread.argument, false);
+            Statment::SpreadElement(spread)
+        }
+        _ => to_pattern(arg, false)
+    }).collect()
+}
+
+// True when a link of the member chain is `?.`, such a chain may stop at null and has nothing to assign to.
+pub fn is_optional_chain(expr: &Statment) -> bool {
+    match expr {
+        Statment::MemberExpr(memexpr) => memexpr.optional || is_optional_chain(&memexpr.object),
+        Statment::SliceExpr(slice) => slice.optional || is_optional_chain(&slice.object),
+        Statment::CallExpr(call) => call.optional || is_optional_chain(&call.caller),
+        _ => false
+    }
+}
+
+// True when an assignment target, or a target inside a destructuring pattern, is an optional chain.
+pub fn assigns_optional_chain(pattern: &Statment) -> bool {
+    match pattern {
+        Statment::MemberExpr(_) => is_optional_chain(pattern),
+        Statment::AssignPattern(assign) => assigns_optional_chain(&assign.target),
+        Statment::SpreadElement(spread) => assigns_optional_chain(&spread.argument),
+        Statment::ArrayPattern(arr) => arr.elements.iter().any(assigns_optional_chain) || assigns_optional_chain(&arr.rest),
+        Statment::ObjectPattern(obj) => obj.properties.iter().any(|prop| assigns_optional_chain(&prop.value)) || assigns_optional_chain(&obj.rest),
+        _ => false
+    }
+}
+
+// Writes a pattern back out the way it was declared, used when printing functions.
+pub fn pattern_text(pattern: &Statment) -> String {
+    match pattern {
+        Statment::Identifier(iden) => iden.symbol.clone(),
+        Statment::SpreadElement(spread) => format!("...{}", pattern_text(&spread.argument)),
+        Statment::AssignPattern(assign) => format!("{} = ...", pattern_text(&assign.target)),
+        Statment::ArrayPattern(arr) => {
+            let mut parts: Vec<String> = arr.elements.iter().map(pattern_text).collect();
+            if (!matches!(arr.rest, Statment::None())) {
+                parts.push(format!("...{}", pattern_text(&arr.rest)));
+            }
+            format!("[{}]", parts.join(", "))
+        }
+        Statment::ObjectPattern(obj) => {
+            let mut parts: Vec<String> = obj.properties.iter().map(|prop| {
+                let key: String = match &prop.key {
+                    Statment::StringLiteral(string) if !prop.computed => string.value.clone(),
+                    _ => "[...]".to_string()
+                };
+                match &prop.value {
+                    Statment::None() => key,
+                    Statment::AssignPattern(assign) if matches!(&assign.target, Statment::Identifier(iden) if iden.symbol == key) => pattern_text(&prop.value),
+                    value => format!("{}: {}", key, pattern_text(value))
+                }
+            }).collect();
+            if (!matches!(obj.rest, Statment::None())) {
+                parts.push(format!("...{}", pattern_text(&obj.rest)));
+            }
+            format!("{{ {} }}", parts.join(", "))
+        }
+        _ => String::new()
+    }
+}
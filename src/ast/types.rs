@@ -0,0 +1,150 @@
+// Author: ContentGamer
+// Type annotations: `x: str`, `(n: int32)`, `-> arr<str>`, `fn(num) -> bool` and `T?`
+
+use alloc::{boxed::Box, string::{String, ToString}, vec::Vec};
+
+use crate::{lexer::lexer::{Token, TokenBase}, logger::logger::{log, LogLevel}, utils::shift};
+
+use super::ast::TypeAnnotation;
+
+// Every name that may be written as a type, aliases are turned into the first spelling by `canonical_type`.
+const TYPE_NAMES: [&str; 21] = [
+    "any", "null", "str", "string", "num", "number", "int", "int32", "int64", "float", "bigint",
+    "bool", "boolean", "obj", "object", "arr", "array", "map", "set", "fn", "function"
+];
+
+pub fn is_type_name(name: &str) -> bool {
+    TYPE_NAMES.contains(&name)
+}
+
+fn canonical_type(name: &str) -> &str {
+    match name {
+        "string" => "str",
+        "number" => "num",
+        "int64" => "int",
+        "boolean" => "bool",
+        "object" => "obj",
+        "array" => "arr",
+        "function" => "fn",
+        _ => name
+    }
+}
+
+// The type a bare name stands for, `arr` alone holds anything.
+pub fn named_type(name: &str) -> TypeAnnotation {
+    match canonical_type(name) {
+        "any" => TypeAnnotation::Any,
+        "arr" => TypeAnnotation::Array(Box::new(TypeAnnotation::Any)),
+        name => TypeAnnotation::Named(name.to_string())
+    }
+}
+
+// Parses a type, exiting with an error when the tokens do not form one.
+pub fn parse_type(tokens: &mut Vec<Token>) -> TypeAnnotation {
+    let (line, column): (usize, usize) = (tokens[0].line, tokens[0].column);
+    match try_parse_type(tokens) {
+        Some(annotation) => annotation,
+        None => {
+            log(LogLevel::Error, format!("Expected a type such as str, num, int32, bool, obj, arr<T>, fn(T) -> U or T? (line {}, column {}).", line, column).as_str());
+            TypeAnnotation::Any
+        }
+    }
+}
+
+// Parses a type without reporting anything, used to look ahead for `str name(...)` functions.
+pub fn try_parse_type(tokens: &mut Vec<Token>) -> Option<TypeAnnotation> {
+    let token: Token = shift(tokens)?;
+    let mut annotation: TypeAnnotation = match token.base {
+        TokenBase::Null => TypeAnnotation::Named("null".to_string()),
+        TokenBase::Function => parse_fn_type(tokens)?,
+        TokenBase::Identifier if is_type_name(&token.value) => match canonical_type(&token.value) {
+            "any" => TypeAnnotation::Any,
+            "arr" if tokens[0].base == TokenBase::LessThan => {
+                shift(tokens);
+                let element: TypeAnnotation = try_parse_type(tokens)?;
+                close_angle(tokens)?;
+                TypeAnnotation::Array(Box::new(element))
+            }
+            "arr" => TypeAnnotation::Array(Box::new(TypeAnnotation::Any)),
+            "fn" if tokens[0].base == TokenBase::OpenParen => parse_fn_type(tokens)?,
+            name => TypeAnnotation::Named(name.to_string())
+        },
+        _ => return None
+    };
+
+    while (tokens[0].base == TokenBase::QuestionMark && !tokens[0].newline) {
+        shift(tokens);
+        if (!matches!(annotation, TypeAnnotation::Optional(_) | TypeAnnotation::Any)) {
+            annotation = TypeAnnotation::Optional(Box::new(annotation));
+        }
+    }
+    Some(annotation)
+}
+
+// `fn(T, U) -> R` after the `fn`, a missing return type is `any`. A bare `fn` accepts every function.
+fn parse_fn_type(tokens: &mut Vec<Token>) -> Option<TypeAnnotation> {
+    if (tokens[0].base != TokenBase::OpenParen) {
+        return Some(TypeAnnotation::Named("fn".to_string()));
+    }
+    shift(tokens);
+
+    let mut params: Vec<TypeAnnotation> = Vec::new();
+    while (tokens[0].base != TokenBase::CloseParen) {
+        params.push(try_parse_type(tokens)?);
+        match tokens[0].base {
+            TokenBase::Comma => { shift(tokens); }
+            TokenBase::CloseParen => {}
+            _ => return None
+        }
+    }
+    shift(tokens);
+
+    let result: TypeAnnotation = if (is_arrow(tokens)) {
+        shift(tokens);
+        shift(tokens);
+        try_parse_type(tokens)?
+    } else {
+        TypeAnnotation::Any
+    };
+    Some(TypeAnnotation::Function(params, Box::new(result)))
+}
+
+// `->` is lexed as a minus followed by a greater than sign.
+pub fn is_arrow(tokens: &[Token]) -> bool {
+    tokens.len() > 1 && tokens[0].base == TokenBase::Minus && tokens[1].base == TokenBase::GreaterThan
+        && tokens[1].line == tokens[0].line && tokens[1].column == tokens[0].column + 1
+}
+
+// Closes `arr<...>`, the `>>` in `arr<arr<T>>` is lexed as a single shift token and is split in two.
+fn close_angle(tokens: &mut Vec<Token>) -> Option<()> {
+    match tokens[0].base {
+        TokenBase::GreaterThan => {
+            shift(tokens);
+            Some(())
+        }
+        TokenBase::ShiftRight => {
+            tokens[0].base = TokenBase::GreaterThan;
+            tokens[0].value = ">".to_string();
+            tokens[0].column += 1;
+            Some(())
+        }
+        _ => None
+    }
+}
+
+// Writes a type back out the way it is declared, used in error messages and when printing functions.
+pub fn type_text(annotation: &TypeAnnotation) -> String {
+    match annotation {
+        TypeAnnotation::Any => "any".to_string(),
+        TypeAnnotation::Named(name) => name.clone(),
+        TypeAnnotation::Array(element) => format!("arr<{}>", type_text(element)),
+        TypeAnnotation::Function(params, result) => {
+            let params: Vec<String> = params.iter().map(type_text).collect();
+            match result.as_ref() {
+                TypeAnnotation::Any => format!("fn({})", params.join(", ")),
+                result => format!("fn({}) -> {}", params.join(", "), type_text(result))
+            }
+        }
+        TypeAnnotation::Optional(inner) => format!("{}?", type_text(inner))
+    }
+}
@@ -0,0 +1,573 @@
+// Author: ContentGamer
+// A pass over the AST that reports type errors without running the program. Typing is gradual,
+// anything without an annotation is `any` and is never reported.
+
+use std::collections::HashMap;
+
+use alloc::{boxed::Box, string::{String, ToString}, vec::Vec};
+
+use crate::{ast::{ast::{BinaryExpr, CallExpr, FuncDeclaration, Identifier, MatchPattern, Statment, TypeAnnotation, UnaryExpr, VarDeclaration}, patterns::pattern_text, types::type_text}, runtime::{interpreter::entry_point, types::compatible}};
+
+struct Variable {
+    annotation: TypeAnnotation,
+    constant: bool,
+    // Set for names declared with `fn`, their calls are checked against the parameter list.
+    function: Option<Box<FuncDeclaration>>
+}
+
+struct Checker {
+    scopes: Vec<HashMap<String, Variable>>,
+    // Function bodies found in each scope, checked once the scope is complete as a function may
+    // use names declared after it.
+    pending: Vec<Vec<FuncDeclaration>>,
+    // The functions being checked and their return types, innermost last.
+    functions: Vec<(String, TypeAnnotation)>,
+    problems: Vec<String>
+}
+
+// Checks a program, `globals` are the names the engine declares before it runs (print, len, ...).
+pub fn check_program(program: &Statment, globals: &[String]) -> Vec<String> {
+    let mut checker: Checker = Checker {
+        scopes: Vec::new(),
+        pending: Vec::new(),
+        functions: Vec::new(),
+        problems: Vec::new()
+    };
+
+    checker.enter();
+    for name in globals {
+        checker.declare(name, TypeAnnotation::Any, true, None);
+    }
+    if let Statment::Program(program) = program {
+        checker.check_statements(&program.body);
+    }
+    checker.leave();
+    if let Some(entry) = entry_point(program) {
+        checker.check_entry(entry);
+    }
+
+    checker.problems
+}
+
+fn named(name: &str) -> TypeAnnotation {
+    TypeAnnotation::Named(name.to_string())
+}
+
+// A type that says something about the value, `any` and optional types are left to the runtime.
+fn is_concrete(annotation: &TypeAnnotation) -> bool {
+    !matches!(annotation, TypeAnnotation::Any | TypeAnnotation::Optional(_)) && *annotation != named("null")
+}
+
+#[derive(PartialEq, Clone, Copy)]
+enum NumberKind {
+    Int,
+    Float,
+    BigInt,
+    // `num`, an int or a float.
+    Either
+}
+
+fn number_kind(annotation: &TypeAnnotation) -> Option<NumberKind> {
+    match annotation {
+        TypeAnnotation::Named(name) => match name.as_str() {
+            "int" | "int32" => Some(NumberKind::Int),
+            "float" => Some(NumberKind::Float),
+            "bigint" => Some(NumberKind::BigInt),
+            "num" => Some(NumberKind::Either),
+            _ => None
+        },
+        _ => None
+    }
+}
+
+// How many arguments a call has to pass. A missing argument is null, so only a parameter without
+// a default whose type rejects null has to be passed, along with every parameter before it.
+fn required_params(func: &FuncDeclaration) -> usize {
+    func.parameters.iter().enumerate().rposition(|(i, param)| {
+        !matches!(param, Statment::AssignPattern(_) | Statment::SpreadElement(_)) && func.param_types.get(i).is_some_and(is_concrete)
+    }).map_or(0, |last| last + 1)
+}
+
+fn function_type(func: &FuncDeclaration) -> TypeAnnotation {
+    TypeAnnotation::Function(func.param_types.clone(), Box::new(func.return_type.clone()))
+}
+
+impl Checker {
+    fn enter(&mut self) {
+        self.scopes.push(HashMap::new());
+        self.pending.push(Vec::new());
+    }
+
+    fn leave(&mut self) {
+        let pending: Vec<FuncDeclaration> = self.pending.last_mut().map(std::mem::take).unwrap_or_default();
+        for func in &pending {
+            self.check_function(func);
+        }
+        self.pending.pop();
+        self.scopes.pop();
+    }
+
+    fn report(&mut self, message: String) {
+        match self.functions.last() {
+            Some((name, _)) => self.problems.push(format!("In function '{}': {}", name, message)),
+            None => self.problems.push(message)
+        }
+    }
+
+    fn declare(&mut self, name: &String, annotation: TypeAnnotation, constant: bool, function: Option<Box<FuncDeclaration>>) {
+        let Some(scope) = self.scopes.last_mut() else { return };
+        if (scope.contains_key(name)) {
+            self.report(format!("'{}' is declared twice in the same scope.", name));
+            return;
+        }
+        scope.insert(name.clone(), Variable { annotation, constant, function });
+    }
+
+    fn lookup(&self, name: &String) -> Option<&Variable> {
+        self.scopes.iter().rev().find_map(|scope| scope.get(name))
+    }
+
+    fn check_statements(&mut self, body: &[Statment]) -> TypeAnnotation {
+        let mut last: TypeAnnotation = named("null");
+        for stmt in body {
+            last = self.infer(stmt);
+        }
+        last
+    }
+
+    // Runs `body` in a new scope, functions declared in it are checked before the scope closes.
+    fn scoped<T>(&mut self, body: impl FnOnce(&mut Checker) -> T) -> T {
+        self.enter();
+        let result: T = body(self);
+        self.leave();
+        result
+    }
+
+    fn check_function(&mut self, func: &FuncDeclaration) {
+        self.functions.push((func.name.clone(), func.return_type.clone()));
+        let result: TypeAnnotation = self.scoped(|checker| {
+            checker.declare(&"self".to_string(), TypeAnnotation::Any, false, None);
+            checker.declare(&"this".to_string(), TypeAnnotation::Any, false, None);
+            for (i, param) in func.parameters.iter().enumerate() {
+                let annotation: TypeAnnotation = func.param_types.get(i).cloned().unwrap_or(TypeAnnotation::Any);
+                checker.declare_pattern(param, annotation, false);
+            }
+            checker.check_statements(&func.body)
+        });
+
+        if (!func.body.is_empty() && is_concrete(&result) && !compatible(&func.return_type, &result)) {
+            self.report(format!("'{}' should return {}, but its last expression is of type {}.", func.name, type_text(&func.return_type), type_text(&result)));
+        }
+        self.functions.pop();
+    }
+
+    // The entry point is called with a single arr<str> holding the command line arguments.
+    fn check_entry(&mut self, entry: &FuncDeclaration) {
+        let required: usize = required_params(entry);
+        if (required > 1) {
+            self.report(format!("The entry point '{}' is called with the command line arguments only, but takes {} arguments.", entry.name, required));
+        }
+        let arguments: TypeAnnotation = TypeAnnotation::Array(Box::new(named("str")));
+        match (entry.parameters.first(), entry.param_types.first()) {
+            (Some(Statment::SpreadElement(_)), _) | (_, None) => {}
+            (Some(param), Some(annotation)) => self.expect_type(annotation, &arguments, || format!("the parameter '{}' of the entry point '{}'", pattern_text(param), entry.name)),
+            _ => {}
+        }
+    }
+
+    // Declares every name bound by a pattern, the annotation only applies to a plain name.
+    fn declare_pattern(&mut self, pattern: &Statment, annotation: TypeAnnotation, constant: bool) {
+        match pattern {
+            Statment::Identifier(iden) => self.declare(&iden.symbol, annotation, constant, None),
+            Statment::AssignPattern(assign) => {
+                let default: TypeAnnotation = self.infer(&assign.default);
+                self.expect_type(&annotation, &default, || format!("the default value of '{}'", pattern_text(&assign.target)));
+                self.declare_pattern(&assign.target, annotation, constant);
+            }
+            Statment::SpreadElement(spread) => self.declare_pattern(&spread.argument, annotation, constant),
+            Statment::ArrayPattern(arr) => {
+                for element in &arr.elements {
+                    self.declare_pattern(element, TypeAnnotation::Any, constant);
+                }
+                self.declare_pattern(&arr.rest, TypeAnnotation::Any, constant);
+            }
+            Statment::ObjectPattern(obj) => {
+                for prop in &obj.properties {
+                    match (&prop.value, &prop.key) {
+                        (Statment::None(), Statment::StringLiteral(key)) => self.declare(&key.value, TypeAnnotation::Any, constant, None),
+                        (value, _) => self.declare_pattern(value, TypeAnnotation::Any, constant)
+                    }
+                }
+                self.declare_pattern(&obj.rest, TypeAnnotation::Any, constant);
+            }
+            _ => {}
+        }
+    }
+
+    // Reports a value of type `actual` that can never fit the annotation `expected`.
+    fn expect_type(&mut self, expected: &TypeAnnotation, actual: &TypeAnnotation, what: impl FnOnce() -> String) {
+        if (*actual != TypeAnnotation::Any && !compatible(expected, actual)) {
+            self.report(format!("Expected {} to be of type {}, got {}.", what(), type_text(expected), type_text(actual)));
+        }
+    }
+
+    fn check_declaration(&mut self, declaration: &VarDeclaration) -> TypeAnnotation {
+        let value: TypeAnnotation = match &declaration.value {
+            Statment::None() => TypeAnnotation::Any,
+            value => self.infer(value)
+        };
+        match &declaration.pattern {
+            Statment::None() => {
+                self.expect_type(&declaration.annotation, &value, || format!("'{}'", declaration.identifier));
+                self.declare(&declaration.identifier, declaration.annotation.clone(), declaration.constant, None);
+            }
+            pattern => {
+                self.expect_type(&declaration.annotation, &value, || format!("the value of '{}'", pattern_text(pattern)));
+                self.declare_pattern(pattern, TypeAnnotation::Any, declaration.constant);
+            }
+        }
+        value
+    }
+
+    // Checks that every name in an assignment target exists and may be reassigned.
+    fn check_target(&mut self, target: &Statment, value: &TypeAnnotation) {
+        match target {
+            Statment::Identifier(iden) => {
+                let (annotation, constant): (TypeAnnotation, bool) = match self.lookup(&iden.symbol) {
+                    Some(variable) => (variable.annotation.clone(), variable.constant),
+                    None => {
+                        self.report(format!("Cannot assign to '{}', it is not declared.", iden.symbol));
+                        return;
+                    }
+                };
+                if (constant) {
+                    self.report(format!("Cannot assign to '{}', it is a constant.", iden.symbol));
+                }
+                self.expect_type(&annotation, value, || format!("'{}'", iden.symbol));
+            }
+            Statment::MemberExpr(_) => {
+                self.infer(target);
+            }
+            Statment::AssignPattern(assign) => {
+                self.infer(&assign.default);
+                self.check_target(&assign.target, &TypeAnnotation::Any);
+            }
+            Statment::SpreadElement(spread) => self.check_target(&spread.argument, &TypeAnnotation::Any),
+            Statment::ArrayPattern(arr) => {
+                for element in &arr.elements {
+                    self.check_target(element, &TypeAnnotation::Any);
+                }
+                self.check_target(&arr.rest, &TypeAnnotation::Any);
+            }
+            Statment::ObjectPattern(obj) => {
+                for prop in &obj.properties {
+                    match (&prop.value, &prop.key) {
+                        (Statment::None(), Statment::StringLiteral(key)) => {
+                            let target: Statment = Statment::Identifier(Identifier { kind: "Identifier".to_string(), symbol: key.value.clone() });
+                            self.check_target(&target, &TypeAnnotation::Any);
+                        }
+                        (value, _) => self.check_target(value, &TypeAnnotation::Any)
+                    }
+                }
+                self.check_target(&obj.rest, &TypeAnnotation::Any);
+            }
+            _ => {}
+        }
+    }
+
+    fn check_call(&mut self, call: &CallExpr) -> TypeAnnotation {
+        let args: Vec<TypeAnnotation> = call.args.iter().map(|arg| self.infer(arg)).collect();
+        let spread: bool = call.args.iter().any(|arg| matches!(arg, Statment::SpreadElement(_)));
+        let callee: TypeAnnotation = self.infer(&call.caller);
+
+        let declared: Option<Box<FuncDeclaration>> = match &call.caller {
+            Statment::Identifier(iden) => self.lookup(&iden.symbol).and_then(|variable| variable.function.clone()),
+            _ => None
+        };
+        // The type of a rest parameter describes the array it collects, not a single argument.
+        let rest: bool = declared.as_ref().is_some_and(|func| matches!(func.parameters.last(), Some(Statment::SpreadElement(_))));
+        if let Some(func) = declared {
+            let required: usize = required_params(func.as_ref());
+            let most: usize = if (rest) { usize::MAX } else { func.parameters.len() };
+            if (!spread && (args.len() < required || args.len() > most)) {
+                let (expected, count): (String, usize) = match (required == func.parameters.len(), rest) {
+                    (true, false) => (format!("{}", required), required),
+                    (_, true) => (format!("at least {}", required), required),
+                    (false, false) => (format!("{} to {}", required, most), most)
+                };
+                self.report(format!("'{}' takes {} argument{}, but is called with {} (line {}, column {}).", func.name, expected, if (count == 1) { "" } else { "s" }, args.len(), call.line, call.column));
+            }
+        }
+
+        if let TypeAnnotation::Function(params, result) = callee {
+            if (!spread) {
+                for (i, (expected, actual)) in params.iter().take(params.len() - rest as usize).zip(&args).enumerate() {
+                    if (*actual != TypeAnnotation::Any && !compatible(expected, actual)) {
+                        self.report(format!("Argument {} of the call at line {}, column {} should be of type {}, got {}.", i + 1, call.line, call.column, type_text(expected), type_text(actual)));
+                    }
+                }
+            }
+            return *result;
+        }
+        if (is_concrete(&callee) && callee != named("fn")) {
+            self.report(format!("A value of type {} is called at line {}, column {}, but it is not a function.", type_text(&callee), call.line, call.column));
+        }
+        TypeAnnotation::Any
+    }
+
+    fn check_binary(&mut self, binary: &BinaryExpr) -> TypeAnnotation {
+        let left: TypeAnnotation = self.infer(&binary.left);
+        let right: TypeAnnotation = self.infer(&binary.right);
+        let operator: &str = binary.operator.as_str();
+
+        match operator {
+            "==" | "!=" => return named("bool"),
+            "&&" | "||" | "??" => return if (left == right) { left } else { TypeAnnotation::Any },
+            _ => {}
+        }
+        if (!is_concrete(&left) || !is_concrete(&right)) {
+            return if (matches!(operator, "<" | ">" | "<=" | ">=")) { named("bool") } else { TypeAnnotation::Any };
+        }
+
+        let strings: bool = left == named("str") && right == named("str");
+        let result: Option<TypeAnnotation> = match (number_kind(&left), number_kind(&right)) {
+            _ if matches!(operator, "<" | ">" | "<=" | ">=") => {
+                (strings || (number_kind(&left).is_some() && number_kind(&right).is_some())).then(|| named("bool"))
+            }
+            _ if strings && operator == "+" => Some(named("str")),
+            (Some(l_kind), Some(r_kind)) => {
+                let kinds: [NumberKind; 2] = [l_kind, r_kind];
+                let bitwise: bool = matches!(operator, "&" | "|" | "^" | "<<" | ">>");
+                if ((bitwise && kinds.contains(&NumberKind::Float)) || (kinds.contains(&NumberKind::BigInt) && kinds.contains(&NumberKind::Float))) {
+                    None
+                } else if (kinds.contains(&NumberKind::BigInt)) {
+                    Some(if (kinds.contains(&NumberKind::Either)) { TypeAnnotation::Any } else { named("bigint") })
+                } else if (kinds.contains(&NumberKind::Float)) {
+                    Some(named("float"))
+                } else if (kinds.contains(&NumberKind::Either) || operator == "**") {
+                    Some(if (bitwise) { named("int") } else { named("num") })
+                } else {
+                    Some(if (operator == "/") { named("float") } else { named("int") })
+                }
+            }
+            _ => None
+        };
+
+        match result {
+            Some(result) => result,
+            None => {
+                self.report(format!("Cannot apply the '{}' operator to {} and {}.", operator, type_text(&left), type_text(&right)));
+                TypeAnnotation::Any
+            }
+        }
+    }
+
+    fn check_unary(&mut self, unary: &UnaryExpr) -> TypeAnnotation {
+        let argument: TypeAnnotation = self.infer(&unary.argument);
+        let kind: Option<NumberKind> = number_kind(&argument);
+        match unary.operator.as_str() {
+            "!" => named("bool"),
+            "-" if kind.is_some() => if (argument == named("int32")) { named("int") } else { argument },
+            "~" if kind == Some(NumberKind::Int) || kind == Some(NumberKind::BigInt) => argument,
+            operator => {
+                if (is_concrete(&argument)) {
+                    self.report(format!("Cannot apply the unary '{}' operator to {}.", operator, type_text(&argument)));
+                }
+                TypeAnnotation::Any
+            }
+        }
+    }
+
+    fn declare_match_pattern(&mut self, pattern: &MatchPattern) {
+        match pattern {
+            MatchPattern::Binding(name) => self.declare(name, TypeAnnotation::Any, false, None),
+            MatchPattern::Literal(literal) => {
+                self.infer(literal);
+            }
+            MatchPattern::Range(start, end, _) => {
+                self.infer(start);
+                self.infer(end);
+            }
+            MatchPattern::Array(elements, rest) => {
+                for element in elements {
+                    self.declare_match_pattern(element);
+                }
+                if let Some(rest) = rest.as_ref().filter(|rest| *rest != "_") {
+                    self.declare(rest, TypeAnnotation::Any, false, None);
+                }
+            }
+            MatchPattern::Object(properties) => {
+                for (_, pattern) in properties {
+                    self.declare_match_pattern(pattern);
+                }
+            }
+            MatchPattern::Type(_, inner) => self.declare_match_pattern(inner),
+            // Every alternative binds the same names, only one of them is declared.
+            MatchPattern::Or(alternatives) => {
+                if let Some(first) = alternatives.first() {
+                    self.declare_match_pattern(first);
+                }
+            }
+            MatchPattern::Wildcard => {}
+        }
+    }
+
+    // Checks a node and returns the type of the value it produces.
+    fn infer(&mut self, node: &Statment) -> TypeAnnotation {
+        match node {
+            Statment::IntegerLiteral(_) => named("int"),
+            Statment::NumericLiteral(_) => named("float"),
+            Statment::BigIntLiteral(_) => named("bigint"),
+            Statment::StringLiteral(_) => named("str"),
+            Statment::BooleanLiteral(_) => named("bool"),
+            Statment::NullLiteral(_) => named("null"),
+            Statment::InterpolatedString(string) => {
+                for part in &string.parts {
+                    self.infer(part);
+                }
+                named("str")
+            }
+            Statment::Identifier(iden) => match self.lookup(&iden.symbol) {
+                Some(variable) => match &variable.function {
+                    Some(func) => function_type(func),
+                    None => variable.annotation.clone()
+                },
+                None => {
+                    self.report(format!("'{}' is used but never declared.", iden.symbol));
+                    TypeAnnotation::Any
+                }
+            },
+            Statment::VarDeclaration(declaration) => self.check_declaration(declaration),
+            Statment::FuncDeclaration(func) => {
+                self.declare(&func.name, function_type(func), true, Some(func.clone()));
+                if let Some(pending) = self.pending.last_mut() {
+                    pending.push(*func.clone());
+                }
+                function_type(func)
+            }
+            Statment::AssignmentExpr(assignment) => {
+                let value: TypeAnnotation = self.infer(&assignment.value);
+                self.check_target(&assignment.assigne, &value);
+                value
+            }
+            Statment::BinaryExpr(binary) => self.check_binary(binary),
+            Statment::UnaryExpr(unary) => self.check_unary(unary),
+            Statment::CallExpr(call) => self.check_call(call),
+            Statment::MemberExpr(member) => {
+                self.infer(&member.object);
+                if (member.computed) {
+                    self.infer(&member.property);
+                }
+                TypeAnnotation::Any
+            }
+            Statment::SliceExpr(slice) => {
+                let object: TypeAnnotation = self.infer(&slice.object);
+                self.infer(&slice.start);
+                self.infer(&slice.end);
+                match object {
+                    TypeAnnotation::Named(_) | TypeAnnotation::Array(_) if !slice.optional => object,
+                    _ => TypeAnnotation::Any
+                }
+            }
+            Statment::ConditionalExpr(conditional) => {
+                self.infer(&conditional.test);
+                let consequent: TypeAnnotation = self.infer(&conditional.consequent);
+                let alternate: TypeAnnotation = self.infer(&conditional.alternate);
+                if (consequent == alternate) { consequent } else { TypeAnnotation::Any }
+            }
+            Statment::ArrayLiteral(arr) => {
+                let elements: Vec<TypeAnnotation> = arr.contents.iter().map(|element| self.infer(element)).collect();
+                let spread: bool = arr.contents.iter().any(|element| matches!(element, Statment::SpreadElement(_)));
+                match elements.first() {
+                    Some(first) if !spread && elements.iter().all(|element| element == first) => TypeAnnotation::Array(Box::new(first.clone())),
+                    _ => TypeAnnotation::Array(Box::new(TypeAnnotation::Any))
+                }
+            }
+            Statment::SpreadElement(spread) => self.infer(&spread.argument),
+            Statment::ObjectLiteral(obj) => {
+                for prop in &obj.properties {
+                    self.infer(prop);
+                }
+                named("obj")
+            }
+            Statment::PropertyLiteral(prop) => {
+                if (prop.computed) {
+                    self.infer(&prop.key);
+                }
+                match (&prop.value, &prop.key) {
+                    // `{ name }` reads the variable of the same name.
+                    (Statment::None(), Statment::StringLiteral(key)) => {
+                        let shorthand: Statment = Statment::Identifier(Identifier { kind: "Identifier".to_string(), symbol: key.value.clone() });
+                        self.infer(&shorthand);
+                    }
+                    // Methods are not declared as variables, their bodies are checked like any other function.
+                    (Statment::FuncDeclaration(method), _) => {
+                        if let Some(pending) = self.pending.last_mut() {
+                            pending.push(*method.clone());
+                        }
+                    }
+                    (value, _) => {
+                        self.infer(value);
+                    }
+                }
+                TypeAnnotation::Any
+            }
+            Statment::BlockStatement(block) => self.scoped(|checker| checker.check_statements(&block.body)),
+            Statment::MatchExpr(expr) => {
+                self.infer(&expr.subject);
+                for arm in &expr.arms {
+                    self.scoped(|checker| {
+                        checker.declare_match_pattern(&arm.pattern);
+                        checker.infer(&arm.guard);
+                        checker.infer(&arm.body);
+                    });
+                }
+                TypeAnnotation::Any
+            }
+            Statment::ThrowStatement(stmt) => {
+                self.infer(&stmt.argument);
+                TypeAnnotation::Any
+            }
+            Statment::ReturnStatement(stmt) => {
+                let value: TypeAnnotation = self.infer(&stmt.argument);
+                match self.functions.last().cloned() {
+                    Some((name, result)) => self.expect_type(&result, &value, || format!("the value returned by '{}'", name)),
+                    None => self.report("return can only be used inside a function.".to_string())
+                }
+                TypeAnnotation::Any
+            }
+            Statment::TryStatement(stmt) => {
+                self.infer(&stmt.block);
+                if (!matches!(stmt.handler, Statment::None())) {
+                    self.scoped(|checker| {
+                        checker.declare_pattern(&stmt.param, TypeAnnotation::Any, false);
+                        checker.infer(&stmt.handler);
+                    });
+                }
+                self.infer(&stmt.finalizer);
+                TypeAnnotation::Any
+            }
+            // Imported names are `any`, the files they come from are checked separately (see check_file in main.rs).
+            Statment::ImportStatement(stmt) => {
+                if (self.scopes.len() > 1) {
+                    self.report("import can only be used at the top level of a file.".to_string());
+                }
+                if (!stmt.namespace.is_empty()) {
+                    self.declare(&stmt.namespace, named("obj"), true, None);
+                }
+                for (_, local) in &stmt.names {
+                    self.declare(local, TypeAnnotation::Any, true, None);
+                }
+                named("obj")
+            }
+            Statment::ExportStatement(stmt) => {
+                if (self.scopes.len() > 1) {
+                    self.report("export can only be used at the top level of a file.".to_string());
+                }
+                self.infer(&stmt.declaration)
+            }
+            Statment::Program(program) => self.check_statements(&program.body),
+            _ => TypeAnnotation::Any
+        }
+    }
+}
@@ -1,29 +1,203 @@
 use std::{any::Any, rc::Rc};
 
-use crate::{logger::logger::{log, LogLevel}, runtime::{env::Environment, values::{FuncVoid, StringVal, ValueType}}};
+use crate::{ast::types::named_type, logger::logger::{log, LogLevel}, runtime::{types::matches_type, env::{DivisionMode, Environment}, errors::{error_value, fault, Eval}, interpreter::display_value, interpreter::hash_key, numbers::{bigint, float, integer, to_bigint, to_float, to_integer}, values::{to_json, type_name, ArrayVal, FuncVoid, MapVal, SetVal, StringVal, ValueType}}, utils::graphemes};
 
-fn base_fn(fnn: &str, args: &Vec<ValueType>, arguments: usize) -> () {
+fn base_fn(fnn: &str, args: &[ValueType], arguments: usize) -> Eval<()> {
     if (args.len() < arguments) {
-        log(LogLevel::Error, format!("{}_fn: Expected an argument.", fnn).as_str());
+        return fault("TypeError", format!("{}_fn: Expected an argument.", fnn));
     }
+    Ok(())
 }
-fn oftype(fnn: &str, indx: usize, args: &Vec<ValueType>, t: &str) -> () {
-    let mut arg_t: String = String::new();
-    let arg: ValueType = args[indx].clone();
-    match arg {
-        ValueType::String(v) => arg_t = "string".to_string(),
-        ValueType::Number(v) => arg_t = "number".to_string(),
-        ValueType::Object(v) => arg_t = "object".to_string(),
-        _ => arg_t = "err".to_string()
+// `t` is written the way annotations write types.
+fn oftype(fnn: &str, indx: usize, args: &[ValueType], t: &str) -> Eval<()> {
+    if (!matches_type(&args[indx], &named_type(t))) {
+        return fault("TypeError", format!("{}_fn: Expected argument {} of the function to be of type {}, got a value of type {}.", fnn, indx, t, type_name(&args[indx])));
+    }
+    Ok(())
+}
+
+pub fn print(args: Vec<ValueType>, env: &mut Environment) -> Eval {
+    base_fn("print", &args, 1)?;
+    let text: Vec<String> = args.iter().map(|arg| display_value(arg, false, env)).collect::<Eval<Vec<String>>>()?;
+    if (env.settings.advanced_logging) {
+        log(LogLevel::Info, text.join(" ").as_str());
+    } else {
+        println!("{}", text.join(" "));
+    }
+
+    Ok(ValueType::FnVoid(FuncVoid {}))
+}
+
+pub fn str(args: Vec<ValueType>, env: &mut Environment) -> Eval {
+    base_fn("str", &args, 1)?;
+    Ok(ValueType::String(StringVal {
+        base: "StringVal".to_string(),
+        value: display_value(&args[0], false, env)?
+    }))
+}
+
+pub fn json(args: Vec<ValueType>, env: &mut Environment) -> Eval {
+    base_fn("json", &args, 1)?;
+    Ok(ValueType::String(StringVal {
+        base: "StringVal".to_string(),
+        value: to_json(&args[0])?
+    }))
+}
+
+// Error(message) or Error(message, kind) builds the same kind of object runtime errors are thrown as.
+pub fn error(args: Vec<ValueType>, env: &mut Environment) -> Eval {
+    base_fn("Error", &args, 1)?;
+    oftype("Error", 0, &args, "str")?;
+    let text = |arg: Option<&ValueType>| match arg {
+        Some(ValueType::String(string)) => Some(string.value.clone()),
+        _ => None
+    };
+    if (args.len() > 1) {
+        oftype("Error", 1, &args, "str")?;
+    }
+
+    let message: String = text(args.first()).unwrap_or_default();
+    let kind: String = text(args.get(1)).unwrap_or("Error".to_string());
+    Ok(error_value(&kind, &message))
+}
+
+pub fn int(args: Vec<ValueType>, env: &mut Environment) -> Eval {
+    base_fn("int", &args, 1)?;
+    Ok(integer(to_integer(&args[0])?))
+}
+
+pub fn bigint_fn(args: Vec<ValueType>, env: &mut Environment) -> Eval {
+    base_fn("bigint", &args, 1)?;
+    Ok(bigint(to_bigint(&args[0])?))
+}
+
+pub fn float_fn(args: Vec<ValueType>, env: &mut Environment) -> Eval {
+    base_fn("float", &args, 1)?;
+    Ok(float(to_float(&args[0])?))
+}
+
+// num(value) gives an int for ints and strings of digits and a float for everything else it can convert.
+pub fn num(args: Vec<ValueType>, env: &mut Environment) -> Eval {
+    base_fn("num", &args, 1)?;
+    match &args[0] {
+        ValueType::Integer(_) | ValueType::Number(_) | ValueType::FnNumber(_) => Ok(args[0].clone()),
+        ValueType::String(string) | ValueType::FnString(string) if string.value.trim().parse::<i64>().is_ok() => Ok(integer(to_integer(&args[0])?)),
+        ValueType::BigInt(_) | ValueType::Boolean(_) => Ok(integer(to_integer(&args[0])?)),
+        _ => Ok(float(to_float(&args[0])?))
+    }
+}
+
+// settings({ division: "error" | "ieee", advancedLogging: bool }) changes the options of the engine,
+// options that are left out keep their value.
+pub fn settings(args: Vec<ValueType>, env: &mut Environment) -> Eval {
+    base_fn("settings", &args, 1)?;
+    oftype("settings", 0, &args, "obj")?;
+    let ValueType::Object(options) = &args[0] else { return Ok(ValueType::None()) };
+
+    for (key, value) in options.properties.borrow().iter() {
+        match (key.as_str(), value) {
+            ("division", ValueType::String(mode)) if mode.value == "error" => env.settings.division = DivisionMode::Error,
+            ("division", ValueType::String(mode)) if mode.value == "ieee" => env.settings.division = DivisionMode::Ieee,
+            ("division", _) => return fault("TypeError", "settings_fn: The division setting must be \"error\" or \"ieee\".".to_string()),
+            ("advancedLogging", ValueType::Boolean(flag)) => env.settings.advanced_logging = flag.value,
+            ("advancedLogging", _) => return fault("TypeError", "settings_fn: The advancedLogging setting must be a boolean.".to_string()),
+            (key, _) => return fault("TypeError", format!("settings_fn: Unknown setting '{}'.", key))
+        }
+    }
+    Ok(ValueType::FnVoid(FuncVoid {}))
+}
+
+fn string_array(strings: Vec<String>) -> ValueType {
+    ValueType::Array(ArrayVal::new(strings.into_iter().map(|value| ValueType::String(StringVal { base: "StringVal".to_string(), value })).collect()))
+}
+
+// Strings are measured in unicode scalar values, use bytes() or graphemes() for the other units.
+pub fn len(args: Vec<ValueType>, env: &mut Environment) -> Eval {
+    base_fn("len", &args, 1)?;
+    let length: usize = match &args[0] {
+        ValueType::String(string) => string.value.chars().count(),
+        ValueType::Array(arr) => arr.contents.borrow().len(),
+        ValueType::Object(obj) => obj.properties.borrow().len(),
+        ValueType::Map(map) => map.entries.borrow().len(),
+        ValueType::Set(set) => set.members.borrow().len(),
+        _ => {
+            return fault("TypeError", "len_fn: Expected argument 0 of the function to be a string, array, object, map or set.".to_string());
+        }
+    };
+
+    Ok(integer(length as i64))
+}
+
+pub fn chars(args: Vec<ValueType>, env: &mut Environment) -> Eval {
+    base_fn("chars", &args, 1)?;
+    oftype("chars", 0, &args, "str")?;
+    match &args[0] {
+        ValueType::String(string) => Ok(string_array(string.value.chars().map(|c| c.to_string()).collect())),
+        _ => Ok(ValueType::None())
+    }
+}
+
+pub fn bytes(args: Vec<ValueType>, env: &mut Environment) -> Eval {
+    base_fn("bytes", &args, 1)?;
+    oftype("bytes", 0, &args, "str")?;
+    match &args[0] {
+        ValueType::String(string) => Ok(ValueType::Array(ArrayVal::new(
+            string.value.bytes().map(|byte| integer(byte as i64)).collect()
+        ))),
+        _ => Ok(ValueType::None())
+    }
+}
+
+pub fn graphemes_fn(args: Vec<ValueType>, env: &mut Environment) -> Eval {
+    base_fn("graphemes", &args, 1)?;
+    oftype("graphemes", 0, &args, "str")?;
+    match &args[0] {
+        ValueType::String(string) => Ok(string_array(graphemes(&string.value))),
+        _ => Ok(ValueType::None())
+    }
+}
+
+// Map() or Map([[key, value], ...]), also accepts an object or another map to copy the entries of.
+pub fn map(args: Vec<ValueType>, env: &mut Environment) -> Eval {
+    let map: MapVal = MapVal::new();
+    let entries: Vec<(ValueType, ValueType)> = match args.first() {
+        None => Vec::new(),
+        Some(ValueType::Array(arr)) => arr.contents.borrow().iter().map(|entry| match entry {
+            ValueType::Array(pair) if pair.contents.borrow().len() == 2 => {
+                let pair = pair.contents.borrow();
+                Ok((pair[0].clone(), pair[1].clone()))
+            }
+            _ => fault("TypeError", "map_fn: Expected every entry to be a [key, value] array.".to_string())
+        }).collect::<Eval<Vec<(ValueType, ValueType)>>>()?,
+        Some(ValueType::Object(obj)) => obj.properties.borrow().iter()
+            .map(|(key, value)| (ValueType::String(StringVal { base: "StringVal".to_string(), value: key.clone() }), value.clone()))
+            .collect(),
+        Some(ValueType::Map(other)) => other.entries.borrow().values().cloned().collect(),
+        Some(other) => {
+            return fault("TypeError", format!("map_fn: Cannot create a map from a value of type {}.", type_name(other)));
+        }
     };
-    if (arg_t.to_lowercase() != t.to_string().to_lowercase()) {
-        log(LogLevel::Error, format!("{}_fn: Expected argument {} of the function to be a {}.", fnn, indx, t).as_str());
+
+    for (key, value) in entries {
+        map.insert(hash_key(&key)?, key, value);
     }
+    Ok(ValueType::Map(map))
 }
 
-pub fn print(args: Vec<ValueType>, env: &mut Environment) -> ValueType {
-    base_fn("print", &args, 1);
-    println!("{:?}", &args[0]);
+// Set() or Set([a, b, ...]), duplicates are dropped keeping the first occurrence.
+pub fn set(args: Vec<ValueType>, env: &mut Environment) -> Eval {
+    let set: SetVal = SetVal::new();
+    let members: Vec<ValueType> = match args.first() {
+        None => Vec::new(),
+        Some(ValueType::Array(arr)) => arr.contents.borrow().clone(),
+        Some(ValueType::Set(other)) => other.members.borrow().values().cloned().collect(),
+        Some(other) => {
+            return fault("TypeError", format!("set_fn: Cannot create a set from a value of type {}.", type_name(other)));
+        }
+    };
 
-    ValueType::FnVoid(FuncVoid {})
-}
\ No newline at end of file
+    for member in members {
+        set.insert(hash_key(&member)?, member);
+    }
+    Ok(ValueType::Set(set))
+}
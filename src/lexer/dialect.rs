@@ -0,0 +1,60 @@
+// Author: ContentGamer
+// The vapor_script dialect, selected by a `//vapor_script` line at the top of a file. Its
+// keywords are aliases that are turned into the tokens of the base language after lexing.
+
+use alloc::{string::{String, ToString}, vec::Vec};
+
+use super::lexer::{Token, TokenBase};
+
+pub const PRAGMA: &str = "//vapor_script";
+
+// Finds the pragma, only blank lines and `#` comments may come before it. The pragma line is
+// blanked out so the lexer does not read it as code and every line keeps its number.
+pub fn read_pragma(source_code: &str) -> (String, bool) {
+    let mut lines: Vec<&str> = source_code.split('\n').collect();
+    let first: Option<usize> = lines.iter().position(|line| {
+        let line: &str = line.trim();
+        !line.is_empty() && !line.starts_with('#')
+    });
+
+    match first {
+        Some(indx) if lines[indx].trim() == PRAGMA => {
+            lines[indx] = "";
+            (lines.join("\n"), true)
+        }
+        _ => (source_code.to_string(), false)
+    }
+}
+
+// The keywords of the dialect and the tokens they stand for.
+fn keyword(name: &str) -> Option<TokenBase> {
+    match name {
+        "v_const" | "v_c" => Some(TokenBase::Const),
+        "v_let" => Some(TokenBase::Let),
+        "v_entry" => Some(TokenBase::Entry),
+        "v_ret" => Some(TokenBase::Return),
+        "v_break" => Some(TokenBase::BreakLoop),
+        "switch" => Some(TokenBase::Switch),
+        _ => None
+    }
+}
+
+// Functions of the dialect that are other names for functions of the engine.
+fn native(name: &str) -> Option<&'static str> {
+    match name {
+        "v_log" => Some("print"),
+        "v_num" => Some("num"),
+        "v_settings" => Some("settings"),
+        _ => None
+    }
+}
+
+pub fn apply_dialect(tokens: &mut [Token]) {
+    for token in tokens.iter_mut().filter(|token| token.base == TokenBase::Identifier) {
+        if let Some(base) = keyword(&token.value) {
+            token.base = base;
+        } else if let Some(name) = native(&token.value) {
+            token.value = name.to_string();
+        }
+    }
+}
@@ -5,7 +5,9 @@ use alloc::{string::{String, ToString}, vec::Vec};
 use core::clone;
 use std::collections::HashMap;
 
-use crate::{logger::logger::{log, LogLevel}, utils::{escape_seq, isalphabet, isempty, isint, shift}};
+use crate::{logger::logger::{log, LogLevel}, utils::{isempty, isidentcontinue, isidentstart, isint, shift}};
+
+use super::{dialect::{apply_dialect, read_pragma}, strings::{is_raw_string, lex_raw_string, lex_string}};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum TokenBase {
@@ -13,6 +15,11 @@ pub enum TokenBase {
     Colon,
     Semicolon,
     Dot,
+    Ellipsis,
+    DoubleDot,
+    DoubleDotEquals,
+    FatArrow,
+    Pipe,
     Hashtag,
     
     OpenParen,
@@ -25,27 +32,61 @@ pub enum TokenBase {
     Plus,
     Minus,
     Divide,
+    IntDivide,
     Modulus,
+    Power,
     Asterick,
     Equals,
+    DoubleEquals,
+    NotEquals,
+    LessThan,
+    GreaterThan,
+    LessEquals,
+    GreaterEquals,
+    And,
+    Or,
+    Ampersand,
+    Caret,
+    Tilde,
+    ShiftLeft,
+    ShiftRight,
     
     Let,
     Const,
     
     Function,
     Number,
+    BigInt,
     String,
+    Boolean,
     Identifier,
     EoF,
     Null,
 
+    Match,
+    Switch,
+    Return,
+    Entry,
+    Import,
+    Export,
+    Throw,
+    Try,
+    Catch,
+    Finally,
     IfCondition,
     ElseCondition,
     ElseIfCondition,
     ExclamationMark,
     QuestionMark,
+    QuestionDot,
+    DoubleQuestion,
     ToKeyword,
 
+    TemplateStart,
+    TemplateEnd,
+    InterpolationStart,
+    InterpolationEnd,
+
     ForLoop,
     WhileLoop,
     BreakLoop,
@@ -55,11 +96,43 @@ pub enum TokenBase {
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Token {
     pub value: String,
-    pub base: TokenBase
+    pub base: TokenBase,
+    pub line: usize,
+    pub column: usize,
+    // Set when a line break separates the token from the one before it.
+    pub newline: bool
 }
 
-fn token(value: &str, base: TokenBase) -> Token {
-    Token { value: String::from(value), base }
+pub fn token(value: &str, base: TokenBase, at: (usize, usize)) -> Token {
+    Token { value: String::from(value), base, line: at.0, column: at.1, newline: false }
+}
+
+pub fn starts_with(code: &[String], pattern: &str) -> bool {
+    let pattern: Vec<String> = pattern.chars().map(|c| c.to_string()).collect();
+    code.len() >= pattern.len() && code[..pattern.len()] == pattern[..]
+}
+
+// Shifts an operator made of several characters out of the source code.
+fn take_operator(code: &mut Vec<String>, length: usize) -> String {
+    (0..length).map(|_| shift(code).unwrap_or_default()).collect()
+}
+
+// Maps every entry of the split source code to its (line, column).
+fn locate(code: &Vec<String>) -> Vec<(usize, usize)> {
+    let mut positions: Vec<(usize, usize)> = Vec::new();
+    let mut line: usize = 1;
+    let mut column: usize = 1;
+
+    for character in code {
+        positions.push((line, column));
+        if (character == "\n") {
+            line += 1;
+            column = 1;
+        } else if (!character.is_empty()) {
+            column += 1;
+        }
+    }
+    positions
 }
 
 fn get_keywords() -> HashMap<String, TokenBase> {
@@ -68,117 +141,201 @@ fn get_keywords() -> HashMap<String, TokenBase> {
     keywords.insert("let".to_string(), TokenBase::Let);
     keywords.insert("const".to_string(), TokenBase::Const);
     keywords.insert("null".to_string(), TokenBase::Null);
+    keywords.insert("true".to_string(), TokenBase::Boolean);
+    keywords.insert("false".to_string(), TokenBase::Boolean);
 
     keywords.insert("fn".to_string(), TokenBase::Function);
+    keywords.insert("match".to_string(), TokenBase::Match);
+    keywords.insert("throw".to_string(), TokenBase::Throw);
+    keywords.insert("try".to_string(), TokenBase::Try);
+    keywords.insert("catch".to_string(), TokenBase::Catch);
+    keywords.insert("finally".to_string(), TokenBase::Finally);
 
     keywords.insert("if".to_string(), TokenBase::IfCondition);
     keywords.insert("else".to_string(), TokenBase::ElseCondition);
     keywords.insert("else if".to_string(), TokenBase::ElseIfCondition);
 
     keywords.insert("to".to_string(), TokenBase::ToKeyword);
+    keywords.insert("return".to_string(), TokenBase::Return);
+    keywords.insert("break".to_string(), TokenBase::BreakLoop);
+
+    keywords.insert("import".to_string(), TokenBase::Import);
+    keywords.insert("export".to_string(), TokenBase::Export);
 
     keywords
 }
 
-pub fn tokenize(source_code: &String) -> Vec<Token> {
+pub fn tokenize(source_code: &str) -> Vec<Token> {
+    let (source_code, dialect): (String, bool) = read_pragma(source_code);
+    let code: Vec<String> = source_code.split("").map(|s| s.to_string()).collect::<Vec<String>>();
+    let positions: Vec<(usize, usize)> = locate(&code);
+    let end: (usize, usize) = positions.last().cloned().unwrap_or((1, 1));
+
+    let mut tokens: Vec<Token> = lex(code, &positions);
+    if (dialect) {
+        apply_dialect(&mut tokens);
+    }
+    tokens.push(token("EndOfFile", TokenBase::EoF, end));
+    tokens
+}
+
+pub fn lex(mut code: Vec<String>, positions: &[(usize, usize)]) -> Vec<Token> {
     let mut tokens: Vec<Token> = Vec::new();
-    let mut code: Vec<String> = source_code.split("").map(|s| s.to_string()).collect::<Vec<String>>();
+    let mut newline: bool = false;
+
+    while (!code.is_empty()) {
+        let at: (usize, usize) = positions[positions.len() - code.len()];
+        let count: usize = tokens.len();
+        if (code[0] == "\n") {
+            newline = true;
+        }
+
+        if (starts_with(&code, "...")) {
+            tokens.push(token(take_operator(&mut code, 3).as_str(), TokenBase::Ellipsis, at));
+        } else if (starts_with(&code, "..=")) {
+            tokens.push(token(take_operator(&mut code, 3).as_str(), TokenBase::DoubleDotEquals, at));
+        } else if (starts_with(&code, "..")) {
+            tokens.push(token(take_operator(&mut code, 2).as_str(), TokenBase::DoubleDot, at));
+        } else if (starts_with(&code, "=>")) {
+            tokens.push(token(take_operator(&mut code, 2).as_str(), TokenBase::FatArrow, at));
+        } else if (starts_with(&code, "==")) {
+            tokens.push(token(take_operator(&mut code, 2).as_str(), TokenBase::DoubleEquals, at));
+        } else if (starts_with(&code, "!=")) {
+            tokens.push(token(take_operator(&mut code, 2).as_str(), TokenBase::NotEquals, at));
+        } else if (starts_with(&code, "<<")) {
+            tokens.push(token(take_operator(&mut code, 2).as_str(), TokenBase::ShiftLeft, at));
+        } else if (starts_with(&code, ">>")) {
+            tokens.push(token(take_operator(&mut code, 2).as_str(), TokenBase::ShiftRight, at));
+        } else if (starts_with(&code, "<=")) {
+            tokens.push(token(take_operator(&mut code, 2).as_str(), TokenBase::LessEquals, at));
+        } else if (starts_with(&code, ">=")) {
+            tokens.push(token(take_operator(&mut code, 2).as_str(), TokenBase::GreaterEquals, at));
+        } else if (starts_with(&code, "&&")) {
+            tokens.push(token(take_operator(&mut code, 2).as_str(), TokenBase::And, at));
+        } else if (starts_with(&code, "||")) {
+            tokens.push(token(take_operator(&mut code, 2).as_str(), TokenBase::Or, at));
+        } else if (code[0] == "|") {
+            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::Pipe, at));
+        } else if (code[0] == "&") {
+            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::Ampersand, at));
+        } else if (code[0] == "^") {
+            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::Caret, at));
+        } else if (code[0] == "~") {
+            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::Tilde, at));
+        } else if (starts_with(&code, "??")) {
+            tokens.push(token(take_operator(&mut code, 2).as_str(), TokenBase::DoubleQuestion, at));
+        } else if (starts_with(&code, "?.")) {
+            tokens.push(token(take_operator(&mut code, 2).as_str(), TokenBase::QuestionDot, at));
+        } else if (starts_with(&code, "**")) {
+            tokens.push(token(take_operator(&mut code, 2).as_str(), TokenBase::Power, at));
+        } else if (starts_with(&code, "//")) {
+            tokens.push(token(take_operator(&mut code, 2).as_str(), TokenBase::IntDivide, at));
+        }
 
-    while (code.len() > 0) {
-        if (code[0] == "*") {
-            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::Asterick));
+        else if (code[0] == "*") {
+            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::Asterick, at));
         } else if (code[0] == "+") {
-            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::Plus));
+            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::Plus, at));
         } else if (code[0] == "-") {
-            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::Minus));
+            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::Minus, at));
         } else if (code[0] == "/") {
-            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::Divide));
+            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::Divide, at));
         } else if (code[0] == "%") {
-            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::Modulus));
+            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::Modulus, at));
         } else if (code[0] == "=") {
-            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::Equals))
+            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::Equals, at))
         }
         
         else if (code[0] == "(") {
-            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::OpenParen))
+            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::OpenParen, at))
         } else if (code[0] == ")") {
-            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::CloseParen))
+            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::CloseParen, at))
         }
         else if (code[0] == "{") {
-            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::OpenBrace))
+            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::OpenBrace, at))
         } else if (code[0] == "}") {
-            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::CloseBrace))
+            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::CloseBrace, at))
         }        
         else if (code[0] == "[") {
-            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::OpenBracket))
+            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::OpenBracket, at))
         } else if (code[0] == "]") {
-            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::CloseBracket))
+            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::CloseBracket, at))
         }
 
         else if (code[0] == ":") {
-            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::Colon))
+            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::Colon, at))
         } else if (code[0] == ",") {
-            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::Comma))
+            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::Comma, at))
         } else if (code[0] == ";") {
-            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::Semicolon))
+            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::Semicolon, at))
         } else if (code[0] == ".") {
-            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::Dot))
+            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::Dot, at))
+        }
+
+        else if (code[0] == "<") {
+            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::LessThan, at))
+        } else if (code[0] == ">") {
+            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::GreaterThan, at))
         }
 
         else if (code[0] == "!") {
-            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::ExclamationMark))
+            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::ExclamationMark, at))
         } else if (code[0] == "?") {
-            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::QuestionMark))
+            tokens.push(token(shift(&mut code).unwrap_or_default().as_str(), TokenBase::QuestionMark, at))
         }
 
         else if (code[0] == "#") {
             shift(&mut code);
-            while (code[0] != "\n") {
+            while (!code.is_empty() && code[0] != "\n") {
                 shift(&mut code);
             }
-            shift(&mut code);
         }
 
         else if (code[0] == '"'.to_string()) {
-            let mut string: String = String::new();
-            shift(&mut code);
-
-            while (code[0].len() > 0 && code[0] != '"'.to_string()) {
-                string += code[0].as_str();
-                shift(&mut code);
-            }
-            shift(&mut code);
-            
-            tokens.push(token(escape_seq(string.as_str()).as_str(), TokenBase::String))
+            lex_string(&mut code, positions, &mut tokens);
+        } else if (is_raw_string(&code)) {
+            lex_raw_string(&mut code, positions, &mut tokens);
         }
 
         else {
             if (isint(code[0].clone())) {
                 let mut num: String = String::from("");
-                while (code.len() > 0 && isint(code[0].clone())) {
+                while (!code.is_empty() && isint(code[0].clone())) {
                     num += shift(&mut code).unwrap_or_default().as_str();
                 }
+                if (code.len() > 1 && code[0] == "." && isint(code[1].clone())) {
+                    num += shift(&mut code).unwrap_or_default().as_str();
+                    while (!code.is_empty() && isint(code[0].clone())) {
+                        num += shift(&mut code).unwrap_or_default().as_str();
+                    }
+                }
 
-                tokens.push(token(num.as_str(), TokenBase::Number));
+                // 123n is a bigint literal, the suffix is not kept in the token.
+                if (code.first().is_some_and(|next| next == "n") && !num.contains('.') && code.get(1).is_none_or(|after| !isidentcontinue(after))) {
+                    shift(&mut code);
+                    tokens.push(token(num.as_str(), TokenBase::BigInt, at));
+                } else {
+                    tokens.push(token(num.as_str(), TokenBase::Number, at));
+                }
             }
             else if (isempty(code[0].clone())) {
                 shift(&mut code);
             }
-            else if (isalphabet(&code[0].clone())) {
+            else if (isidentstart(&code[0].clone())) {
                 let keywords: HashMap<String, TokenBase> = get_keywords();
                 let mut ident: String = String::from("");
 
-                while (code.len() > 0 && isalphabet(&code[0].clone())) {
+                while (!code.is_empty() && isidentcontinue(&code[0].clone())) {
                     ident += shift(&mut code).unwrap_or_default().as_str();
                 }
 
                 match &keywords.get(&ident) {
                     Some(keyword) => {
                         let inner = *keyword;
-                        tokens.push(token(&ident, inner.clone()));
+                        tokens.push(token(&ident, inner.clone(), at));
                     }
                     None => {
-                        tokens.push(token(&ident, TokenBase::Identifier));
+                        tokens.push(token(&ident, TokenBase::Identifier, at));
                     }
                 }
             }
@@ -186,8 +343,12 @@ pub fn tokenize(source_code: &String) -> Vec<Token> {
                 log(LogLevel::Error, format!("Unregonized token found in source code: {}", code[0]).as_str());
             }
         }
+
+        if (tokens.len() > count) {
+            tokens[count].newline = newline;
+            newline = false;
+        }
     }
 
-    tokens.push(token("EndOfFile", TokenBase::EoF));
     tokens
-}
\ No newline at end of file
+}
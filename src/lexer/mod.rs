@@ -1 +1,4 @@
-pub mod lexer;
\ No newline at end of file
+#[allow(clippy::module_inception)]
+pub mod lexer;
+pub mod strings;
+pub mod dialect;
\ No newline at end of file
@@ -0,0 +1,251 @@
+// Author: ContentGamer
+// "quoted ${interpolated}", r#"raw"# and """triple quoted""" string literals
+
+use alloc::{string::{String, ToString}, vec::Vec};
+
+use crate::{logger::logger::{log, LogLevel}, utils::{escape_seq, isempty, shift}};
+
+use super::lexer::{lex, starts_with, token, Token, TokenBase};
+
+// The segments of a string literal, literal text or the raw source of a `${...}` expression,
+// each with the index of its first character inside the string body.
+enum StringSegment {
+    Text(Vec<String>, usize),
+    Expression(Vec<String>, usize)
+}
+
+// The characters of one line of a triple quoted string and their positions.
+type Line = (Vec<String>, Vec<(usize, usize)>);
+
+fn at_end(code: &[String]) -> bool {
+    code.is_empty() || code[0].is_empty()
+}
+
+fn here(code: &[String], positions: &[(usize, usize)]) -> (usize, usize) {
+    positions[(positions.len() - code.len()).min(positions.len() - 1)]
+}
+
+// Moves the next character of the source code into the string body.
+fn take(code: &mut Vec<String>, positions: &[(usize, usize)], body: &mut Vec<String>, body_positions: &mut Vec<(usize, usize)>) {
+    body_positions.push(here(code, positions));
+    body.push(shift(code).unwrap_or_default());
+}
+
+// Finds the closing brace of an interpolation whose expression starts at `from`,
+// skipping over braces that appear inside nested string literals.
+fn interpolation_end(chars: &[String], from: usize) -> Option<usize> {
+    let mut depth: usize = 0;
+    let mut quoted: bool = false;
+    let mut i: usize = from;
+
+    while (i < chars.len() && !chars[i].is_empty()) {
+        if (quoted) {
+            if (chars[i] == "\\") {
+                i += 1;
+            } else if (chars[i] == "\"") {
+                quoted = false;
+            }
+        } else if (chars[i] == "\"") {
+            quoted = true;
+        } else if (chars[i] == "{") {
+            depth += 1;
+        } else if (chars[i] == "}") {
+            if (depth == 0) {
+                return Some(i);
+            }
+            depth -= 1;
+        }
+        i += 1;
+    }
+    None
+}
+
+// Scans a string literal. Strings without `${...}` become a single String token, otherwise the literal is
+// split into TemplateStart, String / InterpolationStart <tokens> InterpolationEnd parts and a TemplateEnd.
+pub fn lex_string(code: &mut Vec<String>, positions: &[(usize, usize)], tokens: &mut Vec<Token>) {
+    let start: (usize, usize) = here(code, positions);
+    let triple: bool = starts_with(code, "\"\"\"");
+    let delimiter: &str = if (triple) { "\"\"\"" } else { "\"" };
+    for _ in 0..delimiter.len() {
+        shift(code);
+    }
+
+    let mut body: Vec<String> = Vec::new();
+    let mut body_positions: Vec<(usize, usize)> = Vec::new();
+
+    while (!starts_with(code, delimiter)) {
+        if (at_end(code)) {
+            log(LogLevel::Error, format!("Unterminated string literal, missing a closing {} (line {}, column {}).", delimiter, start.0, start.1).as_str());
+            return;
+        }
+
+        if (code[0] == "\\" && code.len() > 1) {
+            take(code, positions, &mut body, &mut body_positions);
+            take(code, positions, &mut body, &mut body_positions);
+        } else if (starts_with(code, "${")) {
+            let opened: (usize, usize) = here(code, positions);
+            let end: usize = match interpolation_end(code, 2) {
+                Some(end) => end,
+                None => {
+                    log(LogLevel::Error, format!("Unterminated string interpolation, missing a closing brace (line {}, column {}).", opened.0, opened.1).as_str());
+                    return;
+                }
+            };
+            for _ in 0..=end {
+                take(code, positions, &mut body, &mut body_positions);
+            }
+        } else {
+            take(code, positions, &mut body, &mut body_positions);
+        }
+    }
+
+    let end: (usize, usize) = here(code, positions);
+    for _ in 0..delimiter.len() {
+        shift(code);
+    }
+
+    if (triple) {
+        strip_indentation(&mut body, &mut body_positions);
+    }
+    emit_string(body, &body_positions, start, end, tokens);
+}
+
+fn emit_string(body: Vec<String>, body_positions: &[(usize, usize)], start: (usize, usize), end: (usize, usize), tokens: &mut Vec<Token>) {
+    let mut segments: Vec<StringSegment> = Vec::new();
+    let mut text: Vec<String> = Vec::new();
+    let mut text_start: usize = 0;
+    let mut i: usize = 0;
+
+    while (i < body.len()) {
+        if (body[i] == "\\" && i + 1 < body.len()) {
+            text.push(body[i].clone());
+            text.push(body[i + 1].clone());
+            i += 2;
+        } else if (starts_with(&body[i..], "${")) {
+            let close: usize = interpolation_end(&body, i + 2).unwrap_or(body.len());
+            let expression: Vec<String> = body[i + 2..close].to_vec();
+            if (expression.iter().all(|c| isempty(c.clone()))) {
+                let opened: (usize, usize) = body_positions[i];
+                log(LogLevel::Error, format!("Empty expression in string interpolation (line {}, column {}).", opened.0, opened.1).as_str());
+            }
+
+            segments.push(StringSegment::Text(text, text_start));
+            segments.push(StringSegment::Expression(expression, i + 2));
+            text = Vec::new();
+            i = close + 1;
+            text_start = i;
+        } else {
+            text.push(body[i].clone());
+            i += 1;
+        }
+    }
+
+    if (segments.is_empty()) {
+        tokens.push(token(unescape(&text, &body_positions[text_start..]).as_str(), TokenBase::String, start));
+        return;
+    }
+    segments.push(StringSegment::Text(text, text_start));
+
+    tokens.push(token("\"", TokenBase::TemplateStart, start));
+    for segment in segments {
+        match segment {
+            StringSegment::Text(text, offset) => {
+                let at: (usize, usize) = body_positions.get(offset).cloned().unwrap_or(end);
+                tokens.push(token(unescape(&text, &body_positions[offset..]).as_str(), TokenBase::String, at));
+            }
+            StringSegment::Expression(expression, offset) => {
+                let close: usize = offset + expression.len();
+                tokens.push(token("${", TokenBase::InterpolationStart, body_positions[offset - 2]));
+                tokens.extend(lex(expression, &body_positions[offset..close]));
+                tokens.push(token("}", TokenBase::InterpolationEnd, body_positions[close]));
+            }
+        }
+    }
+    tokens.push(token("\"", TokenBase::TemplateEnd, end));
+}
+
+fn unescape(text: &[String], positions: &[(usize, usize)]) -> String {
+    match escape_seq(text.concat().as_str()) {
+        Ok(string) => string,
+        Err((index, err)) => {
+            let at: (usize, usize) = positions[index];
+            log(LogLevel::Error, format!("{} (line {}, column {})", err, at.0, at.1).as_str());
+            String::new()
+        }
+    }
+}
+
+// Removes the line break after an opening """, a whitespace-only last line and the
+// indentation shared by every non-blank line of a triple quoted string.
+fn strip_indentation(body: &mut Vec<String>, body_positions: &mut Vec<(usize, usize)>) {
+    let mut lines: Vec<Line> = vec![(Vec::new(), Vec::new())];
+    for (character, position) in body.iter().zip(body_positions.iter()) {
+        let line = lines.last_mut().unwrap();
+        line.0.push(character.clone());
+        line.1.push(*position);
+        if (character == "\n") {
+            lines.push((Vec::new(), Vec::new()));
+        }
+    }
+
+    let blank = |line: &Vec<String>| line.iter().all(|c| c == " " || c == "\t" || c == "\n" || c == "\r");
+    if (lines.len() > 1 && blank(&lines[0].0)) {
+        lines.remove(0);
+    }
+    if (lines.len() > 1 && blank(&lines[lines.len() - 1].0)) {
+        lines.pop();
+        let last = lines.last_mut().unwrap();
+        last.0.pop();
+        last.1.pop();
+    }
+
+    let indentation: usize = lines.iter()
+        .filter(|(line, _)| !blank(line))
+        .map(|(line, _)| line.iter().take_while(|c| *c == " " || *c == "\t").count())
+        .min()
+        .unwrap_or(0);
+
+    body.clear();
+    body_positions.clear();
+    for (line, positions) in lines {
+        let strip: usize = line.iter().take(indentation).take_while(|c| *c == " " || *c == "\t").count();
+        body.extend(line[strip..].iter().cloned());
+        body_positions.extend(positions[strip..].iter().cloned());
+    }
+}
+
+pub fn is_raw_string(code: &[String]) -> bool {
+    if (code[0] != "r") {
+        return false;
+    }
+    let hashes: usize = code[1..].iter().take_while(|c| *c == "#").count();
+    code.len() > hashes + 1 && code[hashes + 1] == "\""
+}
+
+// Scans r"..." and r#"..."# literals, the content is kept as written without escapes or interpolation.
+pub fn lex_raw_string(code: &mut Vec<String>, positions: &[(usize, usize)], tokens: &mut Vec<Token>) {
+    let start: (usize, usize) = here(code, positions);
+    shift(code);
+
+    let mut hashes: usize = 0;
+    while (code[0] == "#") {
+        hashes += 1;
+        shift(code);
+    }
+    shift(code);
+
+    let closing: String = format!("\"{}", "#".repeat(hashes));
+    let mut string: String = String::new();
+    while (!starts_with(code, closing.as_str())) {
+        if (at_end(code)) {
+            log(LogLevel::Error, format!("Unterminated raw string literal, missing a closing {} (line {}, column {}).", closing, start.0, start.1).as_str());
+            return;
+        }
+        string += shift(code).unwrap_or_default().as_str();
+    }
+    for _ in 0..=hashes {
+        shift(code);
+    }
+
+    tokens.push(token(string.as_str(), TokenBase::String, start));
+}
@@ -1,2 +1,3 @@
 pub mod color;
+#[allow(clippy::module_inception)]
 pub mod logger;
\ No newline at end of file
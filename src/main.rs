@@ -7,26 +7,63 @@ extern crate alloc;
 
 mod lexer;
 mod utils;
+mod unicode;
 mod ast;
 mod runtime;
 mod logger;
 mod funcs;
+mod checker;
 
 use alloc::string::{String, ToString};
 use lexer::lexer::tokenize;
 use logger::logger::{log, LogLevel};
-use runtime::env::{setup_fn, setup_scopes, Environment};
+use runtime::env::{setup_fn, Environment, ModuleScope, Settings};
 use std::{collections::HashMap, fs::File, hash::Hash, io::{self, BufReader, Read, Write}};
 
-use crate::{ast::{ast::{Program, Statment}, parser::{self, produce_ast}}, runtime::{interpreter::evaluate, values::{BooleanVal, NullVal, ValueType}}, utils::{clear_terminal, parse_value}};
+use crate::{checker::checker::check_program, ast::{ast::{Program, Statment}, parser::{self, produce_ast}}, runtime::{errors::{uncaught_message, Eval, Interrupt}, interpreter::{display_value, evaluate, run_entry}, modules::{imported_modules, run_module}, values::{BooleanVal, NullVal, ValueType}}, utils::{clear_terminal, parse_value}};
+
+// The interpreter recurses for every nested call, MAX_CALL_DEPTH calls must fit in this stack.
+const STACK_SIZE: usize = 256 * 1024 * 1024;
 
 fn main() {
+    let runner = std::thread::Builder::new().stack_size(STACK_SIZE).spawn(run).unwrap();
+    // A panic in the interpreter has already been printed, resuming it exits with a failure code.
+    if let Err(panic) = runner.join() {
+        std::panic::resume_unwind(panic);
+    }
+}
+
+fn run() {
     let mut input = String::new();
-    let mut env: Environment = Environment::new();
+    // The file or the REPL is a module like the ones it imports.
+    let mut env: Environment = Environment::in_module(&ModuleScope::new(), Settings::default());
 
-    setup_scopes(&mut env);
     setup_fn(&mut env);
 
+    // `henox check file.va` only reports type errors and fails on any, `henox file.va args...` lists them
+    // as warnings and runs the file anyway, the arguments after the file are passed to its entry point.
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.as_slice() {
+        [command, path] if command == "check" => {
+            check_file(path, &mut env, true);
+            log(LogLevel::Info, format!("No problems found in {}.", path).as_str());
+            return;
+        }
+        [path, script_args @ ..] if path != "check" => {
+            let program: Statment = check_file(path, &mut env, false);
+            match run_module(path, || evaluate(&program, &mut env)).and_then(|_| run_entry(&program, script_args, &mut env)) {
+                Ok(code) => std::process::exit(code),
+                Err(Interrupt::Throw(thrown)) => log(LogLevel::Error, uncaught_message(&thrown, &mut env).as_str()),
+                Err(_) => {}
+            }
+            return;
+        }
+        [] => {}
+        _ => {
+            log(LogLevel::Error, "Usage: henox [check] [file.va] [args...]");
+        }
+    }
+
     log(LogLevel::Warn, "VaporScript 0.1 (ALPHA)\n");
     loop {
         input = String::new();
@@ -43,8 +80,17 @@ fn main() {
             clear_terminal();
         } else {
             let program: Statment = parser::produce_ast(&input);
-            let result: ValueType = evaluate(&program, &mut env);
-            println!("\n{}", format!("{:#?}", result));
+            let result: Eval<String> = evaluate(&program, &mut env).and_then(|result| match result {
+                ValueType::FnVoid(_) | ValueType::None() => Ok(String::new()),
+                _ => display_value(&result, true, &mut env)
+            });
+            match result {
+                Ok(text) if text.is_empty() => {}
+                Ok(text) => println!("{}", text),
+                Err(Interrupt::Throw(thrown)) => log(LogLevel::Warn, uncaught_message(&thrown, &mut env).as_str()),
+                // A program turns a return or break outside of a function into a thrown SyntaxError.
+                Err(Interrupt::Return(_) | Interrupt::Break) => {}
+            }
 
 	    /*
             let mut file = File::open("./lang/test.txt").unwrap();
@@ -57,4 +103,32 @@ fn main() {
 	    */
         }
     }
-}
\ No newline at end of file
+}
+
+// Parses a file and runs the type checker over it and the modules it imports. Every problem is
+// listed, `strict` exits when there is one, otherwise they are only warnings as the checker also
+// flags code the runtime handles (a missing name inside a try, ...).
+fn check_file(path: &String, env: &mut Environment, strict: bool) -> Statment {
+    let contents: String = std::fs::read_to_string(path).unwrap_or_else(|err| {
+        log(LogLevel::Error, format!("Cannot read {}: {}.", path, err).as_str());
+        String::new()
+    });
+    let program: Statment = produce_ast(&contents);
+
+    let globals: Vec<String> = env.names();
+    let mut problems: Vec<String> = check_program(&program, &globals);
+    for (module, code) in imported_modules(path, &program) {
+        problems.extend(check_program(&code, &globals).into_iter().map(|problem| format!("In {}: {}", module, problem)));
+    }
+    for problem in &problems {
+        log(LogLevel::Warn, problem.as_str());
+    }
+    if (!problems.is_empty()) {
+        let summary: String = format!("Found {} problem{} in {}", problems.len(), if (problems.len() == 1) { "" } else { "s" }, path);
+        if (strict) {
+            log(LogLevel::Error, format!("{}.", summary).as_str());
+        }
+        log(LogLevel::Warn, format!("{}, running it anyway.", summary).as_str());
+    }
+    program
+}
@@ -0,0 +1,487 @@
+// Author: ContentGamer
+// Arbitrary precision integers, stored as a sign and a magnitude of base 2^32 limbs
+
+use core::{cmp::Ordering, fmt};
+
+// The limbs hold the magnitude with the least significant limb first and no trailing zero limbs,
+// zero has no limbs and is never negative.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BigInt {
+    negative: bool,
+    limbs: Vec<u32>
+}
+
+fn trim(limbs: &mut Vec<u32>) {
+    while (limbs.last() == Some(&0)) {
+        limbs.pop();
+    }
+}
+
+fn cmp_mag(left: &[u32], right: &[u32]) -> Ordering {
+    if (left.len() != right.len()) {
+        return left.len().cmp(&right.len());
+    }
+    for i in (0..left.len()).rev() {
+        if (left[i] != right[i]) {
+            return left[i].cmp(&right[i]);
+        }
+    }
+    Ordering::Equal
+}
+
+fn add_mag(left: &[u32], right: &[u32]) -> Vec<u32> {
+    let mut sum: Vec<u32> = Vec::with_capacity(left.len().max(right.len()) + 1);
+    let mut carry: u64 = 0;
+    for i in 0..left.len().max(right.len()) {
+        let total: u64 = *left.get(i).unwrap_or(&0) as u64 + *right.get(i).unwrap_or(&0) as u64 + carry;
+        sum.push(total as u32);
+        carry = total >> 32;
+    }
+    if (carry > 0) {
+        sum.push(carry as u32);
+    }
+    sum
+}
+
+// `left` must not be smaller than `right`.
+fn sub_mag(left: &[u32], right: &[u32]) -> Vec<u32> {
+    let mut difference: Vec<u32> = Vec::with_capacity(left.len());
+    let mut borrow: i64 = 0;
+    for (i, limb) in left.iter().enumerate() {
+        let mut total: i64 = *limb as i64 - *right.get(i).unwrap_or(&0) as i64 - borrow;
+        borrow = 0;
+        if (total < 0) {
+            total += 1 << 32;
+            borrow = 1;
+        }
+        difference.push(total as u32);
+    }
+    trim(&mut difference);
+    difference
+}
+
+fn mul_mag(left: &[u32], right: &[u32]) -> Vec<u32> {
+    if (left.is_empty() || right.is_empty()) {
+        return Vec::new();
+    }
+    let mut product: Vec<u32> = vec![0; left.len() + right.len()];
+    for (i, l) in left.iter().enumerate() {
+        let mut carry: u64 = 0;
+        for (j, r) in right.iter().enumerate() {
+            let total: u64 = *l as u64 * *r as u64 + product[i + j] as u64 + carry;
+            product[i + j] = total as u32;
+            carry = total >> 32;
+        }
+        product[i + right.len()] = carry as u32;
+    }
+    trim(&mut product);
+    product
+}
+
+fn shl_mag(limbs: &[u32], shift: usize) -> Vec<u32> {
+    if (limbs.is_empty()) {
+        return Vec::new();
+    }
+    let (whole, bits) = (shift / 32, shift % 32);
+    let mut shifted: Vec<u32> = vec![0; whole];
+    let mut carry: u32 = 0;
+    for limb in limbs {
+        shifted.push(if (bits == 0) { *limb } else { (limb << bits) | carry });
+        carry = if (bits == 0) { 0 } else { limb >> (32 - bits) };
+    }
+    if (carry > 0) {
+        shifted.push(carry);
+    }
+    shifted
+}
+
+fn shr_mag(limbs: &[u32], shift: usize) -> Vec<u32> {
+    let (whole, bits) = (shift / 32, shift % 32);
+    if (whole >= limbs.len()) {
+        return Vec::new();
+    }
+    let mut shifted: Vec<u32> = Vec::with_capacity(limbs.len() - whole);
+    for i in whole..limbs.len() {
+        let high: u32 = if (bits == 0) { 0 } else { limbs.get(i + 1).map_or(0, |next| next << (32 - bits)) };
+        shifted.push((limbs[i] >> bits) | high);
+    }
+    trim(&mut shifted);
+    shifted
+}
+
+// Divides by a single limb, returning the quotient and the remainder.
+fn divmod_small(limbs: &[u32], divisor: u32) -> (Vec<u32>, u32) {
+    let mut quotient: Vec<u32> = vec![0; limbs.len()];
+    let mut remainder: u64 = 0;
+    for i in (0..limbs.len()).rev() {
+        let current: u64 = (remainder << 32) | limbs[i] as u64;
+        quotient[i] = (current / divisor as u64) as u32;
+        remainder = current % divisor as u64;
+    }
+    trim(&mut quotient);
+    (quotient, remainder as u32)
+}
+
+// Long division one bit at a time, `divisor` must not be zero.
+fn divmod_mag(limbs: &[u32], divisor: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    if (divisor.len() == 1) {
+        let (quotient, remainder) = divmod_small(limbs, divisor[0]);
+        return (quotient, if (remainder == 0) { Vec::new() } else { vec![remainder] });
+    }
+    if (cmp_mag(limbs, divisor) == Ordering::Less) {
+        return (Vec::new(), limbs.to_vec());
+    }
+
+    let mut quotient: Vec<u32> = vec![0; limbs.len()];
+    let mut remainder: Vec<u32> = Vec::new();
+    for bit in (0..limbs.len() * 32).rev() {
+        remainder = shl_mag(&remainder, 1);
+        if ((limbs[bit / 32] >> (bit % 32)) & 1 == 1) {
+            if (remainder.is_empty()) {
+                remainder.push(1);
+            } else {
+                remainder[0] |= 1;
+            }
+        }
+        if (cmp_mag(&remainder, divisor) != Ordering::Less) {
+            remainder = sub_mag(&remainder, divisor);
+            quotient[bit / 32] |= 1 << (bit % 32);
+        }
+    }
+    trim(&mut quotient);
+    (quotient, remainder)
+}
+
+impl BigInt {
+    fn from_parts(negative: bool, mut limbs: Vec<u32>) -> Self {
+        trim(&mut limbs);
+        Self {
+            negative: negative && !limbs.is_empty(),
+            limbs
+        }
+    }
+
+    pub fn zero() -> Self {
+        Self::from_parts(false, Vec::new())
+    }
+
+    pub fn from_i64(value: i64) -> Self {
+        let magnitude: u64 = value.unsigned_abs();
+        Self::from_parts(value < 0, vec![magnitude as u32, (magnitude >> 32) as u32])
+    }
+
+    // Only whole, finite floats can be converted.
+    pub fn from_f64(value: f64) -> Option<Self> {
+        if (!value.is_finite() || value.fract() != 0.0) {
+            return None;
+        }
+        let mut remaining: f64 = value.abs();
+        let mut limbs: Vec<u32> = Vec::new();
+        while (remaining >= 1.0) {
+            limbs.push((remaining % 4294967296.0) as u32);
+            remaining = (remaining / 4294967296.0).floor();
+        }
+        Some(Self::from_parts(value < 0.0, limbs))
+    }
+
+    // Parses base 10 digits with an optional sign.
+    pub fn parse(text: &str) -> Option<Self> {
+        let (negative, digits) = match text.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, text.strip_prefix('+').unwrap_or(text))
+        };
+        if (digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit())) {
+            return None;
+        }
+
+        let mut limbs: Vec<u32> = Vec::new();
+        for digit in digits.chars() {
+            limbs = mul_mag(&limbs, &[10]);
+            limbs = add_mag(&limbs, &[digit as u32 - '0' as u32]);
+            trim(&mut limbs);
+        }
+        Some(Self::from_parts(negative, limbs))
+    }
+
+    pub fn to_i64(&self) -> Option<i64> {
+        if (self.limbs.len() > 2) {
+            return None;
+        }
+        let magnitude: u64 = self.limbs.first().copied().unwrap_or(0) as u64 | (self.limbs.get(1).copied().unwrap_or(0) as u64) << 32;
+        if (self.negative) {
+            if (magnitude > i64::MAX as u64 + 1) { None } else { Some((magnitude as i64).wrapping_neg()) }
+        } else {
+            i64::try_from(magnitude).ok()
+        }
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        let magnitude: f64 = self.limbs.iter().rev().fold(0.0, |total, limb| total * 4294967296.0 + *limb as f64);
+        if (self.negative) { -magnitude } else { magnitude }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    // The number of bits in the magnitude, 0 for zero.
+    pub fn bits(&self) -> u64 {
+        match self.limbs.last() {
+            Some(last) => self.limbs.len() as u64 * 32 - last.leading_zeros() as u64,
+            None => 0
+        }
+    }
+
+    pub fn neg(&self) -> Self {
+        Self::from_parts(!self.negative, self.limbs.clone())
+    }
+
+    pub fn add(&self, other: &Self) -> Self {
+        if (self.negative == other.negative) {
+            return Self::from_parts(self.negative, add_mag(&self.limbs, &other.limbs));
+        }
+        match cmp_mag(&self.limbs, &other.limbs) {
+            Ordering::Less => Self::from_parts(other.negative, sub_mag(&other.limbs, &self.limbs)),
+            _ => Self::from_parts(self.negative, sub_mag(&self.limbs, &other.limbs))
+        }
+    }
+
+    pub fn sub(&self, other: &Self) -> Self {
+        self.add(&other.neg())
+    }
+
+    pub fn mul(&self, other: &Self) -> Self {
+        Self::from_parts(self.negative != other.negative, mul_mag(&self.limbs, &other.limbs))
+    }
+
+    // The quotient rounded down and the matching remainder, which has the sign of the divisor.
+    // None when dividing by zero.
+    pub fn div_floor(&self, other: &Self) -> Option<(Self, Self)> {
+        if (other.is_zero()) {
+            return None;
+        }
+        let (quotient, remainder) = divmod_mag(&self.limbs, &other.limbs);
+        let mut quotient: Self = Self::from_parts(self.negative != other.negative, quotient);
+        let mut remainder: Self = Self::from_parts(self.negative, remainder);
+        if (!remainder.is_zero() && remainder.negative != other.negative) {
+            quotient = quotient.sub(&Self::from_i64(1));
+            remainder = remainder.add(other);
+        }
+        Some((quotient, remainder))
+    }
+
+    // The remainder of truncated division, it has the sign of `self` like `%` on ints.
+    pub fn rem(&self, other: &Self) -> Option<Self> {
+        if (other.is_zero()) {
+            return None;
+        }
+        Some(Self::from_parts(self.negative, divmod_mag(&self.limbs, &other.limbs).1))
+    }
+
+    pub fn pow(&self, mut exponent: u32) -> Self {
+        let mut result: Self = Self::from_i64(1);
+        let mut base: Self = self.clone();
+        while (exponent > 0) {
+            if (exponent & 1 == 1) {
+                result = result.mul(&base);
+            }
+            exponent >>= 1;
+            if (exponent > 0) {
+                base = base.mul(&base);
+            }
+        }
+        result
+    }
+
+    // Two's complement limbs of a fixed length, long enough to hold the sign.
+    fn to_twos(&self, length: usize) -> Vec<u32> {
+        let mut limbs: Vec<u32> = if (self.negative) { sub_mag(&self.limbs, &[1]) } else { self.limbs.clone() };
+        limbs.resize(length, 0);
+        if (self.negative) {
+            limbs.iter_mut().for_each(|limb| *limb = !*limb);
+        }
+        limbs
+    }
+
+    fn from_twos(mut limbs: Vec<u32>) -> Self {
+        if (limbs.last().is_some_and(|limb| limb >> 31 == 1)) {
+            limbs.iter_mut().for_each(|limb| *limb = !*limb);
+            return Self::from_parts(true, add_mag(&limbs, &[1]));
+        }
+        Self::from_parts(false, limbs)
+    }
+
+    // `&`, `|` and `^` act on the infinite two's complement form, like on ints.
+    pub fn bitwise(&self, other: &Self, operation: fn(u32, u32) -> u32) -> Self {
+        let length: usize = self.limbs.len().max(other.limbs.len()) + 1;
+        let (left, right) = (self.to_twos(length), other.to_twos(length));
+        Self::from_twos(left.iter().zip(right.iter()).map(|(l, r)| operation(*l, *r)).collect())
+    }
+
+    pub fn not(&self) -> Self {
+        self.neg().sub(&Self::from_i64(1))
+    }
+
+    pub fn shl(&self, shift: usize) -> Self {
+        Self::from_parts(self.negative, shl_mag(&self.limbs, shift))
+    }
+
+    // Shifting right rounds down, so negative values stay negative.
+    pub fn shr(&self, shift: usize) -> Self {
+        if (self.negative) {
+            let magnitude: Vec<u32> = shr_mag(&sub_mag(&self.limbs, &[1]), shift);
+            return Self::from_parts(false, magnitude).add(&Self::from_i64(1)).neg();
+        }
+        Self::from_parts(false, shr_mag(&self.limbs, shift))
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => cmp_mag(&self.limbs, &other.limbs),
+            (true, true) => cmp_mag(&other.limbs, &self.limbs)
+        }
+    }
+}
+
+// Base 10, nine digits are split off at a time.
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if (self.is_zero()) {
+            return write!(f, "0");
+        }
+        let mut chunks: Vec<u32> = Vec::new();
+        let mut remaining: Vec<u32> = self.limbs.clone();
+        while (!remaining.is_empty()) {
+            let (quotient, chunk) = divmod_small(&remaining, 1_000_000_000);
+            chunks.push(chunk);
+            remaining = quotient;
+        }
+
+        let mut text: String = if (self.negative) { "-".to_string() } else { String::new() };
+        text += chunks.pop().unwrap_or(0).to_string().as_str();
+        for chunk in chunks.iter().rev() {
+            text += format!("{:09}", chunk).as_str();
+        }
+        write!(f, "{}", text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn big(text: &str) -> BigInt {
+        BigInt::parse(text).unwrap()
+    }
+
+    #[test]
+    fn parse_and_display() {
+        assert_eq!(big("0").to_string(), "0");
+        assert_eq!(big("-0").to_string(), "0");
+        assert_eq!(big("+42").to_string(), "42");
+        assert_eq!(big("-123456789012345678901234567890").to_string(), "-123456789012345678901234567890");
+        assert_eq!(big("1000000000000000000").to_string(), "1000000000000000000");
+        assert!(BigInt::parse("").is_none());
+        assert!(BigInt::parse("12a").is_none());
+        assert!(!big("-0").is_negative());
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(BigInt::from_i64(i64::MIN).to_string(), "-9223372036854775808");
+        assert_eq!(BigInt::from_i64(i64::MIN).to_i64(), Some(i64::MIN));
+        assert_eq!(big("9223372036854775808").to_i64(), None);
+        assert_eq!(big("-9223372036854775809").to_i64(), None);
+        assert_eq!(BigInt::from_f64(1e20).unwrap().to_string(), "100000000000000000000");
+        assert!(BigInt::from_f64(1.5).is_none());
+        assert!(BigInt::from_f64(f64::NAN).is_none());
+    }
+
+    #[test]
+    fn carries_and_borrows() {
+        assert_eq!(big("4294967295").add(&big("1")).to_string(), "4294967296");
+        assert_eq!(big("18446744073709551615").add(&big("1")).to_string(), "18446744073709551616");
+        assert_eq!(big("18446744073709551616").sub(&big("1")).to_string(), "18446744073709551615");
+        assert_eq!(big("4294967296").sub(&big("4294967296")).to_string(), "0");
+        assert_eq!(big("4294967295").mul(&big("4294967295")).to_string(), "18446744065119617025");
+    }
+
+    #[test]
+    fn signs() {
+        assert_eq!(big("5").sub(&big("8")).to_string(), "-3");
+        assert_eq!(big("-5").add(&big("8")).to_string(), "3");
+        assert_eq!(big("-5").add(&big("5")).to_string(), "0");
+        assert!(!big("-5").add(&big("5")).is_negative());
+        assert_eq!(big("-5").mul(&big("-4")).to_string(), "20");
+        assert_eq!(big("-5").mul(&big("0")).to_string(), "0");
+        assert!(big("-10") < big("-2"));
+        assert!(big("-2") < big("1"));
+        assert!(big("18446744073709551616") > big("18446744073709551615"));
+    }
+
+    #[test]
+    fn division_rounds_down() {
+        let divide = |l: &str, r: &str| {
+            let (quotient, remainder) = big(l).div_floor(&big(r)).unwrap();
+            (quotient.to_string(), remainder.to_string())
+        };
+        assert_eq!(divide("7", "2"), ("3".to_string(), "1".to_string()));
+        assert_eq!(divide("-7", "2"), ("-4".to_string(), "1".to_string()));
+        assert_eq!(divide("7", "-2"), ("-4".to_string(), "-1".to_string()));
+        assert_eq!(divide("-7", "-2"), ("3".to_string(), "-1".to_string()));
+        assert_eq!(divide("-8", "2"), ("-4".to_string(), "0".to_string()));
+        assert_eq!(divide("340282366920938463463374607431768211456", "18446744073709551616"), ("18446744073709551616".to_string(), "0".to_string()));
+        assert!(big("1").div_floor(&big("0")).is_none());
+    }
+
+    #[test]
+    fn remainder_follows_the_dividend() {
+        assert_eq!(big("-7").rem(&big("2")).unwrap().to_string(), "-1");
+        assert_eq!(big("7").rem(&big("-2")).unwrap().to_string(), "1");
+        assert!(big("7").rem(&big("0")).is_none());
+    }
+
+    #[test]
+    fn powers() {
+        assert_eq!(big("2").pow(64).to_string(), "18446744073709551616");
+        assert_eq!(big("-3").pow(3).to_string(), "-27");
+        assert_eq!(big("-3").pow(0).to_string(), "1");
+        assert_eq!(big("0").pow(0).to_string(), "1");
+    }
+
+    #[test]
+    fn shifts() {
+        assert_eq!(big("1").shl(100).to_string(), "1267650600228229401496703205376");
+        assert_eq!(big("1267650600228229401496703205376").shr(99).to_string(), "2");
+        assert_eq!(big("-1").shl(33).to_string(), "-8589934592");
+        assert_eq!(big("-7").shr(1).to_string(), "-4");
+        assert_eq!(big("-1").shr(1000).to_string(), "-1");
+        assert_eq!(big("7").shr(1000).to_string(), "0");
+        assert_eq!(big("1").shl(64).bits(), 65);
+        assert_eq!(big("0").bits(), 0);
+    }
+
+    #[test]
+    fn bitwise_uses_twos_complement() {
+        assert_eq!(big("-1").bitwise(&big("255"), |l, r| l & r).to_string(), "255");
+        assert_eq!(big("-256").bitwise(&big("255"), |l, r| l | r).to_string(), "-1");
+        assert_eq!(big("12").bitwise(&big("10"), |l, r| l ^ r).to_string(), "6");
+        assert_eq!(big("-18446744073709551616").bitwise(&big("18446744073709551615"), |l, r| l & r).to_string(), "0");
+        assert_eq!(big("5").not().to_string(), "-6");
+        assert_eq!(big("-1").not().to_string(), "0");
+    }
+}
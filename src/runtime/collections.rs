@@ -0,0 +1,134 @@
+// Author: ContentGamer
+
+use std::{collections::HashMap, hash::Hash};
+
+use alloc::{string::{String, ToString}, vec::Vec};
+
+use super::{bigint::BigInt, numbers::{bigint, integer}, values::{NullVal, NumberVal, StringVal, ValueType}};
+use crate::utils::parse_value;
+
+// A hash map that remembers the order its keys were first inserted in. Objects, maps and
+// sets are built on it so printing and iterating them gives the same order on every run.
+#[derive(Debug, Clone)]
+pub struct OrderedMap<K, V> {
+    entries: Vec<(K, V)>,
+    index: HashMap<K, usize>
+}
+
+impl<K: Hash + Eq + Clone, V> OrderedMap<K, V> {
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            index: HashMap::new()
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.index.contains_key(key)
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.index.get(key).map(|indx| &self.entries[*indx].1)
+    }
+
+    // Overwriting a key keeps its original position.
+    pub fn insert(&mut self, key: K, value: V) {
+        match self.index.get(&key) {
+            Some(indx) => self.entries[*indx].1 = value,
+            None => {
+                self.index.insert(key.clone(), self.entries.len());
+                self.entries.push((key, value));
+            }
+        }
+    }
+
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let indx: usize = self.index.remove(key)?;
+        let (_, value) = self.entries.remove(indx);
+        for (_, position) in self.index.iter_mut() {
+            if (*position > indx) {
+                *position -= 1;
+            }
+        }
+        Some(value)
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.index.clear();
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.entries.iter().map(|(key, value)| (key, value))
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.entries.iter().map(|(key, _)| key)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.entries.iter().map(|(_, value)| value)
+    }
+}
+
+impl<K: Hash + Eq + Clone, V> Default for OrderedMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// The values that can be used as Map keys and Set members. Whole floats that fit an int are
+// stored as that int so 1 and 1.0 are the same key, other floats are stored by their bits
+// with every NaN folded into one, so equal numbers hash equally.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum HashKey {
+    Null,
+    Boolean(bool),
+    Integer(i64),
+    BigInt(String),
+    Number(u64),
+    String(String)
+}
+
+impl HashKey {
+    pub fn from_value(value: &ValueType) -> Option<HashKey> {
+        match value {
+            ValueType::Null(_) | ValueType::None() => Some(HashKey::Null),
+            ValueType::Boolean(boolean) => Some(HashKey::Boolean(boolean.value)),
+            ValueType::Integer(int) => Some(HashKey::Integer(int.value)),
+            // Bigints that fit an int share its key, so 1n and 1 are the same key like they are equal.
+            ValueType::BigInt(big) => Some(match big.value.to_i64() {
+                Some(int) => HashKey::Integer(int),
+                None => HashKey::BigInt(big.value.to_string())
+            }),
+            ValueType::Number(num) | ValueType::FnNumber(num) => {
+                if (num.value.fract() == 0.0 && num.value >= i64::MIN as f64 && num.value < i64::MAX as f64) {
+                    return Some(HashKey::Integer(num.value as i64));
+                }
+                let number: f64 = if (num.value.is_nan()) { f64::NAN } else { num.value };
+                Some(HashKey::Number(number.to_bits()))
+            }
+            ValueType::String(string) | ValueType::FnString(string) => Some(HashKey::String(string.value.clone())),
+            _ => None
+        }
+    }
+
+    pub fn to_value(&self) -> ValueType {
+        match self {
+            HashKey::Null => ValueType::Null(NullVal { base: "NullVal".to_string(), value: None }),
+            HashKey::Boolean(boolean) => parse_value(boolean),
+            HashKey::Integer(int) => integer(*int),
+            HashKey::BigInt(digits) => bigint(BigInt::parse(digits).unwrap_or(BigInt::zero())),
+            HashKey::Number(bits) => ValueType::Number(NumberVal { base: "NumberVal".to_string(), value: f64::from_bits(*bits) }),
+            HashKey::String(string) => ValueType::String(StringVal { base: "StringVal".to_string(), value: string.clone() })
+        }
+    }
+}
@@ -1,79 +1,289 @@
-use std::collections::HashMap;
-use crate::{funcs, logger::logger::{log, LogLevel}, runtime::values::FuncVoid, utils::parse_value};
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+use crate::{ast::ast::TypeAnnotation, funcs, logger::logger::{log, LogLevel}, runtime::values::FuncVoid, utils::parse_value};
 
-use super::values::{NativeFn, NullVal, NumberVal, ObjectVal, ValueType};
+use super::{errors::{fault, Eval}, types::check_type, values::{NativeFn, NullVal, NumberVal, ObjectVal, ValueType}};
 
-pub fn setup_fn(env: &mut Environment) -> () {
-    env.declare_var(&"print".to_string(), &ValueType::NativeFn(NativeFn {
+fn declare_native(env: &mut Environment, name: &str, call: fn(args: Vec<ValueType>, env: &mut Environment) -> Eval) {
+    let _ = env.declare_var(&name.to_string(), &ValueType::NativeFn(NativeFn {
         base: "NativeFn".to_string(),
-        call: funcs::print
+        name: name.to_string(),
+        call
     }), true);
 }
 
-pub fn setup_scopes(env: &mut Environment) -> () {
-    env.declare_var(&"true".to_string(), &parse_value(&true), true);
-    env.declare_var(&"false".to_string(), &parse_value(&false), true);
+pub fn setup_fn(env: &mut Environment) {
+    declare_native(env, "print", funcs::print);
+    declare_native(env, "str", funcs::str);
+    declare_native(env, "json", funcs::json);
+    declare_native(env, "Map", funcs::map);
+    declare_native(env, "Set", funcs::set);
+    declare_native(env, "Error", funcs::error);
+
+    declare_native(env, "int", funcs::int);
+    declare_native(env, "float", funcs::float_fn);
+    declare_native(env, "bigint", funcs::bigint_fn);
+    declare_native(env, "num", funcs::num);
+    declare_native(env, "settings", funcs::settings);
+
+    declare_native(env, "len", funcs::len);
+    declare_native(env, "chars", funcs::chars);
+    declare_native(env, "bytes", funcs::bytes);
+    declare_native(env, "graphemes", funcs::graphemes_fn);
+}
+
+// What `/` and `//` do with a zero divisor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DivisionMode {
+    // Raise an ArithmeticError.
+    Error,
+    // Follow IEEE 754 and produce inf, -inf or NaN.
+    Ieee
+}
+
+// Options that hold for the whole engine rather than a single scope.
+#[derive(Debug, Clone, Copy)]
+pub struct Settings {
+    pub division: DivisionMode,
+    // print writes through the logger, with the time in front of every line.
+    pub advanced_logging: bool
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            division: DivisionMode::Error,
+            advanced_logging: false
+        }
+    }
+}
+
+// The top level scope of a file. It outlives the evaluation of the file, the functions declared
+// in it keep it and run in it wherever they are called from.
+#[derive(Clone)]
+pub struct ModuleScope(pub Rc<RefCell<Environment>>);
+
+impl ModuleScope {
+    pub fn new() -> Self {
+        Self(Rc::new(RefCell::new(Environment::new())))
+    }
+
+    pub fn same(&self, other: &ModuleScope) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
 }
 
+// A module refers to itself through its functions, printing it would never end.
+impl core::fmt::Debug for ModuleScope {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("ModuleScope")
+    }
+}
+
+// A scope of variables. Blocks and function calls run in a child scope that owns its parent
+// for the duration of the call, lookups and assignments walk outwards through the parents.
+// The outermost scope of a file belongs to a module: it keeps no names of its own, they are
+// declared in and looked up from the module.
 #[derive(Debug, Clone)]
 pub struct Environment {
     pub constants: Vec<String>,
-    pub variables: HashMap<String, ValueType>
+    pub variables: HashMap<String, ValueType>,
+    // Variables declared with a type, every later assignment is checked against it.
+    pub annotations: HashMap<String, TypeAnnotation>,
+    // Names bound by `import { name }`, read from the exports of the other module on every lookup.
+    pub imports: HashMap<String, (ObjectVal, String)>,
+    // The exports of the module this is the top level of, kept up to date when they are assigned.
+    pub exports: Option<ObjectVal>,
+    pub parent: Option<Box<Environment>>,
+    pub module: Option<ModuleScope>,
+    pub settings: Settings
 }
 
 impl Environment {
     pub fn new() -> Self {
         Self {
             constants: Vec::new(),
-            variables: HashMap::new()
+            variables: HashMap::new(),
+            annotations: HashMap::new(),
+            imports: HashMap::new(),
+            exports: None,
+            parent: None,
+            module: None,
+            settings: Settings::default()
+        }
+    }
+
+    pub fn with_settings(settings: Settings) -> Self {
+        Self {
+            settings,
+            ..Self::new()
+        }
+    }
+
+    // The outermost scope of code running in `module`.
+    pub fn in_module(module: &ModuleScope, settings: Settings) -> Self {
+        Self {
+            module: Some(module.clone()),
+            ..Self::with_settings(settings)
+        }
+    }
+
+    // The module of the file this scope is part of, None for a scope made without one.
+    pub fn module_scope(&self) -> Option<ModuleScope> {
+        match &self.parent {
+            Some(parent) => parent.module_scope(),
+            None => self.module.clone()
+        }
+    }
+
+    // Runs `body` on the outermost scope, the module itself for the top level of a file.
+    fn with_top<T>(&mut self, body: impl FnOnce(&mut Environment) -> T) -> T {
+        if let Some(parent) = self.parent.as_mut() {
+            return parent.with_top(body);
+        }
+        match self.module.clone() {
+            Some(module) => body(&mut module.0.borrow_mut()),
+            None => body(self)
+        }
+    }
+
+    // The module a declaration in this scope goes to, only the top level of a file has one.
+    fn routed(&self) -> Option<ModuleScope> {
+        if (self.parent.is_some()) {
+            return None;
         }
+        self.module.clone()
     }
 
-    pub fn declare_var(&mut self, varname: &String, value: &ValueType, constant: bool) -> ValueType {
+    // Every name that can be looked up from this scope.
+    pub fn names(&mut self) -> Vec<String> {
+        let mut names: Vec<String> = self.variables.keys().cloned().collect();
+        if let Some(parent) = self.parent.as_mut() {
+            names.extend(parent.names());
+        } else if let Some(module) = self.module.clone() {
+            names.extend(module.0.borrow_mut().names());
+        }
+        names.extend(self.imports.keys().cloned());
+        names
+    }
+
+    // Runs `body` in a new scope nested inside `env`, the scope is dropped once it returns.
+    // Settings changed inside the scope stay changed for the engine.
+    pub fn scoped<T>(env: &mut Environment, body: impl FnOnce(&mut Environment) -> T) -> T {
+        let mut scope: Environment = Environment::with_settings(env.settings);
+        scope.parent = Some(Box::new(std::mem::replace(env, Environment::new())));
+
+        let result: T = body(&mut scope);
+        if let Some(parent) = scope.parent.take() {
+            *env = *parent;
+            env.settings = scope.settings;
+        }
+        result
+    }
+
+    fn resolve(&mut self, varname: &String) -> Option<&mut Environment> {
         if (self.variables.contains_key(varname)) {
-            log(LogLevel::Error, format!("Cannot declare a variable '{}' as it is already declared.", varname).as_str());
-            return ValueType::None();
+            return Some(self);
+        }
+        self.parent.as_mut()?.resolve(varname)
+    }
+
+    pub fn declare_var(&mut self, varname: &String, value: &ValueType, constant: bool) -> Eval {
+        if let Some(module) = self.routed() {
+            return module.0.borrow_mut().declare_var(varname, value, constant);
+        }
+        if (self.variables.contains_key(varname) || self.imports.contains_key(varname)) {
+            return fault("ReferenceError", format!("Cannot declare a variable '{}' as it is already declared.", varname));
         }
         self.variables.insert(varname.clone(), value.clone());
         if (constant) {
             self.constants.push(varname.clone());
         }
-        value.clone()
+        Ok(value.clone())
     }
     
-    pub fn assign_var(&mut self, varname: &String, value: &ValueType) -> ValueType {
-        if (!self.variables.contains_key(varname)) {
-            log(LogLevel::Error, format!("Cannot reassign variable '{}', as it is undefined (NotDefinedErr).", varname).as_str());
-            return ValueType::None();
+    pub fn assign_var(&mut self, varname: &String, value: &ValueType) -> Eval {
+        if let Some(scope) = self.resolve(varname) {
+            return scope.assign_here(varname, value);
         }
+        self.with_top(|top| {
+            if (top.variables.contains_key(varname)) {
+                return top.assign_here(varname, value);
+            }
+            if (top.imports.contains_key(varname)) {
+                return fault("TypeError", format!("Cannot reassign variable '{}', as it is imported from another module.", varname));
+            }
+            fault("ReferenceError", format!("Cannot reassign variable '{}', as it is undefined (NotDefinedErr).", varname))
+        })
+    }
+
+    fn assign_here(&mut self, varname: &String, value: &ValueType) -> Eval {
         if (self.constants.contains(varname)) {
-            log(LogLevel::Error, format!("Cannot reassign variable '{}', as it is a constant variable.", varname).as_str());
-            return ValueType::None();
+            return fault("TypeError", format!("Cannot reassign variable '{}', as it is a constant variable.", varname));
+        }
+        if let Some(annotation) = self.annotations.get(varname) {
+            check_type(value, annotation, format!("'{}'", varname))?;
         }
-        self.variables.remove(varname);
         self.variables.insert(varname.clone(), value.clone());
-        value.clone()
+        if let Some(exports) = &self.exports {
+            let exported: bool = exports.properties.borrow().contains_key(varname);
+            if (exported) {
+                exports.properties.borrow_mut().insert(varname.clone(), value.clone());
+            }
+        }
+        Ok(value.clone())
     }
-    
-    pub fn lookup_var(&mut self, varname: &String) -> ValueType {
-        if (!self.variables.contains_key(varname)) {
-            log(LogLevel::Error, format!("Cannot lookup for variable '{}', as it does not exist.", varname).as_str());
-            return ValueType::None();
+
+    // Later assignments to the variable are checked against `annotation`.
+    pub fn annotate(&mut self, varname: &String, annotation: &TypeAnnotation) {
+        match self.routed() {
+            Some(module) => module.0.borrow_mut().annotate(varname, annotation),
+            None => {
+                self.annotations.insert(varname.clone(), annotation.clone());
+            }
         }
-        self.variables.get(varname).unwrap_or(&ValueType::None()).clone()
     }
-}
 
-pub fn assign_var(env: &mut Environment, varname: &String, value: &ValueType) -> ValueType {
-    if (!env.variables.contains_key(varname)) {
-        log(LogLevel::Error, format!("Cannot reassign variable '{}', as it is undefined (NotDefinedErr).", varname).as_str());
-        return ValueType::None();
+    // Makes `local` read the export `name` of a module.
+    pub fn declare_import(&mut self, local: &String, exports: &ObjectVal, name: &String) -> Eval<()> {
+        if let Some(module) = self.routed() {
+            return module.0.borrow_mut().declare_import(local, exports, name);
+        }
+        if (self.variables.contains_key(local) || self.imports.contains_key(local)) {
+            return fault("ReferenceError", format!("Cannot declare a variable '{}' as it is already declared.", local));
+        }
+        self.imports.insert(local.clone(), (exports.clone(), name.clone()));
+        Ok(())
     }
-    if (env.constants.contains(varname)) {
-        log(LogLevel::Error, format!("Cannot reassign variable '{}', as it is a constant variable.", varname).as_str());
-        return ValueType::None();
+    
+    // Declares a variable that only ever holds values of the annotated type.
+    pub fn declare_typed(&mut self, varname: &String, value: &ValueType, constant: bool, annotation: &TypeAnnotation) -> Eval {
+        if let Some(module) = self.routed() {
+            return module.0.borrow_mut().declare_typed(varname, value, constant, annotation);
+        }
+        check_type(value, annotation, format!("'{}'", varname))?;
+        let value: ValueType = self.declare_var(varname, value, constant)?;
+        if (*annotation != TypeAnnotation::Any) {
+            self.annotations.insert(varname.clone(), annotation.clone());
+        }
+        Ok(value)
+    }
+
+    pub fn lookup_var(&mut self, varname: &String) -> Eval {
+        if let Some(scope) = self.resolve(varname) {
+            return Ok(scope.variables.get(varname).unwrap_or(&ValueType::None()).clone());
+        }
+        self.with_top(|top| {
+            if let Some(value) = top.variables.get(varname) {
+                return Ok(value.clone());
+            }
+            match top.imports.get(varname) {
+                Some((exports, name)) => Ok(exports.properties.borrow().get(name).cloned().unwrap_or(ValueType::None())),
+                None => fault("ReferenceError", format!("Cannot lookup for variable '{}', as it does not exist.", varname))
+            }
+        })
     }
-    env.variables.remove(varname);
-    env.variables.insert(varname.clone(), value.clone());
-    value.clone()
-}
\ No newline at end of file
+}
+
+pub fn assign_var(env: &mut Environment, varname: &String, value: &ValueType) -> Eval {
+    env.assign_var(varname, value)
+}
@@ -0,0 +1,112 @@
+// Author: ContentGamer
+// Thrown values, runtime faults and the call stack recorded for error traces
+
+use std::cell::RefCell;
+
+use super::{collections::OrderedMap, env::Environment, interpreter::display_value, values::{ArrayVal, ObjectVal, StringVal, ValueType}};
+
+// Anything that stops the normal evaluation of a statement and travels up to whoever handles it.
+#[derive(Debug, Clone)]
+pub enum Interrupt {
+    Throw(ValueType),
+    // `return` on its way to the function it was written in.
+    Return(ValueType),
+    // `break` on its way to the switch arm it was written in.
+    Break
+}
+
+// The result of evaluating a node, `?` passes a thrown value on to the caller.
+pub type Eval<T = ValueType> = Result<T, Interrupt>;
+
+// Calls nested deeper than this raise a RangeError instead of overflowing the native stack.
+pub const MAX_CALL_DEPTH: usize = 1000;
+
+// One active call, `line` and `column` locate the opening paranthesis of the call (0 when the
+// interpreter made the call itself, such as a toString method used for display).
+#[derive(Debug, Clone)]
+pub struct Frame {
+    pub name: String,
+    pub line: usize,
+    pub column: usize,
+    pub argc: usize
+}
+
+thread_local! {
+    static CALL_STACK: RefCell<Vec<Frame>> = const { RefCell::new(Vec::new()) };
+}
+
+pub fn push_frame(frame: Frame) -> Eval<()> {
+    if (CALL_STACK.with(|stack| stack.borrow().len()) >= MAX_CALL_DEPTH) {
+        return fault("RangeError", format!("Maximum recursion depth exceeded while calling {}, calls can nest at most {} deep.", frame.name, MAX_CALL_DEPTH));
+    }
+    CALL_STACK.with(|stack| stack.borrow_mut().push(frame));
+    Ok(())
+}
+
+pub fn pop_frame() {
+    CALL_STACK.with(|stack| stack.borrow_mut().pop());
+}
+
+fn frame_text(frame: &Frame) -> String {
+    let args: String = format!("{} arg{}", frame.argc, if (frame.argc == 1) { "" } else { "s" });
+    if (frame.line == 0) {
+        return format!("at {} ({})", frame.name, args);
+    }
+    format!("at {} (line {}, column {}, {})", frame.name, frame.line, frame.column, args)
+}
+
+// The functions being called right now, innermost first.
+pub fn stack_trace() -> Vec<String> {
+    CALL_STACK.with(|stack| stack.borrow().iter().rev().map(frame_text).collect())
+}
+
+fn string(value: &str) -> ValueType {
+    ValueType::String(StringVal {
+        base: "StringVal".to_string(),
+        value: value.to_string()
+    })
+}
+
+// Builds an error object `{ kind, message, stack }`, the stack lists the calls active when it was made.
+pub fn error_value(kind: &str, message: &str) -> ValueType {
+    let mut properties: OrderedMap<String, ValueType> = OrderedMap::new();
+    properties.insert("kind".to_string(), string(kind));
+    properties.insert("message".to_string(), string(message));
+    properties.insert("stack".to_string(), ValueType::Array(ArrayVal::new(stack_trace().iter().map(|frame| string(frame)).collect())));
+
+    ValueType::Object(ObjectVal::new(properties))
+}
+
+// A runtime fault raised as a catchable error, `kind` names the category such as TypeError.
+pub fn fault<T>(kind: &str, message: String) -> Eval<T> {
+    Err(Interrupt::Throw(error_value(kind, message.as_str())))
+}
+
+// Describes a thrown value that no catch clause handled, error objects show their kind, message and stack.
+pub fn uncaught_message(thrown: &ValueType, env: &mut Environment) -> String {
+    if let ValueType::Object(obj) = thrown {
+        let properties = obj.properties.borrow();
+        if let (Some(ValueType::String(kind)), Some(ValueType::String(message))) = (properties.get(&"kind".to_string()), properties.get(&"message".to_string())) {
+            let mut text: String = format!("Uncaught {}: {}", kind.value, message.value);
+            if let Some(ValueType::Array(stack)) = properties.get(&"stack".to_string()) {
+                // Runs of the same frame, as left by deep recursion, are printed once with a count.
+                let frames: Vec<String> = stack.contents.borrow().iter().map(|frame| frame.to_string()).collect();
+                let mut i: usize = 0;
+                while (i < frames.len()) {
+                    let repeats: usize = frames[i..].iter().take_while(|frame| **frame == frames[i]).count();
+                    text += format!("\n    {}", frames[i]).as_str();
+                    if (repeats > 1) {
+                        text += format!("\n    ... repeated {} more times", repeats - 1).as_str();
+                    }
+                    i += repeats;
+                }
+            }
+            return text;
+        }
+    }
+
+    match display_value(thrown, true, env) {
+        Ok(text) => format!("Uncaught {}", text),
+        Err(_) => "Uncaught a value that could not be displayed".to_string()
+    }
+}
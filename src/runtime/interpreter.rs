@@ -1,160 +1,421 @@
-use core::hash;
+use core::{cmp::Ordering, hash};
 use std::{any::Any, collections::HashMap, hash::Hash, rc::Rc};
 
 use alloc::{boxed::Box, string::ToString, vec::Vec};
 
-use crate::{ast::ast::{ArrayLiteral, AssignmentExpr, BinaryExpr, CallExpr, FuncDeclaration, Identifier, MemberExpr, NullLiteral, NumericLiteral, ObjectLiteral, Program, Statment, VarDeclaration}, logger::logger::{log, LogLevel}, utils::shift};
-use super::{env::{assign_var, Environment}, values::{ArrayVal, FuncVal, MemberVal, NativeFn, NullVal, NumberVal, ObjectVal, StringVal, ValueType}};
+use crate::{ast::{parser::top_level_fn, patterns::pattern_text, ast::{ArrayLiteral, AssignmentExpr, BinaryExpr, CallExpr, FuncDeclaration, Identifier, InterpolatedString, MemberExpr, NullLiteral, NumericLiteral, ObjectLiteral, Program, SliceExpr, Statment, TryStatement, TypeAnnotation, UnaryExpr, VarDeclaration}}, logger::logger::{log, LogLevel}, utils::{parse_value, shift}};
+use super::{errors::{fault, pop_frame, push_frame, Eval, Frame, Interrupt}, types::check_type, matching::evaluate_match, modules::evaluate_import, collections::{HashKey, OrderedMap}, env::{assign_var, Environment}, bigint::BigInt, numbers::{bigint, compare_numbers, evaluate_numeric, float, integer, invert, is_numeric, negate, to_integer}, methods::{call_method, has_methods, read_property}, values::{ArrayVal, FuncVal, Signature, MemberVal, NativeFn, NullVal, NumberVal, ObjectVal, StringVal, ValueType, format_value, is_null, is_truthy, type_name, values_equal}};
 
 enum BinaryExprEvaluate {
     NumericLiteral(NumericLiteral),
     BinaryExpr(BinaryExpr)
 }
 
-fn evaluate_num(left: f64, right: f64, operation: &str) -> f64 {
-    match operation {
-        "+" => left + right,
-        "*" => left * right,
-        "/" => {
-            if (left == 0.0 || right == 0.0) {
-                log(LogLevel::Error, "Cannot divide by 0");
-                return 0.0;
-            }
-            left / right
-        },
-        "-" => left - right,
-        "%" => left % right,
-        _ => left % right
-    }
-}
-
-fn evaluate_fn(declaration: FuncDeclaration, env: &mut Environment) -> ValueType {
-    let func: ValueType = ValueType::Function(FuncVal {
+fn make_fn(declaration: FuncDeclaration, env: &Environment) -> ValueType {
+    ValueType::Function(FuncVal {
         base: "FuncVal".to_string(),
-        name: declaration.name.clone(),
+        name: declaration.name,
         parameters: declaration.parameters,
-        body: declaration.body
-    });
+        signature: Box::new(Signature {
+            params: declaration.param_types,
+            result: declaration.return_type
+        }),
+        body: Rc::new(declaration.body),
+        module: env.module_scope()
+    })
+}
 
-    env.declare_var(&declaration.name, &func, true)
+fn evaluate_fn(declaration: FuncDeclaration, env: &mut Environment) -> Eval {
+    let name: String = declaration.name.clone();
+    env.declare_var(&name, &make_fn(declaration, env), true)
 }
 
-fn evaluate_call(obj: CallExpr, env: &mut Environment) -> ValueType {
-    let args: Vec<ValueType> = obj.args.iter().map(|x| evaluate(&x, env)).collect();
-    let func = evaluate(&obj.caller, env);
+// Evaluates member and call chains. `None` means that an optional link (`?.`) met a null
+// or missing receiver, which short-circuits the rest of the chain to null.
+fn evaluate_chain(node: &Statment, env: &mut Environment) -> Eval<Option<ValueType>> {
+    match node {
+        Statment::MemberExpr(memexpr) => {
+            let object: ValueType = match evaluate_chain(&memexpr.object, env)? {
+                Some(object) => object,
+                None => return Ok(None)
+            };
+            if (memexpr.optional && is_null(&object)) {
+                return Ok(None);
+            }
+            Ok(Some(evaluate_member(object, memexpr, env)?))
+        }
+        Statment::SliceExpr(slice) => {
+            let object: ValueType = match evaluate_chain(&slice.object, env)? {
+                Some(object) => object,
+                None => return Ok(None)
+            };
+            if (slice.optional && is_null(&object)) {
+                return Ok(None);
+            }
+            Ok(Some(evaluate_slice(object, slice, env)?))
+        }
+        Statment::CallExpr(call) => {
+            // Functions called through a member expression receive the object as self / this.
+            let (func, receiver): (ValueType, Option<ValueType>) = match &call.caller {
+                Statment::MemberExpr(memexpr) => {
+                    let object: ValueType = match evaluate_chain(&memexpr.object, env)? {
+                        Some(object) => object,
+                        None => return Ok(None)
+                    };
+                    if (memexpr.optional && is_null(&object)) {
+                        return Ok(None);
+                    }
+                    if let (Statment::Identifier(iden), false, true) = (&memexpr.property, memexpr.computed, has_methods(&object)) {
+                        let args: Vec<ValueType> = evaluate_elements(&call.args, env)?;
+                        return Ok(Some(call_method(&object, &iden.symbol, args)?));
+                    }
+                    (evaluate_member(object.clone(), memexpr, env)?, Some(object))
+                }
+                _ => match evaluate_chain(&call.caller, env)? {
+                    Some(func) => (func, None),
+                    None => return Ok(None)
+                }
+            };
+            if (call.optional && is_null(&func)) {
+                return Ok(None);
+            }
+            let args: Vec<ValueType> = evaluate_elements(&call.args, env)?;
+            Ok(Some(call_function(func, args, receiver, (call.line, call.column), env)?))
+        }
+        _ => Ok(Some(evaluate(node, env)?))
+    }
+}
 
+// Calls a native or user function. A receiver is visible as `self` and `this` inside the function body.
+// Calls to user functions are recorded on the call stack with the (line, column) of their call site.
+pub fn call_function(func: ValueType, args: Vec<ValueType>, receiver: Option<ValueType>, site: (usize, usize), env: &mut Environment) -> Eval {
     match func {
         ValueType::NativeFn(native) => {
             (native.call)(args, env)
         }
         ValueType::Function(func) => {
-            let mut result: ValueType = ValueType::None();
-            let mut scope = Environment::new();
-            let mut constants: &Vec<String> = &env.constants;
+            push_frame(Frame { name: func.name.clone(), line: site.0, column: site.1, argc: args.len() })?;
+            // A function of another module runs on top of that module, one of the caller's own
+            // module sees the scopes of its caller.
+            let result: Eval = match &func.module {
+                Some(module) if !env.module_scope().is_some_and(|caller| caller.same(module)) => {
+                    let mut top: Environment = Environment::in_module(module, env.settings);
+                    let result: Eval = Environment::scoped(&mut top, |scope| run_function(&func, args, receiver, scope));
+                    env.settings = top.settings;
+                    result
+                }
+                _ => Environment::scoped(env, |scope| run_function(&func, args, receiver, scope))
+            };
+            pop_frame();
+            result
+        }
+        _ => fault("TypeError", format!("Cannot call a value of type {}, it is not a function.", type_name(&func)))
+    }
+}
 
-            for (varname, value) in &env.variables {
-                scope.declare_var(varname, value, constants.contains(varname));
-            }
+// Binds the arguments in the scope of a call and runs the body.
+fn run_function(func: &FuncVal, args: Vec<ValueType>, receiver: Option<ValueType>, scope: &mut Environment) -> Eval {
+    let mut result: ValueType = ValueType::None();
+    if let Some(receiver) = receiver {
+        scope.variables.insert("self".to_string(), receiver.clone());
+        scope.variables.insert("this".to_string(), receiver);
+    }
 
-            for i in 0..func.parameters.len() {
-                let varname = func.parameters[i].clone();
-                scope.declare_var(&varname, &args[i], false);
-            }
-            for stmt in func.body {
-                result = evaluate(&stmt, &mut scope);
+    for (i, param) in func.parameters.iter().enumerate() {
+        let (target, value): (&Statment, ValueType) = match param {
+            Statment::SpreadElement(rest) => (&rest.argument, ValueType::Array(ArrayVal::new(args.get(i..).unwrap_or_default().to_vec()))),
+            // A missing argument is null unless the parameter has a default.
+            Statment::AssignPattern(assign) => (&assign.target, match args.get(i) {
+                Some(arg) => arg.clone(),
+                None => evaluate(&assign.default, scope)?
+            }),
+            _ => (param, args.get(i).cloned().unwrap_or(ValueType::Null(NullVal { base: "NullVal".to_string(), value: None })))
+        };
+        let annotation: &TypeAnnotation = func.signature.params.get(i).unwrap_or(&TypeAnnotation::Any);
+        check_type(&value, annotation, format!("the parameter '{}' of {}", pattern_text(target), func.name))?;
+        bind_pattern(target, Some(value), Binding::Declare(false), scope)?;
+        if let (Statment::Identifier(iden), false) = (target, *annotation == TypeAnnotation::Any) {
+            scope.annotations.insert(iden.symbol.clone(), annotation.clone());
+        }
+    }
+    for stmt in func.body.iter() {
+        match evaluate(stmt, scope) {
+            Ok(value) => result = value,
+            Err(Interrupt::Return(value)) => {
+                result = value;
+                break;
             }
+            Err(Interrupt::Break) => return fault("SyntaxError", format!("Cannot break out of {}, break can only be used inside a switch.", func.name)),
+            Err(thrown) => return Err(thrown)
+        }
+    }
 
-            result
+    check_type(&result, &func.signature.result, format!("the result of {}", func.name))?;
+    Ok(result)
+}
+
+// Formats a value through the display protocol, objects with a toString method
+// are written as whatever that method returns.
+// A value thrown by a toString method stops the formatting and is passed on.
+pub fn display_value(value: &ValueType, quoted: bool, env: &mut Environment) -> Eval<String> {
+    let mut thrown: Option<Interrupt> = None;
+    let text: String = format_value(value, quoted, &mut Vec::new(), &mut |object| {
+        let hook: ValueType = match object {
+            ValueType::Object(obj) => obj.properties.borrow().get(&"toString".to_string()).cloned()?,
+            _ => return None
+        };
+        if (thrown.is_some()) {
+            return Some(String::new());
         }
-        _ => {
-            log(LogLevel::Error, "Cannot call a function that is not a native-fn type.");
-            ValueType::None()
+        match hook {
+            ValueType::Function(_) | ValueType::NativeFn(_) => match call_function(hook, Vec::new(), Some(object.clone()), (0, 0), env) {
+                Ok(result) => Some(result.to_string()),
+                Err(interrupt) => {
+                    thrown = Some(interrupt);
+                    Some(String::new())
+                }
+            },
+            _ => None
         }
+    });
+
+    match thrown {
+        Some(interrupt) => Err(interrupt),
+        None => Ok(text)
+    }
+}
+
+fn integer_index(index: &ValueType, target: &str) -> Eval<i64> {
+    match index {
+        ValueType::Integer(int) => Ok(int.value),
+        ValueType::BigInt(_) => to_integer(index),
+        ValueType::Number(num) if num.value.fract() == 0.0 && num.value.is_finite() => Ok(num.value as i64),
+        ValueType::Number(num) => fault("TypeError", format!("Cannot index the {} with {}, indices must be whole numbers.", target, num.value)),
+        _ => fault("TypeError", format!("Expected a number while indexing the {}, got a value of type {}.", target, type_name(index)))
     }
 }
 
-fn evaluate_member(memexpr: MemberExpr, env: &mut Environment) -> ValueType {
-    let mut eval: ValueType = ValueType::None();
-    let object: ValueType = evaluate(&memexpr.object, env);
-    let property: ValueType = evaluate(&memexpr.property, env);
+// Array and string indices must be whole numbers, negative indices count back from the end.
+fn resolve_index(index: &ValueType, length: usize, target: &str) -> Eval<usize> {
+    let position: i64 = integer_index(index, target)?;
+    let resolved: i64 = if (position < 0) { position + length as i64 } else { position };
 
-    match &object {
+    if (resolved < 0 || resolved >= length as i64) {
+        return fault("RangeError", format!("Index {} is out of range, the {} has a length of {}.", position, target, length));
+    }
+    Ok(resolved as usize)
+}
+
+// Slice bounds may be left out or negative and are clamped to the length like in Python.
+fn slice_bounds(start: Option<i64>, end: Option<i64>, length: usize) -> (usize, usize) {
+    let clamp = |bound: i64| -> usize {
+        let resolved: i64 = if (bound < 0) { bound + length as i64 } else { bound };
+        resolved.clamp(0, length as i64) as usize
+    };
+
+    let start: usize = start.map_or(0, clamp);
+    let end: usize = end.map_or(length, clamp);
+    (start, end.max(start))
+}
+
+fn evaluate_slice(object: ValueType, slice: &SliceExpr, env: &mut Environment) -> Eval {
+    let target: &str = if (matches!(object, ValueType::String(_))) { "string" } else { "array" };
+    let mut bound = |node: &Statment| -> Eval<Option<i64>> {
+        match node {
+            Statment::None() => Ok(None),
+            _ => Ok(Some(integer_index(&evaluate(node, env)?, target)?))
+        }
+    };
+    let start: Option<i64> = bound(&slice.start)?;
+    let end: Option<i64> = bound(&slice.end)?;
+
+    match object {
         ValueType::Array(arr) => {
-            let prop = match &property {
-                ValueType::Number(num) => num.value.to_string().parse::<usize>().unwrap_or(0),
-                _ => {
-                    log(LogLevel::Error, "Expected a number while indexing an array.");
-                    0
-                }
-            };
-            eval = arr.contents[prop].clone();
+            let contents = arr.contents.borrow();
+            let (start, end) = slice_bounds(start, end, contents.len());
+            Ok(ValueType::Array(ArrayVal::new(contents[start..end].to_vec())))
         }
-        ValueType::Object(obj) => {
-            let prop = match &property {
-                ValueType::String(num) => num.value.to_string(),
-                _ => {
-                    log(LogLevel::Error, "Expected a string while indexing an object.");
-                    "Err".to_string()
-                }
-            };
-            eval = obj.properties.get(&prop).unwrap_or(&ValueType::None()).clone();
+        ValueType::String(string) => {
+            let (start, end) = slice_bounds(start, end, string.value.chars().count());
+            Ok(ValueType::String(StringVal {
+                base: "StringVal".to_string(),
+                value: string.value.chars().skip(start).take(end - start).collect()
+            }))
         }
-        _ => {}
+        _ => fault("TypeError", format!("Cannot slice a value of type {}, only arrays and strings can be sliced.", target))
     }
-
-    eval
 }
 
-fn evaluate_array(arr: ArrayLiteral, env: &mut Environment) -> ValueType {
-    let mut array = ArrayVal {
-        base: "ArrayVal".to_string(),
-        contents: Vec::new()
+fn evaluate_member(object: ValueType, memexpr: &MemberExpr, env: &mut Environment) -> Eval {
+    let mut eval: ValueType = ValueType::Null(NullVal { base: "NullVal".to_string(), value: None });
+    let property: ValueType = match &memexpr.property {
+        Statment::Identifier(iden) if !memexpr.computed => ValueType::String(StringVal {
+            base: "StringVal".to_string(),
+            value: iden.symbol.clone()
+        }),
+        _ => evaluate(&memexpr.property, env)?
     };
 
-    for content in arr.contents {
-        match &content {
-            Statment::Identifier(iden) => {
-                array.contents.push(env.lookup_var(&iden.symbol));
+    match &object {
+        ValueType::Array(arr) => {
+            let contents = arr.contents.borrow();
+            let prop: usize = resolve_index(&property, contents.len(), "array")?;
+            eval = contents[prop].clone();
+        }
+        ValueType::String(string) => {
+            let prop: usize = resolve_index(&property, string.value.chars().count(), "string")?;
+            eval = ValueType::String(StringVal {
+                base: "StringVal".to_string(),
+                value: string.value.chars().nth(prop).unwrap_or_default().to_string()
+            });
+        }
+        ValueType::Object(obj) => {
+            let prop: String = object_key(&property)?;
+            if let Some(value) = obj.properties.borrow().get(&prop) {
+                eval = value.clone();
             }
-            Statment::StringLiteral(iden) => {
-                array.contents.push(evaluate(&content, env));
+        }
+        ValueType::Map(map) if memexpr.computed => {
+            if let Some((_, value)) = map.entries.borrow().get(&hash_key(&property)?) {
+                eval = value.clone();
             }
-            _ => {
-                array.contents.push(evaluate(&content, env));
+        }
+        ValueType::Map(_) | ValueType::Set(_) => {
+            eval = read_property(&object, &object_key(&property)?)?;
+        }
+        _ => {
+            return fault("TypeError", format!("Cannot read property {} of a value of type {}.", display_value(&property, true, env)?, type_name(&object)));
+        }
+    }
+
+    Ok(eval)
+}
+
+fn object_key(property: &ValueType) -> Eval<String> {
+    match property {
+        ValueType::String(string) => Ok(string.value.clone()),
+        _ => fault("TypeError", format!("Expected a string while indexing an object, got a value of type {}.", type_name(property)))
+    }
+}
+
+pub fn hash_key(key: &ValueType) -> Eval<HashKey> {
+    match HashKey::from_value(key) {
+        Some(key) => Ok(key),
+        None => fault("TypeError", format!("A value of type {} cannot be used as a key, keys must be numbers, strings, booleans or null.", type_name(key)))
+    }
+}
+
+// Evaluates array elements or call arguments, expanding `...spread` entries in place.
+fn evaluate_elements(elements: &Vec<Statment>, env: &mut Environment) -> Eval<Vec<ValueType>> {
+    let mut values: Vec<ValueType> = Vec::new();
+
+    for element in elements {
+        match element {
+            Statment::SpreadElement(spread) => {
+                match evaluate(&spread.argument, env)? {
+                    ValueType::Array(arr) => values.extend(arr.contents.borrow().iter().cloned()),
+                    ValueType::Set(set) => values.extend(set.members.borrow().values().cloned()),
+                    ValueType::String(string) => values.extend(string.value.chars().map(|c| ValueType::String(StringVal {
+                        base: "StringVal".to_string(),
+                        value: c.to_string()
+                    }))),
+                    other => {
+                        return fault("TypeError", format!("Cannot spread a value of type {}, expected an array, set or string.", type_name(&other)));
+                    }
+                }
             }
+            _ => values.push(evaluate(element, env)?)
         }
     }
 
-    ValueType::Array(array)
+    Ok(values)
 }
 
-fn evaluate_object(obj: ObjectLiteral, env: &mut Environment) -> ValueType {
-    let mut object = ObjectVal {
-        base: "ObjectVal".to_string(),
-        properties: HashMap::new()
-    };
+fn evaluate_array(arr: ArrayLiteral, env: &mut Environment) -> Eval {
+    Ok(ValueType::Array(ArrayVal::new(evaluate_elements(&arr.contents, env)?)))
+}
+
+fn evaluate_object(obj: ObjectLiteral, env: &mut Environment) -> Eval {
+    let mut properties: OrderedMap<String, ValueType> = OrderedMap::new();
 
     for prop in obj.properties {
-        let key = &prop.key;
-        match &prop.value {
-            Statment::None() => {
-                object.properties.insert(key.clone(), env.lookup_var(key));
+        match prop {
+            Statment::SpreadElement(spread) => {
+                match evaluate(&spread.argument, env)? {
+                    ValueType::Object(other) => {
+                        for (key, value) in other.properties.borrow().iter() {
+                            properties.insert(key.clone(), value.clone());
+                        }
+                    }
+                    ValueType::Null(_) => {}
+                    other => {
+                        return fault("TypeError", format!("Cannot spread a value of type {} into an object.", type_name(&other)));
+                    }
+                }
             }
-            _ => {
-                object.properties.insert(key.clone(), evaluate(&prop.value, env));
+            Statment::PropertyLiteral(prop) => {
+                let key: String = object_key(&evaluate(&prop.key, env)?)?;
+                let value: ValueType = match prop.value {
+                    Statment::None() => env.lookup_var(&key)?,
+                    Statment::FuncDeclaration(method) => make_fn(*method, env),
+                    value => evaluate(&value, env)?
+                };
+                properties.insert(key, value);
             }
+            _ => {}
         }
     }
 
-    ValueType::Object(object)
+    Ok(ValueType::Object(ObjectVal::new(properties)))
 }
 
-fn evaluate_assignment(assignment: AssignmentExpr, env: &mut Environment) -> ValueType {
-    let value: &ValueType = &evaluate(&assignment.value, env);
+// Stores a value into an array slot, an object property or a map entry.
+fn assign_member(memexpr: &MemberExpr, value: &ValueType, env: &mut Environment) -> Eval<()> {
+    if (memexpr.optional) {
+        return fault("SyntaxError", "Cannot assign to an optional chain (?.).".to_string());
+    }
+    let object: ValueType = evaluate(&memexpr.object, env)?;
+    let property: ValueType = match &memexpr.property {
+        Statment::Identifier(iden) if !memexpr.computed => ValueType::String(StringVal {
+            base: "StringVal".to_string(),
+            value: iden.symbol.clone()
+        }),
+        _ => evaluate(&memexpr.property, env)?
+    };
+
+    match &object {
+        ValueType::Array(arr) => {
+            let mut contents = arr.contents.borrow_mut();
+            let prop: usize = resolve_index(&property, contents.len(), "array")?;
+            contents[prop] = value.clone();
+        }
+        ValueType::Object(obj) => {
+            obj.properties.borrow_mut().insert(object_key(&property)?, value.clone());
+        }
+        ValueType::Map(map) if memexpr.computed => {
+            map.insert(hash_key(&property)?, property.clone(), value.clone());
+        }
+        _ => {
+            return fault("TypeError", format!("Cannot assign property {} of a value of type {}.", display_value(&property, true, env)?, type_name(&object)));
+        }
+    }
+    Ok(())
+}
+
+fn evaluate_assignment(assignment: AssignmentExpr, env: &mut Environment) -> Eval {
+    let value: &ValueType = &evaluate(&assignment.value, env)?;
     let assigne: String = match assignment.assigne {
         Statment::Identifier(iden) => {
             iden.symbol
         }
+        Statment::MemberExpr(memexpr) => {
+            assign_member(&memexpr, value, env)?;
+            return Ok(value.clone());
+        }
+        Statment::ArrayPattern(_) | Statment::ObjectPattern(_) => {
+            bind_pattern(&assignment.assigne, Some(value.clone()), Binding::Assign, env)?;
+            return Ok(value.clone());
+        }
         _ => {
             log(LogLevel::Error, format!("Unknown Statment {:#?} while evaluating an assignment expression.", assignment.assigne).as_str());
             "Err".to_string()
@@ -163,72 +424,316 @@ fn evaluate_assignment(assignment: AssignmentExpr, env: &mut Environment) -> Val
     assign_var(env, &assigne, value)
 }
 
-fn evaluate_declaration(declaration: VarDeclaration, env: &mut Environment) -> ValueType {
+fn evaluate_declaration(declaration: VarDeclaration, env: &mut Environment) -> Eval {
     match &declaration.value {
+        // A typed variable without a value starts out as null, only the later assignments are checked.
         Statment::None() => {
-            env.declare_var(&declaration.identifier, &&ValueType::Null(NullVal { base: "NullVal".to_string(), value: None }), false)
+            let value: ValueType = env.declare_var(&declaration.identifier, &ValueType::Null(NullVal { base: "NullVal".to_string(), value: None }), false)?;
+            if (declaration.annotation != TypeAnnotation::Any) {
+                env.annotate(&declaration.identifier, &declaration.annotation);
+            }
+            Ok(value)
+        }
+        _ if !matches!(declaration.pattern, Statment::None()) => {
+            let statment = evaluate(&declaration.value, env)?;
+            check_type(&statment, &declaration.annotation, format!("the value of '{}'", pattern_text(&declaration.pattern)))?;
+            bind_pattern(&declaration.pattern, Some(statment.clone()), Binding::Declare(declaration.constant), env)?;
+            Ok(statment)
+        }
+        _ => {
+            let statment = evaluate(&declaration.value, env)?;
+            env.declare_typed(&declaration.identifier, &statment, declaration.constant, &declaration.annotation)
+        }
+    }
+}
+
+// How the names inside a destructuring pattern receive their values.
+#[derive(Clone, Copy)]
+enum Binding {
+    Declare(bool),
+    Assign
+}
+
+// Binds `value` to a pattern, `None` means the value is missing (an array that is too short or
+// an object without the key) which takes the default of an `= default` pattern or fails.
+fn bind_pattern(pattern: &Statment, value: Option<ValueType>, binding: Binding, env: &mut Environment) -> Eval<()> {
+    match pattern {
+        Statment::AssignPattern(assign) => {
+            let value: ValueType = match value {
+                Some(value) => value,
+                None => evaluate(&assign.default, env)?
+            };
+            bind_pattern(&assign.target, Some(value), binding, env)?;
+        }
+        Statment::Identifier(iden) => {
+            let value: ValueType = match value {
+                Some(value) => value,
+                None => return fault("TypeError", format!("Cannot destructure a value for '{}', it is missing and has no default.", iden.symbol))
+            };
+            match binding {
+                Binding::Declare(constant) => env.declare_var(&iden.symbol, &value, constant)?,
+                Binding::Assign => assign_var(env, &iden.symbol, &value)?
+            };
+        }
+        Statment::MemberExpr(memexpr) => {
+            match value {
+                Some(value) => assign_member(memexpr, &value, env)?,
+                None => return fault("TypeError", "Cannot destructure a value for a member target, it is missing and has no default.".to_string())
+            }
+        }
+        Statment::ArrayPattern(arr) => {
+            let contents: Vec<ValueType> = match &value {
+                Some(ValueType::Array(value)) => value.contents.borrow().clone(),
+                _ => {
+                    let found: &str = value.as_ref().map(type_name).unwrap_or("nothing");
+                    return fault("TypeError", format!("Cannot destructure {} with an array pattern, expected an array.", found));
+                }
+            };
+            for (i, element) in arr.elements.iter().enumerate() {
+                bind_pattern(element, contents.get(i).cloned(), binding, env)?;
+            }
+            if (!matches!(arr.rest, Statment::None())) {
+                let rest: Vec<ValueType> = contents.get(arr.elements.len()..).unwrap_or_default().to_vec();
+                bind_pattern(&arr.rest, Some(ValueType::Array(ArrayVal::new(rest))), binding, env)?;
+            }
+        }
+        Statment::ObjectPattern(obj) => {
+            let properties: OrderedMap<String, ValueType> = match &value {
+                Some(ValueType::Object(value)) => value.properties.borrow().clone(),
+                _ => {
+                    let found: &str = value.as_ref().map(type_name).unwrap_or("nothing");
+                    return fault("TypeError", format!("Cannot destructure {} with an object pattern, expected an object.", found));
+                }
+            };
+            let mut used: Vec<String> = Vec::new();
+            for prop in &obj.properties {
+                let key: String = object_key(&evaluate(&prop.key, env)?)?;
+                let target: Statment = match &prop.value {
+                    Statment::None() => Statment::Identifier(Identifier { kind: "Identifier".to_string(), symbol: key.clone() }),
+                    target => target.clone()
+                };
+                bind_pattern(&target, properties.get(&key).cloned(), binding, env)?;
+                used.push(key);
+            }
+            if (!matches!(obj.rest, Statment::None())) {
+                let mut rest: OrderedMap<String, ValueType> = OrderedMap::new();
+                for (key, value) in properties.iter().filter(|(key, _)| !used.contains(key)) {
+                    rest.insert(key.clone(), value.clone());
+                }
+                bind_pattern(&obj.rest, Some(ValueType::Object(ObjectVal::new(rest))), binding, env)?;
+            }
         }
         _ => {
-            let statment = evaluate(&declaration.value, env);
-            env.declare_var(&declaration.identifier, &statment, declaration.constant)
+            log(LogLevel::Error, "Invalid destructuring target.");
         }
     }
+    Ok(())
+}
+
+// A block runs in its own scope and evaluates to its last statement, or null when it is empty.
+fn evaluate_block(body: &Vec<Statment>, env: &mut Environment) -> Eval {
+    Environment::scoped(env, |scope| {
+        let mut last_eval: ValueType = ValueType::Null(NullVal { base: "NullVal".to_string(), value: None });
+        for stmt in body {
+            last_eval = evaluate(stmt, scope)?;
+        }
+        Ok(last_eval)
+    })
+}
+
+// A value thrown inside the block goes to the catch clause, bound to its parameter when there is one.
+// The finally clause always runs afterwards, a value it throws replaces the outcome of the other clauses.
+fn evaluate_try(stmt: &TryStatement, env: &mut Environment) -> Eval {
+    let mut result: Eval = evaluate(&stmt.block, env);
+
+    if let (Err(Interrupt::Throw(thrown)), Statment::BlockStatement(handler)) = (&result, &stmt.handler) {
+        let thrown: ValueType = thrown.clone();
+        result = Environment::scoped(env, |scope| {
+            if (!matches!(stmt.param, Statment::None())) {
+                bind_pattern(&stmt.param, Some(thrown), Binding::Declare(false), scope)?;
+            }
+            let mut last_eval: ValueType = ValueType::Null(NullVal { base: "NullVal".to_string(), value: None });
+            for stmt in &handler.body {
+                last_eval = evaluate(stmt, scope)?;
+            }
+            Ok(last_eval)
+        });
+    }
+
+    if (!matches!(stmt.finalizer, Statment::None())) {
+        evaluate(&stmt.finalizer, env)?;
+    }
+    result
+}
+
+fn evaluate_interpolation(interpolation: InterpolatedString, env: &mut Environment) -> Eval {
+    let mut string: String = String::new();
+    for part in interpolation.parts {
+        let value: ValueType = evaluate(&part, env)?;
+        string += display_value(&value, false, env)?.as_str();
+    }
+
+    Ok(ValueType::String(StringVal {
+        base: "StringVal".to_string(),
+        value: string
+    }))
+}
+
+// The function a program starts in: the one declared with `v_entry`, otherwise a top level `fn main`.
+pub fn entry_point(program: &Statment) -> Option<&FuncDeclaration> {
+    let Statment::Program(program) = program else { return None };
+    let functions = || program.body.iter().filter_map(top_level_fn);
+    functions().find(|func| func.entry).or_else(|| functions().find(|func| func.name == "main"))
 }
 
-fn evaluate_program(program: Program, env: &mut Environment) -> ValueType {
+// Calls the entry point once the top level of a file has run, with the command line arguments given
+// after the file as an array of strings. A number it returns is the exit code of the process,
+// anything else (or a program without an entry point) exits with 0.
+pub fn run_entry(program: &Statment, args: &[String], env: &mut Environment) -> Eval<i32> {
+    let Some(entry) = entry_point(program) else { return Ok(0) };
+
+    let func: ValueType = env.lookup_var(&entry.name)?;
+    let args: Vec<ValueType> = args.iter().map(|arg| ValueType::String(StringVal {
+        base: "StringVal".to_string(),
+        value: arg.clone()
+    })).collect();
+    let result: ValueType = call_function(func, vec![ValueType::Array(ArrayVal::new(args))], None, (0, 0), env)?;
+    if (!is_numeric(&result)) {
+        return Ok(0);
+    }
+
+    match i32::try_from(to_integer(&result)?) {
+        Ok(code) => Ok(code),
+        Err(_) => fault("RangeError", format!("The exit code returned by {} must fit in 32 bits, got {}.", entry.name, result))
+    }
+}
+
+fn evaluate_program(program: Program, env: &mut Environment) -> Eval {
     let mut last_eval: ValueType = ValueType::Null(NullVal { base: "NullVal".to_string(), value: None });
     for stmt in program.body {
-        last_eval = evaluate(&stmt, env);
+        last_eval = match evaluate(&stmt, env) {
+            Ok(value) => value,
+            Err(Interrupt::Return(_)) => return fault("SyntaxError", "return can only be used inside a function.".to_string()),
+            Err(Interrupt::Break) => return fault("SyntaxError", "break can only be used inside a switch.".to_string()),
+            Err(thrown) => return Err(thrown)
+        };
     }
-    last_eval
+    Ok(last_eval)
 }
 
-fn evaluate_binexpr(binary_expr: &mut Box<BinaryExpr>, env: &mut Environment) -> ValueType {
+fn evaluate_comparison(left: &ValueType, right: &ValueType, operation: &str) -> Eval<bool> {
+    let ordering: Option<Ordering> = match (left, right) {
+        (left, right) if is_numeric(left) && is_numeric(right) => compare_numbers(left, right),
+        (ValueType::String(l_str), ValueType::String(r_str)) => Some(l_str.value.cmp(&r_str.value)),
+        (_, _) => {
+            return fault("TypeError", format!("Cannot compare {} with {} using '{}'.", type_name(left), type_name(right), operation));
+        }
+    };
+
+    Ok(match (ordering, operation) {
+        (Some(order), "<") => order == Ordering::Less,
+        (Some(order), ">") => order == Ordering::Greater,
+        (Some(order), "<=") => order != Ordering::Greater,
+        (Some(order), ">=") => order != Ordering::Less,
+        (_, _) => false
+    })
+}
+
+// `&&`, `||` and `??` only evaluate their right side when needed and produce the operand
+// that decided the result, `??` falls back only when its left side is null.
+fn evaluate_binexpr(binary_expr: &mut Box<BinaryExpr>, env: &mut Environment) -> Eval {
     let mut binop: BinaryExpr = *binary_expr.clone();
+    let operator: &str = binop.operator.as_str();
+
+    let left: ValueType = evaluate(&binop.left, env)?;
+    match operator {
+        "&&" => return if (is_truthy(&left)) { evaluate(&binop.right, env) } else { Ok(left) },
+        "||" => return if (is_truthy(&left)) { Ok(left) } else { evaluate(&binop.right, env) },
+        "??" => return if (is_null(&left)) { evaluate(&binop.right, env) } else { Ok(left) },
+        _ => {}
+    }
+    let right: ValueType = evaluate(&binop.right, env)?;
 
-    let left: ValueType = evaluate(&binop.left, env);
-    let right: ValueType = evaluate(&binop.right, env);
+    match operator {
+        // Compared by value without converting between types, functions are only equal to themselves.
+        "==" => return Ok(parse_value(&values_equal(&left, &right))),
+        "!=" => return Ok(parse_value(&!values_equal(&left, &right))),
+        "<" | ">" | "<=" | ">=" => return Ok(parse_value(&evaluate_comparison(&left, &right, operator)?)),
+        _ => {}
+    }
     
     match (left, right) {
-        (ValueType::Number(l_num), ValueType::Number(r_num)) => {
-            ValueType::Number(NumberVal {
-                base: "NumberVal".to_string(),
-                value: evaluate_num(l_num.value, r_num.value, binop.operator.as_str())
-            })
+        (left, right) if is_numeric(&left) && is_numeric(&right) => {
+            evaluate_numeric(&left, &right, operator, &env.settings)
         },
-        (_, _) => {
-            ValueType::Null(NullVal {
-                base: "NullVar".to_string(),
-                value: None
-            })
+        (ValueType::String(l_str), ValueType::String(r_str)) if operator == "+" => {
+            Ok(ValueType::String(StringVal {
+                base: "StringVal".to_string(),
+                value: l_str.value + r_str.value.as_str()
+            }))
+        }
+        (left, right) => {
+            fault("TypeError", format!("Cannot apply the '{}' operator to {} and {}.", operator, type_name(&left), type_name(&right)))
+        }
+    }
+}
+
+fn evaluate_unary(unary: UnaryExpr, env: &mut Environment) -> Eval {
+    let argument: ValueType = evaluate(&unary.argument, env)?;
+    match unary.operator.as_str() {
+        "!" => Ok(parse_value(&!is_truthy(&argument))),
+        "-" => match negate(&argument) {
+            Some(negated) => negated,
+            None => fault("TypeError", format!("Cannot negate a value of type {}.", type_name(&argument)))
+        },
+        "~" => match invert(&argument) {
+            Some(inverted) => Ok(inverted),
+            None => fault("TypeError", format!("The '~' operator needs an int or bigint, got {}.", type_name(&argument)))
+        },
+        _ => {
+            log(LogLevel::Error, format!("Unknown unary operator '{}'.", unary.operator).as_str());
+            Ok(ValueType::None())
         }
     }
 }
 
-pub fn evaluate(ast_node: &Statment, env: &mut Environment) -> ValueType {
+pub fn evaluate(ast_node: &Statment, env: &mut Environment) -> Eval {
     match ast_node {
         Statment::NumericLiteral(ident) => {
-            ValueType::Number(NumberVal {
-                base: "NumberVal".to_string(),
-                value: ident.value
-            })
+            Ok(float(ident.value))
+        }
+        Statment::IntegerLiteral(ident) => {
+            Ok(integer(ident.value))
+        }
+        Statment::BigIntLiteral(ident) => {
+            Ok(bigint(BigInt::parse(&ident.value).unwrap_or(BigInt::zero())))
+        }
+        Statment::BooleanLiteral(ident) => {
+            Ok(parse_value(&ident.value))
         }
         Statment::NullLiteral(ident) => {
-            ValueType::Null(NullVal {
+            Ok(ValueType::Null(NullVal {
                 base: "NullVal".to_string(),
                 value: None
-            })
+            }))
         }
         Statment::StringLiteral(ident) => {
-            ValueType::String(StringVal {
+            Ok(ValueType::String(StringVal {
                 base: "StringVal".to_string(),
                 value: ident.value.clone()
-            })
+            }))
+        }
+
+        Statment::InterpolatedString(ident) => {
+            evaluate_interpolation(*ident.clone(), env)
         }
 
         Statment::BinaryExpr(ident) => {
             evaluate_binexpr(&mut ident.clone(), env)
         }
+        Statment::UnaryExpr(ident) => {
+            evaluate_unary(*ident.clone(), env)
+        }
         Statment::Program(ident) => {
             evaluate_program(ident.clone(), env)
         }
@@ -247,20 +752,53 @@ pub fn evaluate(ast_node: &Statment, env: &mut Environment) -> ValueType {
         Statment::ArrayLiteral(ident) => {
             evaluate_array(*ident.clone(), env)
         }
-        Statment::CallExpr(ident) => {
-            evaluate_call(*ident.clone(), env)
+        Statment::CallExpr(_) | Statment::MemberExpr(_) | Statment::SliceExpr(_) => {
+            Ok(evaluate_chain(ast_node, env)?.unwrap_or(ValueType::Null(NullVal { base: "NullVal".to_string(), value: None })))
+        }
+        Statment::ConditionalExpr(ident) => {
+            if (is_truthy(&evaluate(&ident.test, env)?)) {
+                evaluate(&ident.consequent, env)
+            } else {
+                evaluate(&ident.alternate, env)
+            }
         }
         Statment::FuncDeclaration(func) => {
             evaluate_fn(*func.clone(), env)
         }
-
-        Statment::MemberExpr(expr) => {
-            evaluate_member(*expr.clone(), env)
+        Statment::MatchExpr(expr) => {
+            evaluate_match(expr, env)
+        }
+        Statment::BlockStatement(block) => {
+            evaluate_block(&block.body, env)
+        }
+        Statment::ThrowStatement(stmt) => {
+            Err(Interrupt::Throw(evaluate(&stmt.argument, env)?))
+        }
+        Statment::ReturnStatement(stmt) => {
+            Err(Interrupt::Return(evaluate(&stmt.argument, env)?))
+        }
+        Statment::BreakStatement(_) => {
+            Err(Interrupt::Break)
+        }
+        Statment::TryStatement(stmt) => {
+            evaluate_try(stmt, env)
+        }
+        Statment::ImportStatement(stmt) => {
+            evaluate_import(stmt, env)
+        }
+        Statment::ExportStatement(stmt) => {
+            if (env.parent.is_some()) {
+                return fault("SyntaxError", "export can only be used at the top level of a file.".to_string());
+            }
+            evaluate(&stmt.declaration, env)
+        }
+        Statment::AssignPattern(_) => {
+            fault("SyntaxError", "A `key = default` property is only allowed in a destructuring pattern.".to_string())
         }
 
         _ => {
             log(LogLevel::Warn, format!("This Runtime Interperter did not regonize a AST Node: {:#?}", ast_node).as_str());
-            ValueType::None()
+            Ok(ValueType::None())
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,118 @@
+// Author: ContentGamer
+// Evaluation of match expressions
+
+use core::cmp::Ordering;
+
+use crate::{ast::{ast::{MatchExpr, MatchPattern, Statment}, types::named_type}, logger::logger::{log, LogLevel}};
+
+use super::{types::matches_type, env::Environment, numbers::{compare_numbers, is_numeric}, errors::{fault, Eval, Interrupt}, interpreter::{display_value, evaluate}, values::{is_truthy, type_name, values_equal, ArrayVal, NullVal, ValueType}};
+
+// Tries the arms in order, the first arm whose pattern matches and whose guard holds gives the result.
+// Names bound by a pattern are only visible inside that arm's guard and body.
+pub fn evaluate_match(expr: &MatchExpr, env: &mut Environment) -> Eval {
+    let subject: ValueType = evaluate(&expr.subject, env)?;
+
+    for arm in &expr.arms {
+        let mut bindings: Vec<(String, ValueType)> = Vec::new();
+        if (!match_pattern(&arm.pattern, &subject, &mut bindings, env)?) {
+            continue;
+        }
+
+        let result: Option<ValueType> = Environment::scoped(env, |scope| {
+            for (name, value) in &bindings {
+                scope.declare_var(name, value, false)?;
+            }
+            if (!matches!(arm.guard, Statment::None()) && !is_truthy(&evaluate(&arm.guard, scope)?)) {
+                return Ok(None);
+            }
+            match evaluate(&arm.body, scope) {
+                Err(Interrupt::Break) if expr.switch => Ok(Some(ValueType::Null(NullVal { base: "NullVal".to_string(), value: None }))),
+                result => Ok(Some(result?))
+            }
+        })?;
+        if let Some(result) = result {
+            return Ok(result);
+        }
+    }
+
+    if (!expr.switch) {
+        log(LogLevel::Warn, format!("No arm of the match expression matched {}.", display_value(&subject, true, env)?).as_str());
+    }
+    Ok(ValueType::Null(NullVal { base: "NullVal".to_string(), value: None }))
+}
+
+fn match_pattern(pattern: &MatchPattern, value: &ValueType, bindings: &mut Vec<(String, ValueType)>, env: &mut Environment) -> Eval<bool> {
+    Ok(match pattern {
+        MatchPattern::Wildcard => true,
+        MatchPattern::Binding(name) => {
+            bindings.push((name.clone(), value.clone()));
+            true
+        }
+        MatchPattern::Literal(literal) => values_equal(&evaluate(literal, env)?, value),
+        MatchPattern::Range(start, end, inclusive) => {
+            match (evaluate(start, env)?, evaluate(end, env)?, value) {
+                (start, end, num) if is_numeric(&start) && is_numeric(&end) && is_numeric(num) => {
+                    let (from, to) = (compare_numbers(num, &start), compare_numbers(num, &end));
+                    matches!(from, Some(Ordering::Greater | Ordering::Equal)) && (to == Some(Ordering::Less) || (*inclusive && to == Some(Ordering::Equal)))
+                }
+                (ValueType::String(start), ValueType::String(end), ValueType::String(string)) => {
+                    start.value <= string.value && (string.value < end.value || (*inclusive && string.value == end.value))
+                }
+                (start, end, _) if is_numeric(&start) && is_numeric(&end) => false,
+                (ValueType::String(_), ValueType::String(_), _) => false,
+                (start, end, _) => {
+                    return fault("TypeError", format!("A range pattern needs two numbers or two strings, got {} and {}.", type_name(&start), type_name(&end)));
+                }
+            }
+        }
+        MatchPattern::Array(elements, rest) => {
+            let contents: Vec<ValueType> = match value {
+                ValueType::Array(arr) => arr.contents.borrow().clone(),
+                _ => return Ok(false)
+            };
+            let fits: bool = if (rest.is_some()) { contents.len() >= elements.len() } else { contents.len() == elements.len() };
+            if (!fits) {
+                return Ok(false);
+            }
+            for (element, content) in elements.iter().zip(contents.iter()) {
+                if (!match_pattern(element, content, bindings, env)?) {
+                    return Ok(false);
+                }
+            }
+            if let Some(rest) = rest.as_ref().filter(|rest| *rest != "_") {
+                bindings.push((rest.clone(), ValueType::Array(ArrayVal::new(contents[elements.len()..].to_vec()))));
+            }
+            true
+        }
+        MatchPattern::Object(properties) => {
+            let object = match value {
+                ValueType::Object(obj) => obj.properties.borrow().clone(),
+                _ => return Ok(false)
+            };
+            for (key, pattern) in properties {
+                let matched: bool = match object.get(key) {
+                    Some(property) => match_pattern(pattern, property, bindings, env)?,
+                    None => false
+                };
+                if (!matched) {
+                    return Ok(false);
+                }
+            }
+            true
+        }
+        MatchPattern::Type(name, inner) => {
+            // `num` (or `number`) accepts ints and floats alike, `float` only floats.
+            matches_type(value, &named_type(name)) && match_pattern(inner, value, bindings, env)?
+        }
+        MatchPattern::Or(alternatives) => {
+            for alternative in alternatives {
+                let mut alternative_bindings: Vec<(String, ValueType)> = Vec::new();
+                if (match_pattern(alternative, value, &mut alternative_bindings, env)?) {
+                    bindings.extend(alternative_bindings);
+                    return Ok(true);
+                }
+            }
+            false
+        }
+    })
+}
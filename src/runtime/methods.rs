@@ -0,0 +1,103 @@
+// Author: ContentGamer
+// Built in methods and properties of Map and Set values
+
+use crate::utils::parse_value;
+
+use super::{errors::{fault, Eval}, interpreter::hash_key, numbers::integer, values::{type_name, ArrayVal, MapVal, NullVal, SetVal, ValueType}};
+
+pub fn has_methods(object: &ValueType) -> bool {
+    matches!(object, ValueType::Map(_) | ValueType::Set(_))
+}
+
+fn size(length: usize) -> ValueType {
+    integer(length as i64)
+}
+
+fn expect_args(object: &ValueType, method: &str, args: &[ValueType], count: usize) -> Eval<()> {
+    if (args.len() != count) {
+        return fault("TypeError", format!("{}.{}() expects {} argument(s), got {}.", type_name(object), method, count, args.len()));
+    }
+    Ok(())
+}
+
+// `.size` is the only readable property, everything else on a map or set is a method.
+pub fn read_property(object: &ValueType, property: &str) -> Eval {
+    match (object, property) {
+        (ValueType::Map(map), "size") => Ok(size(map.entries.borrow().len())),
+        (ValueType::Set(set), "size") => Ok(size(set.members.borrow().len())),
+        _ => fault("TypeError", format!("Property {} does not exist on a value of type {}.", property, type_name(object)))
+    }
+}
+
+pub fn call_method(object: &ValueType, method: &str, args: Vec<ValueType>) -> Eval {
+    match object {
+        ValueType::Map(map) => map_method(map, object, method, args),
+        ValueType::Set(set) => set_method(set, object, method, args),
+        _ => Ok(ValueType::None())
+    }
+}
+
+fn map_method(map: &MapVal, object: &ValueType, method: &str, args: Vec<ValueType>) -> Eval {
+    match method {
+        "get" => {
+            expect_args(object, method, &args, 1)?;
+            Ok(match map.entries.borrow().get(&hash_key(&args[0])?) {
+                Some((_, value)) => value.clone(),
+                None => ValueType::Null(NullVal { base: "NullVal".to_string(), value: None })
+            })
+        }
+        "set" => {
+            expect_args(object, method, &args, 2)?;
+            map.insert(hash_key(&args[0])?, args[0].clone(), args[1].clone());
+            Ok(object.clone())
+        }
+        "has" => {
+            expect_args(object, method, &args, 1)?;
+            Ok(parse_value(&map.entries.borrow().contains_key(&hash_key(&args[0])?)))
+        }
+        "delete" => {
+            expect_args(object, method, &args, 1)?;
+            Ok(parse_value(&map.entries.borrow_mut().remove(&hash_key(&args[0])?).is_some()))
+        }
+        "clear" => {
+            expect_args(object, method, &args, 0)?;
+            map.entries.borrow_mut().clear();
+            Ok(object.clone())
+        }
+        "keys" => Ok(ValueType::Array(ArrayVal::new(map.entries.borrow().values().map(|(key, _)| key.clone()).collect()))),
+        "values" => Ok(ValueType::Array(ArrayVal::new(map.entries.borrow().values().map(|(_, value)| value.clone()).collect()))),
+        "entries" => Ok(ValueType::Array(ArrayVal::new(map.entries.borrow().values()
+            .map(|(key, value)| ValueType::Array(ArrayVal::new(vec![key.clone(), value.clone()])))
+            .collect()))),
+        _ => unknown_method(object, method)
+    }
+}
+
+fn set_method(set: &SetVal, object: &ValueType, method: &str, args: Vec<ValueType>) -> Eval {
+    match method {
+        "add" => {
+            expect_args(object, method, &args, 1)?;
+            set.insert(hash_key(&args[0])?, args[0].clone());
+            Ok(object.clone())
+        }
+        "has" => {
+            expect_args(object, method, &args, 1)?;
+            Ok(parse_value(&set.members.borrow().contains_key(&hash_key(&args[0])?)))
+        }
+        "delete" => {
+            expect_args(object, method, &args, 1)?;
+            Ok(parse_value(&set.members.borrow_mut().remove(&hash_key(&args[0])?).is_some()))
+        }
+        "clear" => {
+            expect_args(object, method, &args, 0)?;
+            set.members.borrow_mut().clear();
+            Ok(object.clone())
+        }
+        "values" => Ok(ValueType::Array(ArrayVal::new(set.members.borrow().values().cloned().collect()))),
+        _ => unknown_method(object, method)
+    }
+}
+
+fn unknown_method(object: &ValueType, method: &str) -> Eval {
+    fault("TypeError", format!("{}() is not a method of a value of type {}.", method, type_name(object)))
+}
@@ -1,3 +1,12 @@
 pub mod interpreter;
 pub mod values;
-pub mod env;
\ No newline at end of file
+pub mod env;
+pub mod collections;
+pub mod methods;
+pub mod matching;
+pub mod errors;
+pub mod numbers;
+pub mod bigint;
+pub mod modules;
+
+pub mod types;
\ No newline at end of file
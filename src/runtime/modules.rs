@@ -0,0 +1,186 @@
+// Author: ContentGamer
+// Loading modules for `import`, every module is evaluated once in its own scope and its exports are cached
+
+use std::{cell::RefCell, collections::HashMap, path::{Path, PathBuf}, rc::Rc};
+
+use alloc::{string::{String, ToString}, vec::Vec};
+
+use crate::ast::{ast::{ImportStatement, Statment}, modules::exported_names, parser::produce_ast};
+
+use super::{collections::OrderedMap, env::{setup_fn, Environment, ModuleScope}, errors::{fault, Eval}, interpreter::evaluate, values::{ObjectVal, ValueType}};
+
+// Finds and reads modules. The default resolver works on files, an embedder can install its own
+// with `set_resolver` to load modules from memory, an archive or anywhere else.
+pub trait Resolver {
+    // Turns the source written in an import into the id of a module. `importer` is the id of the
+    // module containing the import, None at the REPL.
+    fn resolve(&self, source: &str, importer: Option<&str>) -> Result<String, String>;
+    // The code of a module returned by `resolve`.
+    fn load(&self, id: &str) -> Result<String, String>;
+}
+
+// Sources are paths relative to the directory of the importing file (the working directory at the
+// REPL), `.va` is added to a path without an extension. Ids are canonical paths so the same file
+// is the same module however it is reached.
+pub struct FileResolver;
+
+impl Resolver for FileResolver {
+    fn resolve(&self, source: &str, importer: Option<&str>) -> Result<String, String> {
+        let base: PathBuf = match importer.and_then(|importer| Path::new(importer).parent()) {
+            Some(dir) => dir.to_path_buf(),
+            None => PathBuf::from(".")
+        };
+        let mut path: PathBuf = base.join(source).components().collect();
+        if (path.extension().is_none()) {
+            path.set_extension("va");
+        }
+        match path.canonicalize() {
+            Ok(path) => Ok(path.display().to_string()),
+            Err(_) => Err(format!("Cannot find the module '{}', looked for {}.", source, path.display()))
+        }
+    }
+
+    fn load(&self, id: &str) -> Result<String, String> {
+        std::fs::read_to_string(id).map_err(|err| format!("Cannot read the module {}: {}.", id, err))
+    }
+}
+
+struct Loader {
+    resolver: Rc<dyn Resolver>,
+    // The exports of every module that finished evaluating, by id.
+    modules: HashMap<String, ObjectVal>,
+    // The modules being evaluated right now, the importing module last. An import of one of them is a cycle.
+    active: Vec<String>
+}
+
+thread_local! {
+    static LOADER: RefCell<Loader> = RefCell::new(Loader {
+        resolver: Rc::new(FileResolver),
+        modules: HashMap::new(),
+        active: Vec::new()
+    });
+}
+
+// Replaces the resolver, modules loaded through the previous one are forgotten.
+pub fn set_resolver(resolver: impl Resolver + 'static) {
+    LOADER.with(|loader| {
+        let mut loader = loader.borrow_mut();
+        loader.resolver = Rc::new(resolver);
+        loader.modules.clear();
+    });
+}
+
+// Runs `body` as the evaluation of the file at `path`, its imports are resolved relative to it.
+pub fn run_module<T>(path: &str, body: impl FnOnce() -> T) -> T {
+    let resolver: Rc<dyn Resolver> = LOADER.with(|loader| loader.borrow().resolver.clone());
+    let id: String = resolver.resolve(path, None).unwrap_or_else(|_| path.to_string());
+
+    LOADER.with(|loader| loader.borrow_mut().active.push(id));
+    let result: T = body();
+    LOADER.with(|loader| loader.borrow_mut().active.pop());
+    result
+}
+
+// The exports of the module `source` refers to, evaluating it first if no import has loaded it yet.
+// The module runs with the settings of the importer and a fresh set of the native functions.
+pub fn load_module(source: &str, env: &Environment) -> Eval<ObjectVal> {
+    let (resolver, active) = LOADER.with(|loader| {
+        let loader = loader.borrow();
+        (loader.resolver.clone(), loader.active.clone())
+    });
+    let id: String = match resolver.resolve(source, active.last().map(String::as_str)) {
+        Ok(id) => id,
+        Err(message) => return fault("ImportError", message)
+    };
+
+    if let Some(exports) = LOADER.with(|loader| loader.borrow().modules.get(&id).cloned()) {
+        return Ok(exports);
+    }
+    if let Some(start) = active.iter().position(|module| *module == id) {
+        let cycle: Vec<&str> = active[start..].iter().chain([&id]).map(String::as_str).collect();
+        return fault("ImportError", format!("Cyclic import: {}.", cycle.join(" -> ")));
+    }
+
+    let code: String = match resolver.load(&id) {
+        Ok(code) => code,
+        Err(message) => return fault("ImportError", message)
+    };
+    let program: Statment = produce_ast(&code);
+    let module: ModuleScope = ModuleScope::new();
+    let mut scope: Environment = Environment::in_module(&module, env.settings);
+    setup_fn(&mut scope);
+    run_module(&id, || evaluate(&program, &mut scope))?;
+
+    let mut properties: OrderedMap<String, ValueType> = OrderedMap::new();
+    if let Statment::Program(program) = &program {
+        for stmt in &program.body {
+            let Statment::ExportStatement(export) = stmt else { continue };
+            for name in exported_names(export) {
+                if let Some(value) = module.0.borrow().variables.get(&name) {
+                    properties.insert(name, value.clone());
+                }
+            }
+        }
+    }
+
+    // The module keeps the exports up to date, its functions may assign them after it has run.
+    let exports: ObjectVal = ObjectVal::new(properties);
+    module.0.borrow_mut().exports = Some(exports.clone());
+    LOADER.with(|loader| loader.borrow_mut().modules.insert(id, exports.clone()));
+    Ok(exports)
+}
+
+// Every module a program imports, directly or through other modules, once each with its code. An
+// import that cannot be resolved or read is left out, running the program reports it.
+pub fn imported_modules(path: &str, program: &Statment) -> Vec<(String, Statment)> {
+    let resolver: Rc<dyn Resolver> = LOADER.with(|loader| loader.borrow().resolver.clone());
+    let root: String = resolver.resolve(path, None).unwrap_or_else(|_| path.to_string());
+    let mut modules: Vec<(String, Statment)> = Vec::new();
+    let mut pending: Vec<(String, Vec<String>)> = vec![(root.clone(), import_sources(program))];
+    let mut seen: Vec<String> = vec![root];
+
+    while let Some((importer, sources)) = pending.pop() {
+        for source in sources {
+            let Ok(id) = resolver.resolve(&source, Some(&importer)) else { continue };
+            if (seen.contains(&id)) {
+                continue;
+            }
+            seen.push(id.clone());
+            let Ok(code) = resolver.load(&id) else { continue };
+            let module: Statment = produce_ast(&code);
+            pending.push((id.clone(), import_sources(&module)));
+            modules.push((id, module));
+        }
+    }
+    modules
+}
+
+fn import_sources(program: &Statment) -> Vec<String> {
+    match program {
+        Statment::Program(program) => program.body.iter().filter_map(|stmt| match stmt {
+            Statment::ImportStatement(import) => Some(import.source.clone()),
+            _ => None
+        }).collect(),
+        _ => Vec::new()
+    }
+}
+
+// Imported names read the current value of the export and cannot be assigned, `import * as name`
+// binds the object of all exports.
+pub fn evaluate_import(stmt: &ImportStatement, env: &mut Environment) -> Eval {
+    if (env.parent.is_some()) {
+        return fault("SyntaxError", format!("import can only be used at the top level of a file (line {}, column {}).", stmt.line, stmt.column));
+    }
+    let exports: ObjectVal = load_module(&stmt.source, env)?;
+
+    if (!stmt.namespace.is_empty()) {
+        env.declare_var(&stmt.namespace, &ValueType::Object(exports.clone()), true)?;
+    }
+    for (name, local) in &stmt.names {
+        if (!exports.properties.borrow().contains_key(name)) {
+            return fault("ImportError", format!("The module '{}' does not export '{}' (line {}, column {}).", stmt.source, name, stmt.line, stmt.column));
+        }
+        env.declare_import(local, &exports, name)?;
+    }
+    Ok(ValueType::Object(exports))
+}
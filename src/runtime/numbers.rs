@@ -0,0 +1,331 @@
+// Author: ContentGamer
+// Arithmetic on integers (i64), floats (f64) and bigints
+
+use core::cmp::Ordering;
+
+
+use super::{env::{DivisionMode, Settings}, errors::{fault, Eval}, bigint::BigInt, values::{type_name, BigIntVal, IntegerVal, NumberVal, ValueType}};
+
+pub fn integer(value: i64) -> ValueType {
+    ValueType::Integer(IntegerVal {
+        base: "IntegerVal".to_string(),
+        value
+    })
+}
+
+pub fn float(value: f64) -> ValueType {
+    ValueType::Number(NumberVal {
+        base: "NumberVal".to_string(),
+        value
+    })
+}
+
+pub fn bigint(value: BigInt) -> ValueType {
+    ValueType::BigInt(BigIntVal {
+        base: "BigIntVal".to_string(),
+        value
+    })
+}
+
+pub fn is_numeric(value: &ValueType) -> bool {
+    matches!(value, ValueType::Integer(_) | ValueType::BigInt(_) | ValueType::Number(_) | ValueType::FnNumber(_))
+}
+
+pub fn as_float(value: &ValueType) -> Option<f64> {
+    match value {
+        ValueType::Integer(int) => Some(int.value as f64),
+        ValueType::BigInt(big) => Some(big.value.to_f64()),
+        ValueType::Number(num) | ValueType::FnNumber(num) => Some(num.value),
+        _ => None
+    }
+}
+
+// The exact value of an int or bigint.
+fn as_bigint(value: &ValueType) -> Option<BigInt> {
+    match value {
+        ValueType::Integer(int) => Some(BigInt::from_i64(int.value)),
+        ValueType::BigInt(big) => Some(big.value.clone()),
+        _ => None
+    }
+}
+
+// Ints and bigints compare exactly, a float on either side compares both as floats.
+pub fn compare_numbers(left: &ValueType, right: &ValueType) -> Option<Ordering> {
+    match (left, right) {
+        (ValueType::Integer(l_int), ValueType::Integer(r_int)) => Some(l_int.value.cmp(&r_int.value)),
+        (ValueType::BigInt(_) | ValueType::Integer(_), ValueType::BigInt(_) | ValueType::Integer(_)) => Some(as_bigint(left)?.cmp(&as_bigint(right)?)),
+        _ => as_float(left)?.partial_cmp(&as_float(right)?)
+    }
+}
+
+// Two integers give an integer except for `/`, which always gives a float, and `**` with a negative
+// exponent. A float on either side turns the other operand into a float as well. A bigint with an
+// int gives a bigint, a bigint cannot be mixed with a float.
+pub fn evaluate_numeric(left: &ValueType, right: &ValueType, operation: &str, settings: &Settings) -> Eval {
+    if (is_bitwise(operation) && !(is_integral(left) && is_integral(right))) {
+        return fault("TypeError", format!("The '{}' operator needs ints or bigints, got {} and {}.", operation, type_name(left), type_name(right)));
+    }
+
+    match (left, right) {
+        (ValueType::Integer(l_int), ValueType::Integer(r_int)) => evaluate_int(l_int.value, r_int.value, operation, settings),
+        (ValueType::BigInt(_) | ValueType::Integer(_), ValueType::BigInt(_) | ValueType::Integer(_)) => {
+            evaluate_bigint(&as_bigint(left).unwrap_or(BigInt::zero()), &as_bigint(right).unwrap_or(BigInt::zero()), operation)
+        }
+        (ValueType::BigInt(_), _) | (_, ValueType::BigInt(_)) => {
+            fault("TypeError", format!("Cannot apply the '{}' operator to {} and {}, convert one side with bigint() or float() first.", operation, type_name(left), type_name(right)))
+        }
+        _ => Ok(float(evaluate_float(as_float(left).unwrap_or(0.0), as_float(right).unwrap_or(0.0), operation, settings)?))
+    }
+}
+
+// The largest bigint `<<` and `**` may produce, a typo such as `1n << 4000000000n` raises a RangeError
+// instead of exhausting memory.
+pub const MAX_BIGINT_BITS: u64 = 1 << 18;
+
+// How far a bigint may be shifted or raised, larger amounts would not fit in memory anyway.
+fn small_amount(amount: &BigInt, operation: &str) -> Eval<u32> {
+    match amount.to_i64().and_then(|amount| u32::try_from(amount).ok()) {
+        Some(amount) => Ok(amount),
+        None if amount.is_negative() && operation != "**" => fault("RangeError", format!("Cannot shift by a negative amount ({}).", amount)),
+        None => fault("RangeError", format!("The right side of '{}' must be a non-negative int below 2^32 for a bigint, got {}.", operation, amount))
+    }
+}
+
+// `bits` is the smallest size the result can have.
+fn check_size(bits: u64, operation: &str) -> Eval<()> {
+    if (bits > MAX_BIGINT_BITS) {
+        return fault("RangeError", format!("The result of '{}' would have more than {} bits, the limit for a bigint.", operation, MAX_BIGINT_BITS));
+    }
+    Ok(())
+}
+
+fn shift_left(left: &BigInt, amount: u32) -> Eval<BigInt> {
+    if (!left.is_zero()) {
+        check_size(left.bits() + amount as u64, "<<")?;
+    }
+    Ok(left.shl(amount as usize))
+}
+
+// 0, 1 and -1 stay small whatever the exponent. The size of the result is estimated from log2 of the
+// base, bases too large for a float are close enough to 2^(bits - 1).
+fn power(left: &BigInt, exponent: u32) -> Eval<BigInt> {
+    if (left.bits() > 1) {
+        let log2: f64 = if (left.bits() < 1000) { left.to_f64().abs().log2() } else { (left.bits() - 1) as f64 };
+        check_size((log2 * exponent as f64) as u64 + 1, "**")?;
+    }
+    Ok(left.pow(exponent))
+}
+
+// Bigints never turn into floats, `/` rounds down like `//`. Dividing by zero is always an error.
+fn evaluate_bigint(left: &BigInt, right: &BigInt, operation: &str) -> Eval {
+    if (right.is_zero() && matches!(operation, "/" | "//" | "%")) {
+        return fault("ArithmeticError", format!("Cannot {} by 0", if (operation == "%") { "take the modulus" } else { "divide" }));
+    }
+
+    Ok(bigint(match operation {
+        "+" => left.add(right),
+        "-" => left.sub(right),
+        "*" => left.mul(right),
+        "/" | "//" => left.div_floor(right).map(|(quotient, _)| quotient).unwrap_or(BigInt::zero()),
+        "%" => left.rem(right).unwrap_or(BigInt::zero()),
+        "**" => power(left, small_amount(right, operation)?)?,
+        "&" => left.bitwise(right, |l, r| l & r),
+        "|" => left.bitwise(right, |l, r| l | r),
+        "^" => left.bitwise(right, |l, r| l ^ r),
+        "<<" => shift_left(left, small_amount(right, operation)?)?,
+        ">>" => left.shr(small_amount(right, operation)? as usize),
+        _ => return fault("TypeError", format!("Unknown binary operator '{}'.", operation))
+    }))
+}
+
+pub fn is_bitwise(operation: &str) -> bool {
+    matches!(operation, "&" | "|" | "^" | "<<" | ">>")
+}
+
+fn is_integral(value: &ValueType) -> bool {
+    matches!(value, ValueType::Integer(_) | ValueType::BigInt(_))
+}
+
+// ~value flips every bit of an int or bigint, which gives -value - 1.
+pub fn invert(value: &ValueType) -> Option<ValueType> {
+    match value {
+        ValueType::Integer(int) => Some(integer(!int.value)),
+        ValueType::BigInt(big) => Some(bigint(big.value.not())),
+        _ => None
+    }
+}
+
+pub fn negate(value: &ValueType) -> Option<Eval> {
+    match value {
+        ValueType::Integer(int) => Some(match int.value.checked_neg() {
+            Some(negated) => Ok(integer(negated)),
+            None => fault("ArithmeticError", format!("Integer overflow while negating {}.", int.value))
+        }),
+        ValueType::BigInt(big) => Some(Ok(bigint(big.value.neg()))),
+        ValueType::Number(num) | ValueType::FnNumber(num) => Some(Ok(float(-num.value))),
+        _ => None
+    }
+}
+
+fn overflow(left: i64, right: i64, operation: &str) -> Eval {
+    fault("ArithmeticError", format!("Integer overflow while computing {} {} {}.", left, operation, right))
+}
+
+fn evaluate_int(left: i64, right: i64, operation: &str, settings: &Settings) -> Eval {
+    // Dividing by zero is left to the float rules so the division mode of the engine applies.
+    if (right == 0 && (operation == "//" || operation == "%")) {
+        return Ok(float(evaluate_float(left as f64, 0.0, operation, settings)?));
+    }
+
+    let result: Option<i64> = match operation {
+        "+" => left.checked_add(right),
+        "-" => left.checked_sub(right),
+        "*" => left.checked_mul(right),
+        "/" => return Ok(float(evaluate_float(left as f64, right as f64, operation, settings)?)),
+        "//" => left.checked_div(right).map(|quotient| {
+            if (left % right != 0 && (left < 0) != (right < 0)) { quotient - 1 } else { quotient }
+        }),
+        "%" => Some(left.wrapping_rem(right)),
+        "**" if right < 0 => return Ok(float((left as f64).powf(right as f64))),
+        "**" => u32::try_from(right).ok().and_then(|exponent| left.checked_pow(exponent)),
+        "&" => Some(left & right),
+        "|" => Some(left | right),
+        "^" => Some(left ^ right),
+        "<<" | ">>" if right < 0 => {
+            return fault("RangeError", format!("Cannot shift by a negative amount ({}).", right));
+        }
+        // Bits shifted out at the top are an overflow, the result must shift back to `left`.
+        "<<" => u32::try_from(right).ok()
+            .and_then(|amount| left.checked_shl(amount))
+            .filter(|shifted| shifted >> right == left),
+        ">>" => Some(left >> right.min(63)),
+        _ => return fault("TypeError", format!("Unknown binary operator '{}'.", operation))
+    };
+
+    match result {
+        Some(result) => Ok(integer(result)),
+        None => overflow(left, right, operation)
+    }
+}
+
+// `/` and `//` by zero follow the division mode of the engine, `%` by zero is always an error.
+// `//` rounds the quotient down and `%` keeps the sign of the left operand.
+fn evaluate_float(left: f64, right: f64, operation: &str, settings: &Settings) -> Eval<f64> {
+    if (right == 0.0 && (operation == "%" || ((operation == "/" || operation == "//") && settings.division == DivisionMode::Error))) {
+        return fault("ArithmeticError", format!("Cannot {} by 0", if (operation == "%") { "take the modulus" } else { "divide" }));
+    }
+
+    Ok(match operation {
+        "+" => left + right,
+        "-" => left - right,
+        "*" => left * right,
+        "/" => left / right,
+        "//" => (left / right).floor(),
+        "%" => left % right,
+        "**" => left.powf(right),
+        _ => return fault("TypeError", format!("Unknown binary operator '{}'.", operation))
+    })
+}
+
+// int(value) truncates floats towards zero and parses strings written in base 10.
+pub fn to_integer(value: &ValueType) -> Eval<i64> {
+    match value {
+        ValueType::Integer(int) => Ok(int.value),
+        ValueType::BigInt(big) => match big.value.to_i64() {
+            Some(int) => Ok(int),
+            None => fault("RangeError", format!("Cannot convert {} to an int, it is outside the range of an int.", big.value))
+        },
+        ValueType::Number(num) | ValueType::FnNumber(num) => {
+            let truncated: f64 = num.value.trunc();
+            if (!truncated.is_finite() || truncated < i64::MIN as f64 || truncated >= i64::MAX as f64) {
+                return fault("RangeError", format!("Cannot convert {} to an int, it is outside the range of an int.", num.value));
+            }
+            Ok(truncated as i64)
+        }
+        ValueType::Boolean(boolean) => Ok(boolean.value as i64),
+        ValueType::String(string) | ValueType::FnString(string) => match string.value.trim().parse::<i64>() {
+            Ok(int) => Ok(int),
+            Err(_) => fault("TypeError", format!("Cannot convert \"{}\" to an int.", string.value))
+        },
+        _ => fault("TypeError", format!("Cannot convert a value of type {} to an int.", type_name(value)))
+    }
+}
+
+// bigint(value) accepts ints, whole floats, booleans and base 10 strings.
+pub fn to_bigint(value: &ValueType) -> Eval<BigInt> {
+    match value {
+        ValueType::Integer(_) | ValueType::BigInt(_) => Ok(as_bigint(value).unwrap_or(BigInt::zero())),
+        ValueType::Number(num) | ValueType::FnNumber(num) => match BigInt::from_f64(num.value) {
+            Some(big) => Ok(big),
+            None => fault("RangeError", format!("Cannot convert {} to a bigint, only whole numbers can be converted.", num.value))
+        },
+        ValueType::Boolean(boolean) => Ok(BigInt::from_i64(boolean.value as i64)),
+        ValueType::String(string) | ValueType::FnString(string) => match BigInt::parse(string.value.trim()) {
+            Some(big) => Ok(big),
+            None => fault("TypeError", format!("Cannot convert \"{}\" to a bigint.", string.value))
+        },
+        _ => fault("TypeError", format!("Cannot convert a value of type {} to a bigint.", type_name(value)))
+    }
+}
+
+pub fn to_float(value: &ValueType) -> Eval<f64> {
+    match value {
+        ValueType::Boolean(boolean) => Ok(boolean.value as i64 as f64),
+        ValueType::String(string) | ValueType::FnString(string) => match string.value.trim().parse::<f64>() {
+            Ok(num) => Ok(num),
+            Err(_) => fault("TypeError", format!("Cannot convert \"{}\" to a float.", string.value))
+        },
+        _ => match as_float(value) {
+            Some(num) => Ok(num),
+            None => fault("TypeError", format!("Cannot convert a value of type {} to a float.", type_name(value)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::errors::Interrupt;
+
+    fn big(text: &str) -> BigInt {
+        BigInt::parse(text).unwrap()
+    }
+
+    fn error_kind(result: Eval) -> String {
+        match result {
+            Err(Interrupt::Throw(ValueType::Object(obj))) => obj.properties.borrow().get(&"kind".to_string()).map(|kind| kind.to_string()).unwrap_or_default(),
+            _ => String::new()
+        }
+    }
+
+    #[test]
+    fn huge_shifts_and_powers_raise() {
+        assert_eq!(error_kind(evaluate_bigint(&big("1"), &big("4000000000"), "<<")), "RangeError");
+        assert_eq!(error_kind(evaluate_bigint(&big("1"), &big("4294967296"), "<<")), "RangeError");
+        assert_eq!(error_kind(evaluate_bigint(&big("3"), &big("200000"), "**")), "RangeError");
+        assert_eq!(error_kind(evaluate_bigint(&big("1"), &big("-1"), "<<")), "RangeError");
+        assert_eq!(error_kind(evaluate_bigint(&big("2"), &big("-1"), "**")), "RangeError");
+    }
+
+    #[test]
+    fn small_results_are_allowed() {
+        assert!(evaluate_bigint(&big("1"), &big("262143"), "<<").is_ok());
+        assert!(evaluate_bigint(&big("0"), &big("4000000000"), "<<").is_ok());
+        assert!(evaluate_bigint(&big("-1"), &big("4000000000"), "**").is_ok());
+        assert!(evaluate_bigint(&big("7"), &big("4000000000"), ">>").is_ok());
+    }
+
+    #[test]
+    fn division_by_zero_raises() {
+        assert_eq!(error_kind(evaluate_bigint(&big("1"), &big("0"), "//")), "ArithmeticError");
+        assert_eq!(error_kind(evaluate_bigint(&big("1"), &big("0"), "%")), "ArithmeticError");
+    }
+
+    #[test]
+    fn unknown_operators_raise() {
+        let settings: Settings = Settings::default();
+        assert_eq!(error_kind(evaluate_bigint(&big("1"), &big("2"), "@")), "TypeError");
+        assert_eq!(error_kind(evaluate_int(1, 2, "@", &settings)), "TypeError");
+        assert_eq!(error_kind(evaluate_float(1.0, 2.0, "@", &settings).map(float)), "TypeError");
+    }
+}
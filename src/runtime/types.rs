@@ -0,0 +1,77 @@
+// Author: ContentGamer
+// Runtime checks for type annotations, values are checked when they cross an annotated boundary
+
+use alloc::string::String;
+
+use crate::ast::{ast::TypeAnnotation, types::type_text};
+
+use super::{errors::{fault, Eval}, values::{is_null, type_name, ValueType}};
+
+pub fn matches_type(value: &ValueType, annotation: &TypeAnnotation) -> bool {
+    if let ValueType::Member(member) = value {
+        return matches_type(&member.eval, annotation);
+    }
+
+    match annotation {
+        TypeAnnotation::Any => true,
+        TypeAnnotation::Optional(inner) => is_null(value) || matches_type(value, inner),
+        TypeAnnotation::Array(element) => match value {
+            ValueType::Array(arr) => arr.contents.borrow().iter().all(|item| matches_type(item, element)),
+            _ => false
+        },
+        TypeAnnotation::Function(params, result) => match value {
+            ValueType::NativeFn(_) => true,
+            // A function with annotations of its own has to agree with the ones that are written on both sides.
+            ValueType::Function(func) => {
+                params.iter().zip(&func.signature.params).all(|(expected, declared)| compatible(expected, declared))
+                    && compatible(result, &func.signature.result)
+            }
+            _ => false
+        },
+        TypeAnnotation::Named(name) => match name.as_str() {
+            "null" => is_null(value),
+            "str" => matches!(value, ValueType::String(_) | ValueType::FnString(_)),
+            "num" => matches!(value, ValueType::Integer(_) | ValueType::Number(_) | ValueType::FnNumber(_)),
+            "int" => matches!(value, ValueType::Integer(_)),
+            "int32" => matches!(value, ValueType::Integer(int) if i32::try_from(int.value).is_ok()),
+            "float" => matches!(value, ValueType::Number(_) | ValueType::FnNumber(_)),
+            "bigint" => matches!(value, ValueType::BigInt(_)),
+            "bool" => matches!(value, ValueType::Boolean(_)),
+            "obj" => matches!(value, ValueType::Object(_) | ValueType::FnObject(_)),
+            "map" => matches!(value, ValueType::Map(_)),
+            "set" => matches!(value, ValueType::Set(_)),
+            "fn" => matches!(value, ValueType::Function(_) | ValueType::NativeFn(_)),
+            _ => false
+        }
+    }
+}
+
+// `any` on either side is compatible with everything, otherwise the types have to be written the same.
+// An int and an int32 are compatible both ways, whether an int fits is only known from its value.
+pub fn compatible(expected: &TypeAnnotation, actual: &TypeAnnotation) -> bool {
+    match (expected, actual) {
+        (TypeAnnotation::Any, _) | (_, TypeAnnotation::Any) => true,
+        (TypeAnnotation::Optional(expected), TypeAnnotation::Optional(actual)) => compatible(expected, actual),
+        (TypeAnnotation::Optional(_), TypeAnnotation::Named(actual)) if actual == "null" => true,
+        (TypeAnnotation::Optional(expected), actual) => compatible(expected, actual),
+        (TypeAnnotation::Array(expected), TypeAnnotation::Array(actual)) => compatible(expected, actual),
+        (TypeAnnotation::Function(e_params, e_result), TypeAnnotation::Function(a_params, a_result)) => {
+            e_params.len() == a_params.len()
+                && e_params.iter().zip(a_params).all(|(expected, actual)| compatible(expected, actual))
+                && compatible(e_result, a_result)
+        }
+        (TypeAnnotation::Named(expected), TypeAnnotation::Named(actual)) => {
+            expected == actual || (expected == "num" && matches!(actual.as_str(), "int" | "int32" | "float")) || (expected.starts_with("int") && actual.starts_with("int"))
+        }
+        (TypeAnnotation::Named(name), TypeAnnotation::Function(..)) => name == "fn",
+        _ => false
+    }
+}
+
+// Raises a TypeError when `value` does not fit the annotation, `what` names the checked place.
+pub fn check_type(value: &ValueType, annotation: &TypeAnnotation, what: String) -> Eval<()> {
+    if (matches_type(value, annotation)) {
+        return Ok(());
+    }
+    fault("TypeError", format!("Expected {} to be of type {}, got a value of type {}.", what, type_text(annotation), type_name(value)))
+}
@@ -1,17 +1,21 @@
-use std::{cell::RefCell, collections::HashMap};
+use std::{cell::RefCell, cmp::Ordering, collections::HashMap, fmt, rc::Rc};
 
-use crate::ast::ast::Statment;
+use crate::{ast::{ast::{Statment, TypeAnnotation}, patterns::pattern_text, types::type_text}, logger::logger::{log, LogLevel}, utils::isidentifier};
 
-use super::env::Environment;
+use super::{bigint::BigInt, collections::{HashKey, OrderedMap}, env::{Environment, ModuleScope}, errors::{fault, Eval}, numbers::{compare_numbers, is_numeric}};
 
 #[derive(Debug, Clone)]
 pub enum ValueType {
     Null(NullVal),
     Number(NumberVal),
+    Integer(IntegerVal),
+    BigInt(BigIntVal),
     String(StringVal),
     Boolean(BooleanVal),
     Object(ObjectVal),
     Array(ArrayVal),
+    Map(MapVal),
+    Set(SetVal),
     Member(Box<MemberVal>),
 
     NativeFn(NativeFn),
@@ -39,6 +43,16 @@ pub struct NumberVal {
     pub value: f64
 }
 #[derive(Debug, Clone)]
+pub struct IntegerVal {
+    pub base: String,
+    pub value: i64
+}
+#[derive(Debug, Clone)]
+pub struct BigIntVal {
+    pub base: String,
+    pub value: BigInt
+}
+#[derive(Debug, Clone)]
 pub struct StringVal {
     pub base: String,
     pub value: String
@@ -48,27 +62,50 @@ pub struct BooleanVal {
     pub base: String,
     pub value: bool
 }
+// Objects, arrays, maps and sets are shared by reference, copies of the value see each others changes.
 #[derive(Debug, Clone)]
 pub struct ObjectVal {
     pub base: String,
-    pub properties: HashMap<String, ValueType>
+    pub properties: Rc<RefCell<OrderedMap<String, ValueType>>>
 }
 #[derive(Debug, Clone)]
 pub struct ArrayVal {
     pub base: String,
-    pub contents: Vec<ValueType>
+    pub contents: Rc<RefCell<Vec<ValueType>>>
+}
+#[derive(Debug, Clone)]
+pub struct MapVal {
+    pub base: String,
+    pub entries: Rc<RefCell<OrderedMap<HashKey, (ValueType, ValueType)>>>
+}
+#[derive(Debug, Clone)]
+pub struct SetVal {
+    pub base: String,
+    pub members: Rc<RefCell<OrderedMap<HashKey, ValueType>>>
 }
 #[derive(Debug, Clone)]
 pub struct NativeFn {
     pub base: String,
-    pub call: fn(args: Vec<ValueType>, env: &mut Environment) -> ValueType
+    pub name: String,
+    pub call: fn(args: Vec<ValueType>, env: &mut Environment) -> Eval
 }
 #[derive(Debug, Clone)]
 pub struct FuncVal {
     pub base: String,
     pub name: String,
-    pub parameters: Vec<String>,
-    pub body: Vec<Statment>
+    pub parameters: Vec<Statment>,
+    // Boxed to keep every value small.
+    pub signature: Box<Signature>,
+    // Shared by the copies of one function value, two functions are equal when they share it.
+    pub body: Rc<Vec<Statment>>,
+    // The module the function was declared in, its body runs there when another module calls it.
+    pub module: Option<ModuleScope>
+}
+// The annotated parameter types (one per parameter) and return type of a function.
+#[derive(Debug, Clone)]
+pub struct Signature {
+    pub params: Vec<TypeAnnotation>,
+    pub result: TypeAnnotation
 }
 #[derive(Debug, Clone)]
 pub struct MemberVal {
@@ -77,4 +114,324 @@ pub struct MemberVal {
     pub property: ValueType,
     pub eval: ValueType,
     pub computed: bool
-}
\ No newline at end of file
+}
+
+impl ObjectVal {
+    pub fn new(properties: OrderedMap<String, ValueType>) -> Self {
+        Self { base: "ObjectVal".to_string(), properties: Rc::new(RefCell::new(properties)) }
+    }
+}
+impl ArrayVal {
+    pub fn new(contents: Vec<ValueType>) -> Self {
+        Self { base: "ArrayVal".to_string(), contents: Rc::new(RefCell::new(contents)) }
+    }
+}
+impl MapVal {
+    pub fn new() -> Self {
+        Self { base: "MapVal".to_string(), entries: Rc::new(RefCell::new(OrderedMap::new())) }
+    }
+
+    // Overwriting an entry keeps the key it was first stored under, `1.0` does not replace the key `1`.
+    pub fn insert(&self, hash: HashKey, key: ValueType, value: ValueType) {
+        let mut entries = self.entries.borrow_mut();
+        let key: ValueType = match entries.get(&hash) {
+            Some((first, _)) => first.clone(),
+            None => key
+        };
+        entries.insert(hash, (key, value));
+    }
+}
+impl SetVal {
+    pub fn new() -> Self {
+        Self { base: "SetVal".to_string(), members: Rc::new(RefCell::new(OrderedMap::new())) }
+    }
+
+    // Adding a member that is already there keeps the first one.
+    pub fn insert(&self, hash: HashKey, member: ValueType) {
+        let mut members = self.members.borrow_mut();
+        if (!members.contains_key(&hash)) {
+            members.insert(hash, member);
+        }
+    }
+}
+
+impl fmt::Display for ValueType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", format_value(self, false, &mut Vec::new(), &mut |_| None))
+    }
+}
+
+// The type of a value spelled the way annotations write it, used in every error message.
+pub fn type_name(value: &ValueType) -> &'static str {
+    match value {
+        ValueType::Null(_) | ValueType::None() => "null",
+        ValueType::Number(_) | ValueType::FnNumber(_) => "float",
+        ValueType::Integer(_) => "int",
+        ValueType::BigInt(_) => "bigint",
+        ValueType::String(_) | ValueType::FnString(_) => "str",
+        ValueType::Boolean(_) => "bool",
+        ValueType::Object(_) | ValueType::FnObject(_) => "obj",
+        ValueType::Array(_) => "arr",
+        ValueType::Map(_) => "map",
+        ValueType::Set(_) => "set",
+        ValueType::Member(member) => type_name(&member.eval),
+        ValueType::NativeFn(_) | ValueType::Function(_) => "fn",
+        ValueType::FnVoid(_) => "void"
+    }
+}
+
+pub fn is_null(value: &ValueType) -> bool {
+    matches!(value, ValueType::Null(_) | ValueType::None())
+}
+
+// false, null, 0 and "" are falsy, every other value is truthy.
+pub fn is_truthy(value: &ValueType) -> bool {
+    match value {
+        ValueType::Null(_) | ValueType::None() | ValueType::FnVoid(_) => false,
+        ValueType::Boolean(boolean) => boolean.value,
+        ValueType::Number(num) | ValueType::FnNumber(num) => num.value != 0.0,
+        ValueType::Integer(int) => int.value != 0,
+        ValueType::BigInt(big) => !big.value.is_zero(),
+        ValueType::String(string) | ValueType::FnString(string) => !string.value.is_empty(),
+        ValueType::Member(member) => is_truthy(&member.eval),
+        _ => true
+    }
+}
+
+// Values of different types are never equal except ints, floats and bigints, which compare as numbers.
+// Arrays, objects, maps and sets are compared by their contents.
+pub fn values_equal(left: &ValueType, right: &ValueType) -> bool {
+    contents_equal(left, right, &mut Vec::new())
+}
+
+// `comparing` holds the pairs of containers already being compared further up, meeting
+// one of them again means the structures loop back the same way.
+fn contents_equal(left: &ValueType, right: &ValueType, comparing: &mut Vec<(usize, usize)>) -> bool {
+    let pair: Option<(usize, usize)> = match (left, right) {
+        (ValueType::Array(l_arr), ValueType::Array(r_arr)) => Some((Rc::as_ptr(&l_arr.contents) as usize, Rc::as_ptr(&r_arr.contents) as usize)),
+        (ValueType::Object(l_obj), ValueType::Object(r_obj)) => Some((Rc::as_ptr(&l_obj.properties) as usize, Rc::as_ptr(&r_obj.properties) as usize)),
+        (ValueType::Map(l_map), ValueType::Map(r_map)) => Some((Rc::as_ptr(&l_map.entries) as usize, Rc::as_ptr(&r_map.entries) as usize)),
+        (_, _) => None
+    };
+    if let Some(pair) = pair {
+        if (pair.0 == pair.1 || comparing.contains(&pair)) {
+            return true;
+        }
+        comparing.push(pair);
+    }
+
+    let equal: bool = match (left, right) {
+        (ValueType::Null(_) | ValueType::None(), ValueType::Null(_) | ValueType::None()) => true,
+        (left, right) if is_numeric(left) && is_numeric(right) => compare_numbers(left, right) == Some(Ordering::Equal),
+        (ValueType::String(l_str), ValueType::String(r_str)) => l_str.value == r_str.value,
+        (ValueType::Boolean(l_bool), ValueType::Boolean(r_bool)) => l_bool.value == r_bool.value,
+        (ValueType::Array(l_arr), ValueType::Array(r_arr)) => {
+            let (l_contents, r_contents) = (l_arr.contents.borrow(), r_arr.contents.borrow());
+            l_contents.len() == r_contents.len() && l_contents.iter().zip(r_contents.iter()).all(|(l, r)| contents_equal(l, r, comparing))
+        }
+        (ValueType::Object(l_obj), ValueType::Object(r_obj)) => {
+            let (l_properties, r_properties) = (l_obj.properties.borrow(), r_obj.properties.borrow());
+            l_properties.len() == r_properties.len() && l_properties.iter().all(|(key, l)| {
+                r_properties.get(key).is_some_and(|r| contents_equal(l, r, comparing))
+            })
+        }
+        (ValueType::Map(l_map), ValueType::Map(r_map)) => {
+            let (l_entries, r_entries) = (l_map.entries.borrow(), r_map.entries.borrow());
+            l_entries.len() == r_entries.len() && l_entries.iter().all(|(key, (_, l))| {
+                r_entries.get(key).is_some_and(|(_, r)| contents_equal(l, r, comparing))
+            })
+        }
+        (ValueType::Set(l_set), ValueType::Set(r_set)) => {
+            let (l_members, r_members) = (l_set.members.borrow(), r_set.members.borrow());
+            l_members.len() == r_members.len() && l_members.keys().all(|key| r_members.contains_key(key))
+        }
+        (ValueType::Function(l_func), ValueType::Function(r_func)) => Rc::ptr_eq(&l_func.body, &r_func.body),
+        (ValueType::NativeFn(l_native), ValueType::NativeFn(r_native)) => l_native.name == r_native.name,
+        (_, _) => false
+    };
+
+    if (pair.is_some()) {
+        comparing.pop();
+    }
+    equal
+}
+
+// Writes a string the way it appears in source code.
+pub fn quote(string: &str) -> String {
+    let mut quoted: String = String::from("\"");
+    for ch in string.chars() {
+        match ch {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\t' => quoted.push_str("\\t"),
+            '\r' => quoted.push_str("\\r"),
+            '\0' => quoted.push_str("\\0"),
+            ch if ch.is_control() => quoted.push_str(format!("\\u{{{:X}}}", ch as u32).as_str()),
+            ch => quoted.push(ch)
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+// Formats a value for users. Top level strings are written as is unless `quoted` is set, strings
+// inside arrays and objects are always quoted. `seen` holds the containers currently being
+// written so a container that holds itself prints as [...] or {...} instead of recursing forever.
+// `hook` may replace the text of an object, the interpreter uses it for user defined toString methods.
+pub fn format_value(value: &ValueType, quoted: bool, seen: &mut Vec<usize>, hook: &mut dyn FnMut(&ValueType) -> Option<String>) -> String {
+    match value {
+        ValueType::Null(_) | ValueType::None() => "null".to_string(),
+        ValueType::Number(num) | ValueType::FnNumber(num) => num.value.to_string(),
+        ValueType::Integer(int) => int.value.to_string(),
+        ValueType::BigInt(big) => if (quoted) { format!("{}n", big.value) } else { big.value.to_string() },
+        ValueType::String(string) | ValueType::FnString(string) => {
+            if (quoted) { quote(&string.value) } else { string.value.clone() }
+        }
+        ValueType::Boolean(boolean) => boolean.value.to_string(),
+        ValueType::Array(arr) => {
+            let address: usize = Rc::as_ptr(&arr.contents) as usize;
+            if (seen.contains(&address)) {
+                return "[...]".to_string();
+            }
+
+            seen.push(address);
+            let contents: Vec<String> = arr.contents.borrow().iter().map(|content| format_value(content, true, seen, hook)).collect();
+            seen.pop();
+            format!("[{}]", contents.join(", "))
+        }
+        ValueType::Object(obj) | ValueType::FnObject(obj) => {
+            let address: usize = Rc::as_ptr(&obj.properties) as usize;
+            if (seen.contains(&address)) {
+                return "{...}".to_string();
+            }
+            if let Some(custom) = hook(value) {
+                return custom;
+            }
+            if (obj.properties.borrow().is_empty()) {
+                return "{}".to_string();
+            }
+
+            seen.push(address);
+            let properties: Vec<String> = obj.properties.borrow().iter().map(|(key, value)| {
+                let key: String = if (isidentifier(key)) { key.clone() } else { quote(key) };
+                format!("{}: {}", key, format_value(value, true, seen, hook))
+            }).collect();
+            seen.pop();
+            format!("{{ {} }}", properties.join(", "))
+        }
+        ValueType::Map(map) => {
+            let address: usize = Rc::as_ptr(&map.entries) as usize;
+            if (seen.contains(&address)) {
+                return "Map {...}".to_string();
+            }
+            if (map.entries.borrow().is_empty()) {
+                return "Map {}".to_string();
+            }
+
+            seen.push(address);
+            let entries: Vec<String> = map.entries.borrow().values().map(|(key, value)| {
+                format!("{}: {}", format_value(key, true, seen, hook), format_value(value, true, seen, hook))
+            }).collect();
+            seen.pop();
+            format!("Map {{ {} }}", entries.join(", "))
+        }
+        ValueType::Set(set) => {
+            if (set.members.borrow().is_empty()) {
+                return "Set {}".to_string();
+            }
+            let members: Vec<String> = set.members.borrow().values().map(|member| format_value(member, true, seen, hook)).collect();
+            format!("Set {{ {} }}", members.join(", "))
+        }
+        ValueType::Member(member) => format_value(&member.eval, quoted, seen, hook),
+        ValueType::NativeFn(native) => format!("<native fn {}>", native.name),
+        ValueType::Function(func) => {
+            let params: Vec<String> = func.parameters.iter().zip(&func.signature.params).map(|(param, annotation)| match annotation {
+                TypeAnnotation::Any => pattern_text(param),
+                annotation => match param {
+                    Statment::AssignPattern(assign) => format!("{}: {} = ...", pattern_text(&assign.target), type_text(annotation)),
+                    _ => format!("{}: {}", pattern_text(param), type_text(annotation))
+                }
+            }).collect();
+            match &func.signature.result {
+                TypeAnnotation::Any => format!("<fn {}({})>", func.name, params.join(", ")),
+                annotation => format!("<fn {}({}) -> {}>", func.name, params.join(", "), type_text(annotation))
+            }
+        }
+        ValueType::FnVoid(_) => "void".to_string()
+    }
+}
+
+fn json_string(string: &str) -> String {
+    let mut quoted: String = String::from("\"");
+    for ch in string.chars() {
+        match ch {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\t' => quoted.push_str("\\t"),
+            '\r' => quoted.push_str("\\r"),
+            ch if ch.is_control() => quoted.push_str(format!("\\u{:04x}", ch as u32).as_str()),
+            ch => quoted.push(ch)
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+// Serializes a value as JSON. Booleans and null map onto their JSON literals, numbers
+// that JSON cannot represent and functions are written as null. Maps become objects
+// with their keys written as text and sets become arrays.
+pub fn to_json(value: &ValueType) -> Eval<String> {
+    json_value(value, &mut Vec::new())
+}
+
+fn json_value(value: &ValueType, seen: &mut Vec<usize>) -> Eval<String> {
+    let address: Option<usize> = match value {
+        ValueType::Array(arr) => Some(Rc::as_ptr(&arr.contents) as usize),
+        ValueType::Object(obj) | ValueType::FnObject(obj) => Some(Rc::as_ptr(&obj.properties) as usize),
+        ValueType::Map(map) => Some(Rc::as_ptr(&map.entries) as usize),
+        _ => None
+    };
+    if let Some(address) = address {
+        if (seen.contains(&address)) {
+            return fault("TypeError", "Cannot convert a structure that contains itself to JSON.".to_string());
+        }
+        seen.push(address);
+    }
+
+    let json: String = match value {
+        ValueType::Boolean(boolean) => boolean.value.to_string(),
+        ValueType::Number(num) | ValueType::FnNumber(num) if num.value.is_finite() => num.value.to_string(),
+        ValueType::Integer(int) => int.value.to_string(),
+        ValueType::BigInt(big) => big.value.to_string(),
+        ValueType::String(string) | ValueType::FnString(string) => json_string(&string.value),
+        ValueType::Array(arr) => {
+            let contents: Vec<String> = arr.contents.borrow().iter().map(|content| json_value(content, seen)).collect::<Eval<Vec<String>>>()?;
+            format!("[{}]", contents.join(","))
+        }
+        ValueType::Set(set) => {
+            let members: Vec<String> = set.members.borrow().values().map(|member| json_value(member, seen)).collect::<Eval<Vec<String>>>()?;
+            format!("[{}]", members.join(","))
+        }
+        ValueType::Object(obj) | ValueType::FnObject(obj) => {
+            let properties: Vec<String> = obj.properties.borrow().iter().map(|(key, value)| {
+                Ok(format!("{}:{}", json_string(key), json_value(value, seen)?))
+            }).collect::<Eval<Vec<String>>>()?;
+            format!("{{{}}}", properties.join(","))
+        }
+        ValueType::Map(map) => {
+            let entries: Vec<String> = map.entries.borrow().values().map(|(key, value)| {
+                Ok(format!("{}:{}", json_string(&key.to_string()), json_value(value, seen)?))
+            }).collect::<Eval<Vec<String>>>()?;
+            format!("{{{}}}", entries.join(","))
+        }
+        ValueType::Member(member) => json_value(&member.eval, seen)?,
+        _ => "null".to_string()
+    };
+
+    if (address.is_some()) {
+        seen.pop();
+    }
+    Ok(json)
+}
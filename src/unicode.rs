@@ -0,0 +1,674 @@
+// Author: ContentGamer
+// Character properties from the Unicode 14.0 character database: the identifier classes of
+// UAX #31 and the grapheme cluster break classes of UAX #29. The tables are sorted ranges of code
+// points, generated from the database and not edited by hand.
+
+use core::cmp::Ordering;
+
+// The Grapheme_Cluster_Break class of a character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Break {
+    Cr,
+    Lf,
+    Control,
+    Extend,
+    Zwj,
+    RegionalIndicator,
+    Prepend,
+    SpacingMark,
+    // Hangul jamo and syllables.
+    L,
+    V,
+    T,
+    Lv,
+    Lvt,
+    Other
+}
+
+fn find<T: Copy>(table: &[(u32, u32, T)], ch: char) -> Option<T> {
+    let code: u32 = ch as u32;
+    table.binary_search_by(|&(start, end, _)| {
+        if (end < code) {
+            Ordering::Less
+        } else if (start > code) {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }).ok().map(|indx| table[indx].2)
+}
+
+fn contains(table: &[(u32, u32)], ch: char) -> bool {
+    let code: u32 = ch as u32;
+    table.binary_search_by(|&(start, end)| {
+        if (end < code) {
+            Ordering::Less
+        } else if (start > code) {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }).is_ok()
+}
+
+pub fn is_xid_start(ch: char) -> bool {
+    contains(XID_START, ch)
+}
+
+pub fn is_xid_continue(ch: char) -> bool {
+    contains(XID_CONTINUE, ch)
+}
+
+pub fn is_extended_pictographic(ch: char) -> bool {
+    contains(EXTENDED_PICTOGRAPHIC, ch)
+}
+
+pub fn grapheme_break(ch: char) -> Break {
+    find(GRAPHEME_BREAK, ch).unwrap_or(Break::Other)
+}
+
+// XID_Start.
+const XID_START: &[(u32, u32)] = &[
+    (0x41, 0x5A), (0x61, 0x7A), (0xAA, 0xAA), (0xB5, 0xB5), (0xBA, 0xBA), (0xC0, 0xD6),
+    (0xD8, 0xF6), (0xF8, 0x2C1), (0x2C6, 0x2D1), (0x2E0, 0x2E4), (0x2EC, 0x2EC), (0x2EE, 0x2EE),
+    (0x370, 0x374), (0x376, 0x377), (0x37B, 0x37D), (0x37F, 0x37F), (0x386, 0x386), (0x388, 0x38A),
+    (0x38C, 0x38C), (0x38E, 0x3A1), (0x3A3, 0x3F5), (0x3F7, 0x481), (0x48A, 0x52F), (0x531, 0x556),
+    (0x559, 0x559), (0x560, 0x588), (0x5D0, 0x5EA), (0x5EF, 0x5F2), (0x620, 0x64A), (0x66E, 0x66F),
+    (0x671, 0x6D3), (0x6D5, 0x6D5), (0x6E5, 0x6E6), (0x6EE, 0x6EF), (0x6FA, 0x6FC), (0x6FF, 0x6FF),
+    (0x710, 0x710), (0x712, 0x72F), (0x74D, 0x7A5), (0x7B1, 0x7B1), (0x7CA, 0x7EA), (0x7F4, 0x7F5),
+    (0x7FA, 0x7FA), (0x800, 0x815), (0x81A, 0x81A), (0x824, 0x824), (0x828, 0x828), (0x840, 0x858),
+    (0x860, 0x86A), (0x870, 0x887), (0x889, 0x88E), (0x8A0, 0x8C9), (0x904, 0x939), (0x93D, 0x93D),
+    (0x950, 0x950), (0x958, 0x961), (0x971, 0x980), (0x985, 0x98C), (0x98F, 0x990), (0x993, 0x9A8),
+    (0x9AA, 0x9B0), (0x9B2, 0x9B2), (0x9B6, 0x9B9), (0x9BD, 0x9BD), (0x9CE, 0x9CE), (0x9DC, 0x9DD),
+    (0x9DF, 0x9E1), (0x9F0, 0x9F1), (0x9FC, 0x9FC), (0xA05, 0xA0A), (0xA0F, 0xA10), (0xA13, 0xA28),
+    (0xA2A, 0xA30), (0xA32, 0xA33), (0xA35, 0xA36), (0xA38, 0xA39), (0xA59, 0xA5C), (0xA5E, 0xA5E),
+    (0xA72, 0xA74), (0xA85, 0xA8D), (0xA8F, 0xA91), (0xA93, 0xAA8), (0xAAA, 0xAB0), (0xAB2, 0xAB3),
+    (0xAB5, 0xAB9), (0xABD, 0xABD), (0xAD0, 0xAD0), (0xAE0, 0xAE1), (0xAF9, 0xAF9), (0xB05, 0xB0C),
+    (0xB0F, 0xB10), (0xB13, 0xB28), (0xB2A, 0xB30), (0xB32, 0xB33), (0xB35, 0xB39), (0xB3D, 0xB3D),
+    (0xB5C, 0xB5D), (0xB5F, 0xB61), (0xB71, 0xB71), (0xB83, 0xB83), (0xB85, 0xB8A), (0xB8E, 0xB90),
+    (0xB92, 0xB95), (0xB99, 0xB9A), (0xB9C, 0xB9C), (0xB9E, 0xB9F), (0xBA3, 0xBA4), (0xBA8, 0xBAA),
+    (0xBAE, 0xBB9), (0xBD0, 0xBD0), (0xC05, 0xC0C), (0xC0E, 0xC10), (0xC12, 0xC28), (0xC2A, 0xC39),
+    (0xC3D, 0xC3D), (0xC58, 0xC5A), (0xC5D, 0xC5D), (0xC60, 0xC61), (0xC80, 0xC80), (0xC85, 0xC8C),
+    (0xC8E, 0xC90), (0xC92, 0xCA8), (0xCAA, 0xCB3), (0xCB5, 0xCB9), (0xCBD, 0xCBD), (0xCDD, 0xCDE),
+    (0xCE0, 0xCE1), (0xCF1, 0xCF2), (0xD04, 0xD0C), (0xD0E, 0xD10), (0xD12, 0xD3A), (0xD3D, 0xD3D),
+    (0xD4E, 0xD4E), (0xD54, 0xD56), (0xD5F, 0xD61), (0xD7A, 0xD7F), (0xD85, 0xD96), (0xD9A, 0xDB1),
+    (0xDB3, 0xDBB), (0xDBD, 0xDBD), (0xDC0, 0xDC6), (0xE01, 0xE30), (0xE32, 0xE32), (0xE40, 0xE46),
+    (0xE81, 0xE82), (0xE84, 0xE84), (0xE86, 0xE8A), (0xE8C, 0xEA3), (0xEA5, 0xEA5), (0xEA7, 0xEB0),
+    (0xEB2, 0xEB2), (0xEBD, 0xEBD), (0xEC0, 0xEC4), (0xEC6, 0xEC6), (0xEDC, 0xEDF), (0xF00, 0xF00),
+    (0xF40, 0xF47), (0xF49, 0xF6C), (0xF88, 0xF8C), (0x1000, 0x102A), (0x103F, 0x103F), (0x1050, 0x1055),
+    (0x105A, 0x105D), (0x1061, 0x1061), (0x1065, 0x1066), (0x106E, 0x1070), (0x1075, 0x1081), (0x108E, 0x108E),
+    (0x10A0, 0x10C5), (0x10C7, 0x10C7), (0x10CD, 0x10CD), (0x10D0, 0x10FA), (0x10FC, 0x1248), (0x124A, 0x124D),
+    (0x1250, 0x1256), (0x1258, 0x1258), (0x125A, 0x125D), (0x1260, 0x1288), (0x128A, 0x128D), (0x1290, 0x12B0),
+    (0x12B2, 0x12B5), (0x12B8, 0x12BE), (0x12C0, 0x12C0), (0x12C2, 0x12C5), (0x12C8, 0x12D6), (0x12D8, 0x1310),
+    (0x1312, 0x1315), (0x1318, 0x135A), (0x1380, 0x138F), (0x13A0, 0x13F5), (0x13F8, 0x13FD), (0x1401, 0x166C),
+    (0x166F, 0x167F), (0x1681, 0x169A), (0x16A0, 0x16EA), (0x16EE, 0x16F8), (0x1700, 0x1711), (0x171F, 0x1731),
+    (0x1740, 0x1751), (0x1760, 0x176C), (0x176E, 0x1770), (0x1780, 0x17B3), (0x17D7, 0x17D7), (0x17DC, 0x17DC),
+    (0x1820, 0x1878), (0x1880, 0x18A8), (0x18AA, 0x18AA), (0x18B0, 0x18F5), (0x1900, 0x191E), (0x1950, 0x196D),
+    (0x1970, 0x1974), (0x1980, 0x19AB), (0x19B0, 0x19C9), (0x1A00, 0x1A16), (0x1A20, 0x1A54), (0x1AA7, 0x1AA7),
+    (0x1B05, 0x1B33), (0x1B45, 0x1B4C), (0x1B83, 0x1BA0), (0x1BAE, 0x1BAF), (0x1BBA, 0x1BE5), (0x1C00, 0x1C23),
+    (0x1C4D, 0x1C4F), (0x1C5A, 0x1C7D), (0x1C80, 0x1C88), (0x1C90, 0x1CBA), (0x1CBD, 0x1CBF), (0x1CE9, 0x1CEC),
+    (0x1CEE, 0x1CF3), (0x1CF5, 0x1CF6), (0x1CFA, 0x1CFA), (0x1D00, 0x1DBF), (0x1E00, 0x1F15), (0x1F18, 0x1F1D),
+    (0x1F20, 0x1F45), (0x1F48, 0x1F4D), (0x1F50, 0x1F57), (0x1F59, 0x1F59), (0x1F5B, 0x1F5B), (0x1F5D, 0x1F5D),
+    (0x1F5F, 0x1F7D), (0x1F80, 0x1FB4), (0x1FB6, 0x1FBC), (0x1FBE, 0x1FBE), (0x1FC2, 0x1FC4), (0x1FC6, 0x1FCC),
+    (0x1FD0, 0x1FD3), (0x1FD6, 0x1FDB), (0x1FE0, 0x1FEC), (0x1FF2, 0x1FF4), (0x1FF6, 0x1FFC), (0x2071, 0x2071),
+    (0x207F, 0x207F), (0x2090, 0x209C), (0x2102, 0x2102), (0x2107, 0x2107), (0x210A, 0x2113), (0x2115, 0x2115),
+    (0x2118, 0x211D), (0x2124, 0x2124), (0x2126, 0x2126), (0x2128, 0x2128), (0x212A, 0x2139), (0x213C, 0x213F),
+    (0x2145, 0x2149), (0x214E, 0x214E), (0x2160, 0x2188), (0x2C00, 0x2CE4), (0x2CEB, 0x2CEE), (0x2CF2, 0x2CF3),
+    (0x2D00, 0x2D25), (0x2D27, 0x2D27), (0x2D2D, 0x2D2D), (0x2D30, 0x2D67), (0x2D6F, 0x2D6F), (0x2D80, 0x2D96),
+    (0x2DA0, 0x2DA6), (0x2DA8, 0x2DAE), (0x2DB0, 0x2DB6), (0x2DB8, 0x2DBE), (0x2DC0, 0x2DC6), (0x2DC8, 0x2DCE),
+    (0x2DD0, 0x2DD6), (0x2DD8, 0x2DDE), (0x3005, 0x3007), (0x3021, 0x3029), (0x3031, 0x3035), (0x3038, 0x303C),
+    (0x3041, 0x3096), (0x309D, 0x309F), (0x30A1, 0x30FA), (0x30FC, 0x30FF), (0x3105, 0x312F), (0x3131, 0x318E),
+    (0x31A0, 0x31BF), (0x31F0, 0x31FF), (0x3400, 0x4DBF), (0x4E00, 0xA48C), (0xA4D0, 0xA4FD), (0xA500, 0xA60C),
+    (0xA610, 0xA61F), (0xA62A, 0xA62B), (0xA640, 0xA66E), (0xA67F, 0xA69D), (0xA6A0, 0xA6EF), (0xA717, 0xA71F),
+    (0xA722, 0xA788), (0xA78B, 0xA7CA), (0xA7D0, 0xA7D1), (0xA7D3, 0xA7D3), (0xA7D5, 0xA7D9), (0xA7F2, 0xA801),
+    (0xA803, 0xA805), (0xA807, 0xA80A), (0xA80C, 0xA822), (0xA840, 0xA873), (0xA882, 0xA8B3), (0xA8F2, 0xA8F7),
+    (0xA8FB, 0xA8FB), (0xA8FD, 0xA8FE), (0xA90A, 0xA925), (0xA930, 0xA946), (0xA960, 0xA97C), (0xA984, 0xA9B2),
+    (0xA9CF, 0xA9CF), (0xA9E0, 0xA9E4), (0xA9E6, 0xA9EF), (0xA9FA, 0xA9FE), (0xAA00, 0xAA28), (0xAA40, 0xAA42),
+    (0xAA44, 0xAA4B), (0xAA60, 0xAA76), (0xAA7A, 0xAA7A), (0xAA7E, 0xAAAF), (0xAAB1, 0xAAB1), (0xAAB5, 0xAAB6),
+    (0xAAB9, 0xAABD), (0xAAC0, 0xAAC0), (0xAAC2, 0xAAC2), (0xAADB, 0xAADD), (0xAAE0, 0xAAEA), (0xAAF2, 0xAAF4),
+    (0xAB01, 0xAB06), (0xAB09, 0xAB0E), (0xAB11, 0xAB16), (0xAB20, 0xAB26), (0xAB28, 0xAB2E), (0xAB30, 0xAB5A),
+    (0xAB5C, 0xAB69), (0xAB70, 0xABE2), (0xAC00, 0xD7A3), (0xD7B0, 0xD7C6), (0xD7CB, 0xD7FB), (0xF900, 0xFA6D),
+    (0xFA70, 0xFAD9), (0xFB00, 0xFB06), (0xFB13, 0xFB17), (0xFB1D, 0xFB1D), (0xFB1F, 0xFB28), (0xFB2A, 0xFB36),
+    (0xFB38, 0xFB3C), (0xFB3E, 0xFB3E), (0xFB40, 0xFB41), (0xFB43, 0xFB44), (0xFB46, 0xFBB1), (0xFBD3, 0xFC5D),
+    (0xFC64, 0xFD3D), (0xFD50, 0xFD8F), (0xFD92, 0xFDC7), (0xFDF0, 0xFDF9), (0xFE71, 0xFE71), (0xFE73, 0xFE73),
+    (0xFE77, 0xFE77), (0xFE79, 0xFE79), (0xFE7B, 0xFE7B), (0xFE7D, 0xFE7D), (0xFE7F, 0xFEFC), (0xFF21, 0xFF3A),
+    (0xFF41, 0xFF5A), (0xFF66, 0xFF9D), (0xFFA0, 0xFFBE), (0xFFC2, 0xFFC7), (0xFFCA, 0xFFCF), (0xFFD2, 0xFFD7),
+    (0xFFDA, 0xFFDC), (0x10000, 0x1000B), (0x1000D, 0x10026), (0x10028, 0x1003A), (0x1003C, 0x1003D), (0x1003F, 0x1004D),
+    (0x10050, 0x1005D), (0x10080, 0x100FA), (0x10140, 0x10174), (0x10280, 0x1029C), (0x102A0, 0x102D0), (0x10300, 0x1031F),
+    (0x1032D, 0x1034A), (0x10350, 0x10375), (0x10380, 0x1039D), (0x103A0, 0x103C3), (0x103C8, 0x103CF), (0x103D1, 0x103D5),
+    (0x10400, 0x1049D), (0x104B0, 0x104D3), (0x104D8, 0x104FB), (0x10500, 0x10527), (0x10530, 0x10563), (0x10570, 0x1057A),
+    (0x1057C, 0x1058A), (0x1058C, 0x10592), (0x10594, 0x10595), (0x10597, 0x105A1), (0x105A3, 0x105B1), (0x105B3, 0x105B9),
+    (0x105BB, 0x105BC), (0x10600, 0x10736), (0x10740, 0x10755), (0x10760, 0x10767), (0x10780, 0x10785), (0x10787, 0x107B0),
+    (0x107B2, 0x107BA), (0x10800, 0x10805), (0x10808, 0x10808), (0x1080A, 0x10835), (0x10837, 0x10838), (0x1083C, 0x1083C),
+    (0x1083F, 0x10855), (0x10860, 0x10876), (0x10880, 0x1089E), (0x108E0, 0x108F2), (0x108F4, 0x108F5), (0x10900, 0x10915),
+    (0x10920, 0x10939), (0x10980, 0x109B7), (0x109BE, 0x109BF), (0x10A00, 0x10A00), (0x10A10, 0x10A13), (0x10A15, 0x10A17),
+    (0x10A19, 0x10A35), (0x10A60, 0x10A7C), (0x10A80, 0x10A9C), (0x10AC0, 0x10AC7), (0x10AC9, 0x10AE4), (0x10B00, 0x10B35),
+    (0x10B40, 0x10B55), (0x10B60, 0x10B72), (0x10B80, 0x10B91), (0x10C00, 0x10C48), (0x10C80, 0x10CB2), (0x10CC0, 0x10CF2),
+    (0x10D00, 0x10D23), (0x10E80, 0x10EA9), (0x10EB0, 0x10EB1), (0x10F00, 0x10F1C), (0x10F27, 0x10F27), (0x10F30, 0x10F45),
+    (0x10F70, 0x10F81), (0x10FB0, 0x10FC4), (0x10FE0, 0x10FF6), (0x11003, 0x11037), (0x11071, 0x11072), (0x11075, 0x11075),
+    (0x11083, 0x110AF), (0x110D0, 0x110E8), (0x11103, 0x11126), (0x11144, 0x11144), (0x11147, 0x11147), (0x11150, 0x11172),
+    (0x11176, 0x11176), (0x11183, 0x111B2), (0x111C1, 0x111C4), (0x111DA, 0x111DA), (0x111DC, 0x111DC), (0x11200, 0x11211),
+    (0x11213, 0x1122B), (0x11280, 0x11286), (0x11288, 0x11288), (0x1128A, 0x1128D), (0x1128F, 0x1129D), (0x1129F, 0x112A8),
+    (0x112B0, 0x112DE), (0x11305, 0x1130C), (0x1130F, 0x11310), (0x11313, 0x11328), (0x1132A, 0x11330), (0x11332, 0x11333),
+    (0x11335, 0x11339), (0x1133D, 0x1133D), (0x11350, 0x11350), (0x1135D, 0x11361), (0x11400, 0x11434), (0x11447, 0x1144A),
+    (0x1145F, 0x11461), (0x11480, 0x114AF), (0x114C4, 0x114C5), (0x114C7, 0x114C7), (0x11580, 0x115AE), (0x115D8, 0x115DB),
+    (0x11600, 0x1162F), (0x11644, 0x11644), (0x11680, 0x116AA), (0x116B8, 0x116B8), (0x11700, 0x1171A), (0x11740, 0x11746),
+    (0x11800, 0x1182B), (0x118A0, 0x118DF), (0x118FF, 0x11906), (0x11909, 0x11909), (0x1190C, 0x11913), (0x11915, 0x11916),
+    (0x11918, 0x1192F), (0x1193F, 0x1193F), (0x11941, 0x11941), (0x119A0, 0x119A7), (0x119AA, 0x119D0), (0x119E1, 0x119E1),
+    (0x119E3, 0x119E3), (0x11A00, 0x11A00), (0x11A0B, 0x11A32), (0x11A3A, 0x11A3A), (0x11A50, 0x11A50), (0x11A5C, 0x11A89),
+    (0x11A9D, 0x11A9D), (0x11AB0, 0x11AF8), (0x11C00, 0x11C08), (0x11C0A, 0x11C2E), (0x11C40, 0x11C40), (0x11C72, 0x11C8F),
+    (0x11D00, 0x11D06), (0x11D08, 0x11D09), (0x11D0B, 0x11D30), (0x11D46, 0x11D46), (0x11D60, 0x11D65), (0x11D67, 0x11D68),
+    (0x11D6A, 0x11D89), (0x11D98, 0x11D98), (0x11EE0, 0x11EF2), (0x11FB0, 0x11FB0), (0x12000, 0x12399), (0x12400, 0x1246E),
+    (0x12480, 0x12543), (0x12F90, 0x12FF0), (0x13000, 0x1342E), (0x14400, 0x14646), (0x16800, 0x16A38), (0x16A40, 0x16A5E),
+    (0x16A70, 0x16ABE), (0x16AD0, 0x16AED), (0x16B00, 0x16B2F), (0x16B40, 0x16B43), (0x16B63, 0x16B77), (0x16B7D, 0x16B8F),
+    (0x16E40, 0x16E7F), (0x16F00, 0x16F4A), (0x16F50, 0x16F50), (0x16F93, 0x16F9F), (0x16FE0, 0x16FE1), (0x16FE3, 0x16FE3),
+    (0x17000, 0x187F7), (0x18800, 0x18CD5), (0x18D00, 0x18D08), (0x1AFF0, 0x1AFF3), (0x1AFF5, 0x1AFFB), (0x1AFFD, 0x1AFFE),
+    (0x1B000, 0x1B122), (0x1B150, 0x1B152), (0x1B164, 0x1B167), (0x1B170, 0x1B2FB), (0x1BC00, 0x1BC6A), (0x1BC70, 0x1BC7C),
+    (0x1BC80, 0x1BC88), (0x1BC90, 0x1BC99), (0x1D400, 0x1D454), (0x1D456, 0x1D49C), (0x1D49E, 0x1D49F), (0x1D4A2, 0x1D4A2),
+    (0x1D4A5, 0x1D4A6), (0x1D4A9, 0x1D4AC), (0x1D4AE, 0x1D4B9), (0x1D4BB, 0x1D4BB), (0x1D4BD, 0x1D4C3), (0x1D4C5, 0x1D505),
+    (0x1D507, 0x1D50A), (0x1D50D, 0x1D514), (0x1D516, 0x1D51C), (0x1D51E, 0x1D539), (0x1D53B, 0x1D53E), (0x1D540, 0x1D544),
+    (0x1D546, 0x1D546), (0x1D54A, 0x1D550), (0x1D552, 0x1D6A5), (0x1D6A8, 0x1D6C0), (0x1D6C2, 0x1D6DA), (0x1D6DC, 0x1D6FA),
+    (0x1D6FC, 0x1D714), (0x1D716, 0x1D734), (0x1D736, 0x1D74E), (0x1D750, 0x1D76E), (0x1D770, 0x1D788), (0x1D78A, 0x1D7A8),
+    (0x1D7AA, 0x1D7C2), (0x1D7C4, 0x1D7CB), (0x1DF00, 0x1DF1E), (0x1E100, 0x1E12C), (0x1E137, 0x1E13D), (0x1E14E, 0x1E14E),
+    (0x1E290, 0x1E2AD), (0x1E2C0, 0x1E2EB), (0x1E7E0, 0x1E7E6), (0x1E7E8, 0x1E7EB), (0x1E7ED, 0x1E7EE), (0x1E7F0, 0x1E7FE),
+    (0x1E800, 0x1E8C4), (0x1E900, 0x1E943), (0x1E94B, 0x1E94B), (0x1EE00, 0x1EE03), (0x1EE05, 0x1EE1F), (0x1EE21, 0x1EE22),
+    (0x1EE24, 0x1EE24), (0x1EE27, 0x1EE27), (0x1EE29, 0x1EE32), (0x1EE34, 0x1EE37), (0x1EE39, 0x1EE39), (0x1EE3B, 0x1EE3B),
+    (0x1EE42, 0x1EE42), (0x1EE47, 0x1EE47), (0x1EE49, 0x1EE49), (0x1EE4B, 0x1EE4B), (0x1EE4D, 0x1EE4F), (0x1EE51, 0x1EE52),
+    (0x1EE54, 0x1EE54), (0x1EE57, 0x1EE57), (0x1EE59, 0x1EE59), (0x1EE5B, 0x1EE5B), (0x1EE5D, 0x1EE5D), (0x1EE5F, 0x1EE5F),
+    (0x1EE61, 0x1EE62), (0x1EE64, 0x1EE64), (0x1EE67, 0x1EE6A), (0x1EE6C, 0x1EE72), (0x1EE74, 0x1EE77), (0x1EE79, 0x1EE7C),
+    (0x1EE7E, 0x1EE7E), (0x1EE80, 0x1EE89), (0x1EE8B, 0x1EE9B), (0x1EEA1, 0x1EEA3), (0x1EEA5, 0x1EEA9), (0x1EEAB, 0x1EEBB),
+    (0x20000, 0x2A6DF), (0x2A700, 0x2B738), (0x2B740, 0x2B81D), (0x2B820, 0x2CEA1), (0x2CEB0, 0x2EBE0), (0x2F800, 0x2FA1D),
+    (0x30000, 0x3134A)
+];
+
+// XID_Continue.
+const XID_CONTINUE: &[(u32, u32)] = &[
+    (0x30, 0x39), (0x41, 0x5A), (0x5F, 0x5F), (0x61, 0x7A), (0xAA, 0xAA), (0xB5, 0xB5),
+    (0xB7, 0xB7), (0xBA, 0xBA), (0xC0, 0xD6), (0xD8, 0xF6), (0xF8, 0x2C1), (0x2C6, 0x2D1),
+    (0x2E0, 0x2E4), (0x2EC, 0x2EC), (0x2EE, 0x2EE), (0x300, 0x374), (0x376, 0x377), (0x37B, 0x37D),
+    (0x37F, 0x37F), (0x386, 0x38A), (0x38C, 0x38C), (0x38E, 0x3A1), (0x3A3, 0x3F5), (0x3F7, 0x481),
+    (0x483, 0x487), (0x48A, 0x52F), (0x531, 0x556), (0x559, 0x559), (0x560, 0x588), (0x591, 0x5BD),
+    (0x5BF, 0x5BF), (0x5C1, 0x5C2), (0x5C4, 0x5C5), (0x5C7, 0x5C7), (0x5D0, 0x5EA), (0x5EF, 0x5F2),
+    (0x610, 0x61A), (0x620, 0x669), (0x66E, 0x6D3), (0x6D5, 0x6DC), (0x6DF, 0x6E8), (0x6EA, 0x6FC),
+    (0x6FF, 0x6FF), (0x710, 0x74A), (0x74D, 0x7B1), (0x7C0, 0x7F5), (0x7FA, 0x7FA), (0x7FD, 0x7FD),
+    (0x800, 0x82D), (0x840, 0x85B), (0x860, 0x86A), (0x870, 0x887), (0x889, 0x88E), (0x898, 0x8E1),
+    (0x8E3, 0x963), (0x966, 0x96F), (0x971, 0x983), (0x985, 0x98C), (0x98F, 0x990), (0x993, 0x9A8),
+    (0x9AA, 0x9B0), (0x9B2, 0x9B2), (0x9B6, 0x9B9), (0x9BC, 0x9C4), (0x9C7, 0x9C8), (0x9CB, 0x9CE),
+    (0x9D7, 0x9D7), (0x9DC, 0x9DD), (0x9DF, 0x9E3), (0x9E6, 0x9F1), (0x9FC, 0x9FC), (0x9FE, 0x9FE),
+    (0xA01, 0xA03), (0xA05, 0xA0A), (0xA0F, 0xA10), (0xA13, 0xA28), (0xA2A, 0xA30), (0xA32, 0xA33),
+    (0xA35, 0xA36), (0xA38, 0xA39), (0xA3C, 0xA3C), (0xA3E, 0xA42), (0xA47, 0xA48), (0xA4B, 0xA4D),
+    (0xA51, 0xA51), (0xA59, 0xA5C), (0xA5E, 0xA5E), (0xA66, 0xA75), (0xA81, 0xA83), (0xA85, 0xA8D),
+    (0xA8F, 0xA91), (0xA93, 0xAA8), (0xAAA, 0xAB0), (0xAB2, 0xAB3), (0xAB5, 0xAB9), (0xABC, 0xAC5),
+    (0xAC7, 0xAC9), (0xACB, 0xACD), (0xAD0, 0xAD0), (0xAE0, 0xAE3), (0xAE6, 0xAEF), (0xAF9, 0xAFF),
+    (0xB01, 0xB03), (0xB05, 0xB0C), (0xB0F, 0xB10), (0xB13, 0xB28), (0xB2A, 0xB30), (0xB32, 0xB33),
+    (0xB35, 0xB39), (0xB3C, 0xB44), (0xB47, 0xB48), (0xB4B, 0xB4D), (0xB55, 0xB57), (0xB5C, 0xB5D),
+    (0xB5F, 0xB63), (0xB66, 0xB6F), (0xB71, 0xB71), (0xB82, 0xB83), (0xB85, 0xB8A), (0xB8E, 0xB90),
+    (0xB92, 0xB95), (0xB99, 0xB9A), (0xB9C, 0xB9C), (0xB9E, 0xB9F), (0xBA3, 0xBA4), (0xBA8, 0xBAA),
+    (0xBAE, 0xBB9), (0xBBE, 0xBC2), (0xBC6, 0xBC8), (0xBCA, 0xBCD), (0xBD0, 0xBD0), (0xBD7, 0xBD7),
+    (0xBE6, 0xBEF), (0xC00, 0xC0C), (0xC0E, 0xC10), (0xC12, 0xC28), (0xC2A, 0xC39), (0xC3C, 0xC44),
+    (0xC46, 0xC48), (0xC4A, 0xC4D), (0xC55, 0xC56), (0xC58, 0xC5A), (0xC5D, 0xC5D), (0xC60, 0xC63),
+    (0xC66, 0xC6F), (0xC80, 0xC83), (0xC85, 0xC8C), (0xC8E, 0xC90), (0xC92, 0xCA8), (0xCAA, 0xCB3),
+    (0xCB5, 0xCB9), (0xCBC, 0xCC4), (0xCC6, 0xCC8), (0xCCA, 0xCCD), (0xCD5, 0xCD6), (0xCDD, 0xCDE),
+    (0xCE0, 0xCE3), (0xCE6, 0xCEF), (0xCF1, 0xCF2), (0xD00, 0xD0C), (0xD0E, 0xD10), (0xD12, 0xD44),
+    (0xD46, 0xD48), (0xD4A, 0xD4E), (0xD54, 0xD57), (0xD5F, 0xD63), (0xD66, 0xD6F), (0xD7A, 0xD7F),
+    (0xD81, 0xD83), (0xD85, 0xD96), (0xD9A, 0xDB1), (0xDB3, 0xDBB), (0xDBD, 0xDBD), (0xDC0, 0xDC6),
+    (0xDCA, 0xDCA), (0xDCF, 0xDD4), (0xDD6, 0xDD6), (0xDD8, 0xDDF), (0xDE6, 0xDEF), (0xDF2, 0xDF3),
+    (0xE01, 0xE3A), (0xE40, 0xE4E), (0xE50, 0xE59), (0xE81, 0xE82), (0xE84, 0xE84), (0xE86, 0xE8A),
+    (0xE8C, 0xEA3), (0xEA5, 0xEA5), (0xEA7, 0xEBD), (0xEC0, 0xEC4), (0xEC6, 0xEC6), (0xEC8, 0xECD),
+    (0xED0, 0xED9), (0xEDC, 0xEDF), (0xF00, 0xF00), (0xF18, 0xF19), (0xF20, 0xF29), (0xF35, 0xF35),
+    (0xF37, 0xF37), (0xF39, 0xF39), (0xF3E, 0xF47), (0xF49, 0xF6C), (0xF71, 0xF84), (0xF86, 0xF97),
+    (0xF99, 0xFBC), (0xFC6, 0xFC6), (0x1000, 0x1049), (0x1050, 0x109D), (0x10A0, 0x10C5), (0x10C7, 0x10C7),
+    (0x10CD, 0x10CD), (0x10D0, 0x10FA), (0x10FC, 0x1248), (0x124A, 0x124D), (0x1250, 0x1256), (0x1258, 0x1258),
+    (0x125A, 0x125D), (0x1260, 0x1288), (0x128A, 0x128D), (0x1290, 0x12B0), (0x12B2, 0x12B5), (0x12B8, 0x12BE),
+    (0x12C0, 0x12C0), (0x12C2, 0x12C5), (0x12C8, 0x12D6), (0x12D8, 0x1310), (0x1312, 0x1315), (0x1318, 0x135A),
+    (0x135D, 0x135F), (0x1369, 0x1371), (0x1380, 0x138F), (0x13A0, 0x13F5), (0x13F8, 0x13FD), (0x1401, 0x166C),
+    (0x166F, 0x167F), (0x1681, 0x169A), (0x16A0, 0x16EA), (0x16EE, 0x16F8), (0x1700, 0x1715), (0x171F, 0x1734),
+    (0x1740, 0x1753), (0x1760, 0x176C), (0x176E, 0x1770), (0x1772, 0x1773), (0x1780, 0x17D3), (0x17D7, 0x17D7),
+    (0x17DC, 0x17DD), (0x17E0, 0x17E9), (0x180B, 0x180D), (0x180F, 0x1819), (0x1820, 0x1878), (0x1880, 0x18AA),
+    (0x18B0, 0x18F5), (0x1900, 0x191E), (0x1920, 0x192B), (0x1930, 0x193B), (0x1946, 0x196D), (0x1970, 0x1974),
+    (0x1980, 0x19AB), (0x19B0, 0x19C9), (0x19D0, 0x19DA), (0x1A00, 0x1A1B), (0x1A20, 0x1A5E), (0x1A60, 0x1A7C),
+    (0x1A7F, 0x1A89), (0x1A90, 0x1A99), (0x1AA7, 0x1AA7), (0x1AB0, 0x1ABD), (0x1ABF, 0x1ACE), (0x1B00, 0x1B4C),
+    (0x1B50, 0x1B59), (0x1B6B, 0x1B73), (0x1B80, 0x1BF3), (0x1C00, 0x1C37), (0x1C40, 0x1C49), (0x1C4D, 0x1C7D),
+    (0x1C80, 0x1C88), (0x1C90, 0x1CBA), (0x1CBD, 0x1CBF), (0x1CD0, 0x1CD2), (0x1CD4, 0x1CFA), (0x1D00, 0x1F15),
+    (0x1F18, 0x1F1D), (0x1F20, 0x1F45), (0x1F48, 0x1F4D), (0x1F50, 0x1F57), (0x1F59, 0x1F59), (0x1F5B, 0x1F5B),
+    (0x1F5D, 0x1F5D), (0x1F5F, 0x1F7D), (0x1F80, 0x1FB4), (0x1FB6, 0x1FBC), (0x1FBE, 0x1FBE), (0x1FC2, 0x1FC4),
+    (0x1FC6, 0x1FCC), (0x1FD0, 0x1FD3), (0x1FD6, 0x1FDB), (0x1FE0, 0x1FEC), (0x1FF2, 0x1FF4), (0x1FF6, 0x1FFC),
+    (0x203F, 0x2040), (0x2054, 0x2054), (0x2071, 0x2071), (0x207F, 0x207F), (0x2090, 0x209C), (0x20D0, 0x20DC),
+    (0x20E1, 0x20E1), (0x20E5, 0x20F0), (0x2102, 0x2102), (0x2107, 0x2107), (0x210A, 0x2113), (0x2115, 0x2115),
+    (0x2118, 0x211D), (0x2124, 0x2124), (0x2126, 0x2126), (0x2128, 0x2128), (0x212A, 0x2139), (0x213C, 0x213F),
+    (0x2145, 0x2149), (0x214E, 0x214E), (0x2160, 0x2188), (0x2C00, 0x2CE4), (0x2CEB, 0x2CF3), (0x2D00, 0x2D25),
+    (0x2D27, 0x2D27), (0x2D2D, 0x2D2D), (0x2D30, 0x2D67), (0x2D6F, 0x2D6F), (0x2D7F, 0x2D96), (0x2DA0, 0x2DA6),
+    (0x2DA8, 0x2DAE), (0x2DB0, 0x2DB6), (0x2DB8, 0x2DBE), (0x2DC0, 0x2DC6), (0x2DC8, 0x2DCE), (0x2DD0, 0x2DD6),
+    (0x2DD8, 0x2DDE), (0x2DE0, 0x2DFF), (0x3005, 0x3007), (0x3021, 0x302F), (0x3031, 0x3035), (0x3038, 0x303C),
+    (0x3041, 0x3096), (0x3099, 0x309A), (0x309D, 0x309F), (0x30A1, 0x30FA), (0x30FC, 0x30FF), (0x3105, 0x312F),
+    (0x3131, 0x318E), (0x31A0, 0x31BF), (0x31F0, 0x31FF), (0x3400, 0x4DBF), (0x4E00, 0xA48C), (0xA4D0, 0xA4FD),
+    (0xA500, 0xA60C), (0xA610, 0xA62B), (0xA640, 0xA66F), (0xA674, 0xA67D), (0xA67F, 0xA6F1), (0xA717, 0xA71F),
+    (0xA722, 0xA788), (0xA78B, 0xA7CA), (0xA7D0, 0xA7D1), (0xA7D3, 0xA7D3), (0xA7D5, 0xA7D9), (0xA7F2, 0xA827),
+    (0xA82C, 0xA82C), (0xA840, 0xA873), (0xA880, 0xA8C5), (0xA8D0, 0xA8D9), (0xA8E0, 0xA8F7), (0xA8FB, 0xA8FB),
+    (0xA8FD, 0xA92D), (0xA930, 0xA953), (0xA960, 0xA97C), (0xA980, 0xA9C0), (0xA9CF, 0xA9D9), (0xA9E0, 0xA9FE),
+    (0xAA00, 0xAA36), (0xAA40, 0xAA4D), (0xAA50, 0xAA59), (0xAA60, 0xAA76), (0xAA7A, 0xAAC2), (0xAADB, 0xAADD),
+    (0xAAE0, 0xAAEF), (0xAAF2, 0xAAF6), (0xAB01, 0xAB06), (0xAB09, 0xAB0E), (0xAB11, 0xAB16), (0xAB20, 0xAB26),
+    (0xAB28, 0xAB2E), (0xAB30, 0xAB5A), (0xAB5C, 0xAB69), (0xAB70, 0xABEA), (0xABEC, 0xABED), (0xABF0, 0xABF9),
+    (0xAC00, 0xD7A3), (0xD7B0, 0xD7C6), (0xD7CB, 0xD7FB), (0xF900, 0xFA6D), (0xFA70, 0xFAD9), (0xFB00, 0xFB06),
+    (0xFB13, 0xFB17), (0xFB1D, 0xFB28), (0xFB2A, 0xFB36), (0xFB38, 0xFB3C), (0xFB3E, 0xFB3E), (0xFB40, 0xFB41),
+    (0xFB43, 0xFB44), (0xFB46, 0xFBB1), (0xFBD3, 0xFC5D), (0xFC64, 0xFD3D), (0xFD50, 0xFD8F), (0xFD92, 0xFDC7),
+    (0xFDF0, 0xFDF9), (0xFE00, 0xFE0F), (0xFE20, 0xFE2F), (0xFE33, 0xFE34), (0xFE4D, 0xFE4F), (0xFE71, 0xFE71),
+    (0xFE73, 0xFE73), (0xFE77, 0xFE77), (0xFE79, 0xFE79), (0xFE7B, 0xFE7B), (0xFE7D, 0xFE7D), (0xFE7F, 0xFEFC),
+    (0xFF10, 0xFF19), (0xFF21, 0xFF3A), (0xFF3F, 0xFF3F), (0xFF41, 0xFF5A), (0xFF66, 0xFFBE), (0xFFC2, 0xFFC7),
+    (0xFFCA, 0xFFCF), (0xFFD2, 0xFFD7), (0xFFDA, 0xFFDC), (0x10000, 0x1000B), (0x1000D, 0x10026), (0x10028, 0x1003A),
+    (0x1003C, 0x1003D), (0x1003F, 0x1004D), (0x10050, 0x1005D), (0x10080, 0x100FA), (0x10140, 0x10174), (0x101FD, 0x101FD),
+    (0x10280, 0x1029C), (0x102A0, 0x102D0), (0x102E0, 0x102E0), (0x10300, 0x1031F), (0x1032D, 0x1034A), (0x10350, 0x1037A),
+    (0x10380, 0x1039D), (0x103A0, 0x103C3), (0x103C8, 0x103CF), (0x103D1, 0x103D5), (0x10400, 0x1049D), (0x104A0, 0x104A9),
+    (0x104B0, 0x104D3), (0x104D8, 0x104FB), (0x10500, 0x10527), (0x10530, 0x10563), (0x10570, 0x1057A), (0x1057C, 0x1058A),
+    (0x1058C, 0x10592), (0x10594, 0x10595), (0x10597, 0x105A1), (0x105A3, 0x105B1), (0x105B3, 0x105B9), (0x105BB, 0x105BC),
+    (0x10600, 0x10736), (0x10740, 0x10755), (0x10760, 0x10767), (0x10780, 0x10785), (0x10787, 0x107B0), (0x107B2, 0x107BA),
+    (0x10800, 0x10805), (0x10808, 0x10808), (0x1080A, 0x10835), (0x10837, 0x10838), (0x1083C, 0x1083C), (0x1083F, 0x10855),
+    (0x10860, 0x10876), (0x10880, 0x1089E), (0x108E0, 0x108F2), (0x108F4, 0x108F5), (0x10900, 0x10915), (0x10920, 0x10939),
+    (0x10980, 0x109B7), (0x109BE, 0x109BF), (0x10A00, 0x10A03), (0x10A05, 0x10A06), (0x10A0C, 0x10A13), (0x10A15, 0x10A17),
+    (0x10A19, 0x10A35), (0x10A38, 0x10A3A), (0x10A3F, 0x10A3F), (0x10A60, 0x10A7C), (0x10A80, 0x10A9C), (0x10AC0, 0x10AC7),
+    (0x10AC9, 0x10AE6), (0x10B00, 0x10B35), (0x10B40, 0x10B55), (0x10B60, 0x10B72), (0x10B80, 0x10B91), (0x10C00, 0x10C48),
+    (0x10C80, 0x10CB2), (0x10CC0, 0x10CF2), (0x10D00, 0x10D27), (0x10D30, 0x10D39), (0x10E80, 0x10EA9), (0x10EAB, 0x10EAC),
+    (0x10EB0, 0x10EB1), (0x10F00, 0x10F1C), (0x10F27, 0x10F27), (0x10F30, 0x10F50), (0x10F70, 0x10F85), (0x10FB0, 0x10FC4),
+    (0x10FE0, 0x10FF6), (0x11000, 0x11046), (0x11066, 0x11075), (0x1107F, 0x110BA), (0x110C2, 0x110C2), (0x110D0, 0x110E8),
+    (0x110F0, 0x110F9), (0x11100, 0x11134), (0x11136, 0x1113F), (0x11144, 0x11147), (0x11150, 0x11173), (0x11176, 0x11176),
+    (0x11180, 0x111C4), (0x111C9, 0x111CC), (0x111CE, 0x111DA), (0x111DC, 0x111DC), (0x11200, 0x11211), (0x11213, 0x11237),
+    (0x1123E, 0x1123E), (0x11280, 0x11286), (0x11288, 0x11288), (0x1128A, 0x1128D), (0x1128F, 0x1129D), (0x1129F, 0x112A8),
+    (0x112B0, 0x112EA), (0x112F0, 0x112F9), (0x11300, 0x11303), (0x11305, 0x1130C), (0x1130F, 0x11310), (0x11313, 0x11328),
+    (0x1132A, 0x11330), (0x11332, 0x11333), (0x11335, 0x11339), (0x1133B, 0x11344), (0x11347, 0x11348), (0x1134B, 0x1134D),
+    (0x11350, 0x11350), (0x11357, 0x11357), (0x1135D, 0x11363), (0x11366, 0x1136C), (0x11370, 0x11374), (0x11400, 0x1144A),
+    (0x11450, 0x11459), (0x1145E, 0x11461), (0x11480, 0x114C5), (0x114C7, 0x114C7), (0x114D0, 0x114D9), (0x11580, 0x115B5),
+    (0x115B8, 0x115C0), (0x115D8, 0x115DD), (0x11600, 0x11640), (0x11644, 0x11644), (0x11650, 0x11659), (0x11680, 0x116B8),
+    (0x116C0, 0x116C9), (0x11700, 0x1171A), (0x1171D, 0x1172B), (0x11730, 0x11739), (0x11740, 0x11746), (0x11800, 0x1183A),
+    (0x118A0, 0x118E9), (0x118FF, 0x11906), (0x11909, 0x11909), (0x1190C, 0x11913), (0x11915, 0x11916), (0x11918, 0x11935),
+    (0x11937, 0x11938), (0x1193B, 0x11943), (0x11950, 0x11959), (0x119A0, 0x119A7), (0x119AA, 0x119D7), (0x119DA, 0x119E1),
+    (0x119E3, 0x119E4), (0x11A00, 0x11A3E), (0x11A47, 0x11A47), (0x11A50, 0x11A99), (0x11A9D, 0x11A9D), (0x11AB0, 0x11AF8),
+    (0x11C00, 0x11C08), (0x11C0A, 0x11C36), (0x11C38, 0x11C40), (0x11C50, 0x11C59), (0x11C72, 0x11C8F), (0x11C92, 0x11CA7),
+    (0x11CA9, 0x11CB6), (0x11D00, 0x11D06), (0x11D08, 0x11D09), (0x11D0B, 0x11D36), (0x11D3A, 0x11D3A), (0x11D3C, 0x11D3D),
+    (0x11D3F, 0x11D47), (0x11D50, 0x11D59), (0x11D60, 0x11D65), (0x11D67, 0x11D68), (0x11D6A, 0x11D8E), (0x11D90, 0x11D91),
+    (0x11D93, 0x11D98), (0x11DA0, 0x11DA9), (0x11EE0, 0x11EF6), (0x11FB0, 0x11FB0), (0x12000, 0x12399), (0x12400, 0x1246E),
+    (0x12480, 0x12543), (0x12F90, 0x12FF0), (0x13000, 0x1342E), (0x14400, 0x14646), (0x16800, 0x16A38), (0x16A40, 0x16A5E),
+    (0x16A60, 0x16A69), (0x16A70, 0x16ABE), (0x16AC0, 0x16AC9), (0x16AD0, 0x16AED), (0x16AF0, 0x16AF4), (0x16B00, 0x16B36),
+    (0x16B40, 0x16B43), (0x16B50, 0x16B59), (0x16B63, 0x16B77), (0x16B7D, 0x16B8F), (0x16E40, 0x16E7F), (0x16F00, 0x16F4A),
+    (0x16F4F, 0x16F87), (0x16F8F, 0x16F9F), (0x16FE0, 0x16FE1), (0x16FE3, 0x16FE4), (0x16FF0, 0x16FF1), (0x17000, 0x187F7),
+    (0x18800, 0x18CD5), (0x18D00, 0x18D08), (0x1AFF0, 0x1AFF3), (0x1AFF5, 0x1AFFB), (0x1AFFD, 0x1AFFE), (0x1B000, 0x1B122),
+    (0x1B150, 0x1B152), (0x1B164, 0x1B167), (0x1B170, 0x1B2FB), (0x1BC00, 0x1BC6A), (0x1BC70, 0x1BC7C), (0x1BC80, 0x1BC88),
+    (0x1BC90, 0x1BC99), (0x1BC9D, 0x1BC9E), (0x1CF00, 0x1CF2D), (0x1CF30, 0x1CF46), (0x1D165, 0x1D169), (0x1D16D, 0x1D172),
+    (0x1D17B, 0x1D182), (0x1D185, 0x1D18B), (0x1D1AA, 0x1D1AD), (0x1D242, 0x1D244), (0x1D400, 0x1D454), (0x1D456, 0x1D49C),
+    (0x1D49E, 0x1D49F), (0x1D4A2, 0x1D4A2), (0x1D4A5, 0x1D4A6), (0x1D4A9, 0x1D4AC), (0x1D4AE, 0x1D4B9), (0x1D4BB, 0x1D4BB),
+    (0x1D4BD, 0x1D4C3), (0x1D4C5, 0x1D505), (0x1D507, 0x1D50A), (0x1D50D, 0x1D514), (0x1D516, 0x1D51C), (0x1D51E, 0x1D539),
+    (0x1D53B, 0x1D53E), (0x1D540, 0x1D544), (0x1D546, 0x1D546), (0x1D54A, 0x1D550), (0x1D552, 0x1D6A5), (0x1D6A8, 0x1D6C0),
+    (0x1D6C2, 0x1D6DA), (0x1D6DC, 0x1D6FA), (0x1D6FC, 0x1D714), (0x1D716, 0x1D734), (0x1D736, 0x1D74E), (0x1D750, 0x1D76E),
+    (0x1D770, 0x1D788), (0x1D78A, 0x1D7A8), (0x1D7AA, 0x1D7C2), (0x1D7C4, 0x1D7CB), (0x1D7CE, 0x1D7FF), (0x1DA00, 0x1DA36),
+    (0x1DA3B, 0x1DA6C), (0x1DA75, 0x1DA75), (0x1DA84, 0x1DA84), (0x1DA9B, 0x1DA9F), (0x1DAA1, 0x1DAAF), (0x1DF00, 0x1DF1E),
+    (0x1E000, 0x1E006), (0x1E008, 0x1E018), (0x1E01B, 0x1E021), (0x1E023, 0x1E024), (0x1E026, 0x1E02A), (0x1E100, 0x1E12C),
+    (0x1E130, 0x1E13D), (0x1E140, 0x1E149), (0x1E14E, 0x1E14E), (0x1E290, 0x1E2AE), (0x1E2C0, 0x1E2F9), (0x1E7E0, 0x1E7E6),
+    (0x1E7E8, 0x1E7EB), (0x1E7ED, 0x1E7EE), (0x1E7F0, 0x1E7FE), (0x1E800, 0x1E8C4), (0x1E8D0, 0x1E8D6), (0x1E900, 0x1E94B),
+    (0x1E950, 0x1E959), (0x1EE00, 0x1EE03), (0x1EE05, 0x1EE1F), (0x1EE21, 0x1EE22), (0x1EE24, 0x1EE24), (0x1EE27, 0x1EE27),
+    (0x1EE29, 0x1EE32), (0x1EE34, 0x1EE37), (0x1EE39, 0x1EE39), (0x1EE3B, 0x1EE3B), (0x1EE42, 0x1EE42), (0x1EE47, 0x1EE47),
+    (0x1EE49, 0x1EE49), (0x1EE4B, 0x1EE4B), (0x1EE4D, 0x1EE4F), (0x1EE51, 0x1EE52), (0x1EE54, 0x1EE54), (0x1EE57, 0x1EE57),
+    (0x1EE59, 0x1EE59), (0x1EE5B, 0x1EE5B), (0x1EE5D, 0x1EE5D), (0x1EE5F, 0x1EE5F), (0x1EE61, 0x1EE62), (0x1EE64, 0x1EE64),
+    (0x1EE67, 0x1EE6A), (0x1EE6C, 0x1EE72), (0x1EE74, 0x1EE77), (0x1EE79, 0x1EE7C), (0x1EE7E, 0x1EE7E), (0x1EE80, 0x1EE89),
+    (0x1EE8B, 0x1EE9B), (0x1EEA1, 0x1EEA3), (0x1EEA5, 0x1EEA9), (0x1EEAB, 0x1EEBB), (0x1FBF0, 0x1FBF9), (0x20000, 0x2A6DF),
+    (0x2A700, 0x2B738), (0x2B740, 0x2B81D), (0x2B820, 0x2CEA1), (0x2CEB0, 0x2EBE0), (0x2F800, 0x2FA1D), (0x30000, 0x3134A),
+    (0xE0100, 0xE01EF)
+];
+
+// Extended_Pictographic, from emoji-data.txt.
+const EXTENDED_PICTOGRAPHIC: &[(u32, u32)] = &[
+    (0xA9, 0xA9), (0xAE, 0xAE), (0x203C, 0x203C), (0x2049, 0x2049), (0x2122, 0x2122), (0x2139, 0x2139),
+    (0x2194, 0x2199), (0x21A9, 0x21AA), (0x231A, 0x231B), (0x2328, 0x2328), (0x2388, 0x2388), (0x23CF, 0x23CF),
+    (0x23E9, 0x23F3), (0x23F8, 0x23FA), (0x24C2, 0x24C2), (0x25AA, 0x25AB), (0x25B6, 0x25B6), (0x25C0, 0x25C0),
+    (0x25FB, 0x25FE), (0x2600, 0x2605), (0x2607, 0x2612), (0x2614, 0x2685), (0x2690, 0x2705), (0x2708, 0x2712),
+    (0x2714, 0x2714), (0x2716, 0x2716), (0x271D, 0x271D), (0x2721, 0x2721), (0x2728, 0x2728), (0x2733, 0x2734),
+    (0x2744, 0x2744), (0x2747, 0x2747), (0x274C, 0x274C), (0x274E, 0x274E), (0x2753, 0x2755), (0x2757, 0x2757),
+    (0x2763, 0x2767), (0x2795, 0x2797), (0x27A1, 0x27A1), (0x27B0, 0x27B0), (0x27BF, 0x27BF), (0x2934, 0x2935),
+    (0x2B05, 0x2B07), (0x2B1B, 0x2B1C), (0x2B50, 0x2B50), (0x2B55, 0x2B55), (0x3030, 0x3030), (0x303D, 0x303D),
+    (0x3297, 0x3297), (0x3299, 0x3299), (0x1F000, 0x1F0FF), (0x1F10D, 0x1F10F), (0x1F12F, 0x1F12F), (0x1F16C, 0x1F171),
+    (0x1F17E, 0x1F17F), (0x1F18E, 0x1F18E), (0x1F191, 0x1F19A), (0x1F1AD, 0x1F1E5), (0x1F201, 0x1F20F), (0x1F21A, 0x1F21A),
+    (0x1F22F, 0x1F22F), (0x1F232, 0x1F23A), (0x1F23C, 0x1F23F), (0x1F249, 0x1F3FA), (0x1F400, 0x1F53D), (0x1F546, 0x1F64F),
+    (0x1F680, 0x1F6FF), (0x1F774, 0x1F77F), (0x1F7D5, 0x1F7FF), (0x1F80C, 0x1F80F), (0x1F848, 0x1F84F), (0x1F85A, 0x1F85F),
+    (0x1F888, 0x1F88F), (0x1F8AE, 0x1F8FF), (0x1F90C, 0x1F93A), (0x1F93C, 0x1F945), (0x1F947, 0x1FAFF), (0x1FC00, 0x1FFFD)
+];
+
+// Grapheme_Cluster_Break, characters that are not listed are Other.
+const GRAPHEME_BREAK: &[(u32, u32, Break)] = &[
+    (0x0, 0x9, Break::Control), (0xA, 0xA, Break::Lf), (0xB, 0xC, Break::Control), (0xD, 0xD, Break::Cr),
+    (0xE, 0x1F, Break::Control), (0x7F, 0x9F, Break::Control), (0xAD, 0xAD, Break::Control), (0x300, 0x36F, Break::Extend),
+    (0x483, 0x489, Break::Extend), (0x591, 0x5BD, Break::Extend), (0x5BF, 0x5BF, Break::Extend), (0x5C1, 0x5C2, Break::Extend),
+    (0x5C4, 0x5C5, Break::Extend), (0x5C7, 0x5C7, Break::Extend), (0x600, 0x605, Break::Prepend), (0x610, 0x61A, Break::Extend),
+    (0x61C, 0x61C, Break::Control), (0x64B, 0x65F, Break::Extend), (0x670, 0x670, Break::Extend), (0x6D6, 0x6DC, Break::Extend),
+    (0x6DD, 0x6DD, Break::Prepend), (0x6DF, 0x6E4, Break::Extend), (0x6E7, 0x6E8, Break::Extend), (0x6EA, 0x6ED, Break::Extend),
+    (0x70F, 0x70F, Break::Prepend), (0x711, 0x711, Break::Extend), (0x730, 0x74A, Break::Extend), (0x7A6, 0x7B0, Break::Extend),
+    (0x7EB, 0x7F3, Break::Extend), (0x7FD, 0x7FD, Break::Extend), (0x816, 0x819, Break::Extend), (0x81B, 0x823, Break::Extend),
+    (0x825, 0x827, Break::Extend), (0x829, 0x82D, Break::Extend), (0x859, 0x85B, Break::Extend), (0x890, 0x891, Break::Prepend),
+    (0x898, 0x89F, Break::Extend), (0x8CA, 0x8E1, Break::Extend), (0x8E2, 0x8E2, Break::Prepend), (0x8E3, 0x902, Break::Extend),
+    (0x903, 0x903, Break::SpacingMark), (0x93A, 0x93A, Break::Extend), (0x93B, 0x93B, Break::SpacingMark), (0x93C, 0x93C, Break::Extend),
+    (0x93E, 0x940, Break::SpacingMark), (0x941, 0x948, Break::Extend), (0x949, 0x94C, Break::SpacingMark), (0x94D, 0x94D, Break::Extend),
+    (0x94E, 0x94F, Break::SpacingMark), (0x951, 0x957, Break::Extend), (0x962, 0x963, Break::Extend), (0x981, 0x981, Break::Extend),
+    (0x982, 0x983, Break::SpacingMark), (0x9BC, 0x9BC, Break::Extend), (0x9BE, 0x9BE, Break::Extend), (0x9BF, 0x9C0, Break::SpacingMark),
+    (0x9C1, 0x9C4, Break::Extend), (0x9C7, 0x9C8, Break::SpacingMark), (0x9CB, 0x9CC, Break::SpacingMark), (0x9CD, 0x9CD, Break::Extend),
+    (0x9D7, 0x9D7, Break::Extend), (0x9E2, 0x9E3, Break::Extend), (0x9FE, 0x9FE, Break::Extend), (0xA01, 0xA02, Break::Extend),
+    (0xA03, 0xA03, Break::SpacingMark), (0xA3C, 0xA3C, Break::Extend), (0xA3E, 0xA40, Break::SpacingMark), (0xA41, 0xA42, Break::Extend),
+    (0xA47, 0xA48, Break::Extend), (0xA4B, 0xA4D, Break::Extend), (0xA51, 0xA51, Break::Extend), (0xA70, 0xA71, Break::Extend),
+    (0xA75, 0xA75, Break::Extend), (0xA81, 0xA82, Break::Extend), (0xA83, 0xA83, Break::SpacingMark), (0xABC, 0xABC, Break::Extend),
+    (0xABE, 0xAC0, Break::SpacingMark), (0xAC1, 0xAC5, Break::Extend), (0xAC7, 0xAC8, Break::Extend), (0xAC9, 0xAC9, Break::SpacingMark),
+    (0xACB, 0xACC, Break::SpacingMark), (0xACD, 0xACD, Break::Extend), (0xAE2, 0xAE3, Break::Extend), (0xAFA, 0xAFF, Break::Extend),
+    (0xB01, 0xB01, Break::Extend), (0xB02, 0xB03, Break::SpacingMark), (0xB3C, 0xB3C, Break::Extend), (0xB3E, 0xB3F, Break::Extend),
+    (0xB40, 0xB40, Break::SpacingMark), (0xB41, 0xB44, Break::Extend), (0xB47, 0xB48, Break::SpacingMark), (0xB4B, 0xB4C, Break::SpacingMark),
+    (0xB4D, 0xB4D, Break::Extend), (0xB55, 0xB57, Break::Extend), (0xB62, 0xB63, Break::Extend), (0xB82, 0xB82, Break::Extend),
+    (0xBBE, 0xBBE, Break::Extend), (0xBBF, 0xBBF, Break::SpacingMark), (0xBC0, 0xBC0, Break::Extend), (0xBC1, 0xBC2, Break::SpacingMark),
+    (0xBC6, 0xBC8, Break::SpacingMark), (0xBCA, 0xBCC, Break::SpacingMark), (0xBCD, 0xBCD, Break::Extend), (0xBD7, 0xBD7, Break::Extend),
+    (0xC00, 0xC00, Break::Extend), (0xC01, 0xC03, Break::SpacingMark), (0xC04, 0xC04, Break::Extend), (0xC3C, 0xC3C, Break::Extend),
+    (0xC3E, 0xC40, Break::Extend), (0xC41, 0xC44, Break::SpacingMark), (0xC46, 0xC48, Break::Extend), (0xC4A, 0xC4D, Break::Extend),
+    (0xC55, 0xC56, Break::Extend), (0xC62, 0xC63, Break::Extend), (0xC81, 0xC81, Break::Extend), (0xC82, 0xC83, Break::SpacingMark),
+    (0xCBC, 0xCBC, Break::Extend), (0xCBE, 0xCBE, Break::SpacingMark), (0xCBF, 0xCBF, Break::Extend), (0xCC0, 0xCC1, Break::SpacingMark),
+    (0xCC2, 0xCC2, Break::Extend), (0xCC3, 0xCC4, Break::SpacingMark), (0xCC6, 0xCC6, Break::Extend), (0xCC7, 0xCC8, Break::SpacingMark),
+    (0xCCA, 0xCCB, Break::SpacingMark), (0xCCC, 0xCCD, Break::Extend), (0xCD5, 0xCD6, Break::Extend), (0xCE2, 0xCE3, Break::Extend),
+    (0xD00, 0xD01, Break::Extend), (0xD02, 0xD03, Break::SpacingMark), (0xD3B, 0xD3C, Break::Extend), (0xD3E, 0xD3E, Break::Extend),
+    (0xD3F, 0xD40, Break::SpacingMark), (0xD41, 0xD44, Break::Extend), (0xD46, 0xD48, Break::SpacingMark), (0xD4A, 0xD4C, Break::SpacingMark),
+    (0xD4D, 0xD4D, Break::Extend), (0xD4E, 0xD4E, Break::Prepend), (0xD57, 0xD57, Break::Extend), (0xD62, 0xD63, Break::Extend),
+    (0xD81, 0xD81, Break::Extend), (0xD82, 0xD83, Break::SpacingMark), (0xDCA, 0xDCA, Break::Extend), (0xDCF, 0xDCF, Break::Extend),
+    (0xDD0, 0xDD1, Break::SpacingMark), (0xDD2, 0xDD4, Break::Extend), (0xDD6, 0xDD6, Break::Extend), (0xDD8, 0xDDE, Break::SpacingMark),
+    (0xDDF, 0xDDF, Break::Extend), (0xDF2, 0xDF3, Break::SpacingMark), (0xE31, 0xE31, Break::Extend), (0xE33, 0xE33, Break::SpacingMark),
+    (0xE34, 0xE3A, Break::Extend), (0xE47, 0xE4E, Break::Extend), (0xEB1, 0xEB1, Break::Extend), (0xEB3, 0xEB3, Break::SpacingMark),
+    (0xEB4, 0xEBC, Break::Extend), (0xEC8, 0xECD, Break::Extend), (0xF18, 0xF19, Break::Extend), (0xF35, 0xF35, Break::Extend),
+    (0xF37, 0xF37, Break::Extend), (0xF39, 0xF39, Break::Extend), (0xF3E, 0xF3F, Break::SpacingMark), (0xF71, 0xF7E, Break::Extend),
+    (0xF7F, 0xF7F, Break::SpacingMark), (0xF80, 0xF84, Break::Extend), (0xF86, 0xF87, Break::Extend), (0xF8D, 0xF97, Break::Extend),
+    (0xF99, 0xFBC, Break::Extend), (0xFC6, 0xFC6, Break::Extend), (0x102D, 0x1030, Break::Extend), (0x1031, 0x1031, Break::SpacingMark),
+    (0x1032, 0x1037, Break::Extend), (0x1039, 0x103A, Break::Extend), (0x103B, 0x103C, Break::SpacingMark), (0x103D, 0x103E, Break::Extend),
+    (0x1056, 0x1057, Break::SpacingMark), (0x1058, 0x1059, Break::Extend), (0x105E, 0x1060, Break::Extend), (0x1071, 0x1074, Break::Extend),
+    (0x1082, 0x1082, Break::Extend), (0x1084, 0x1084, Break::SpacingMark), (0x1085, 0x1086, Break::Extend), (0x108D, 0x108D, Break::Extend),
+    (0x109D, 0x109D, Break::Extend), (0x1100, 0x115F, Break::L), (0x1160, 0x11A7, Break::V), (0x11A8, 0x11FF, Break::T),
+    (0x135D, 0x135F, Break::Extend), (0x1712, 0x1714, Break::Extend), (0x1715, 0x1715, Break::SpacingMark), (0x1732, 0x1733, Break::Extend),
+    (0x1734, 0x1734, Break::SpacingMark), (0x1752, 0x1753, Break::Extend), (0x1772, 0x1773, Break::Extend), (0x17B4, 0x17B5, Break::Extend),
+    (0x17B6, 0x17B6, Break::SpacingMark), (0x17B7, 0x17BD, Break::Extend), (0x17BE, 0x17C5, Break::SpacingMark), (0x17C6, 0x17C6, Break::Extend),
+    (0x17C7, 0x17C8, Break::SpacingMark), (0x17C9, 0x17D3, Break::Extend), (0x17DD, 0x17DD, Break::Extend), (0x180B, 0x180D, Break::Extend),
+    (0x180E, 0x180E, Break::Control), (0x180F, 0x180F, Break::Extend), (0x1885, 0x1886, Break::Extend), (0x18A9, 0x18A9, Break::Extend),
+    (0x1920, 0x1922, Break::Extend), (0x1923, 0x1926, Break::SpacingMark), (0x1927, 0x1928, Break::Extend), (0x1929, 0x192B, Break::SpacingMark),
+    (0x1930, 0x1931, Break::SpacingMark), (0x1932, 0x1932, Break::Extend), (0x1933, 0x1938, Break::SpacingMark), (0x1939, 0x193B, Break::Extend),
+    (0x1A17, 0x1A18, Break::Extend), (0x1A19, 0x1A1A, Break::SpacingMark), (0x1A1B, 0x1A1B, Break::Extend), (0x1A55, 0x1A55, Break::SpacingMark),
+    (0x1A56, 0x1A56, Break::Extend), (0x1A57, 0x1A57, Break::SpacingMark), (0x1A58, 0x1A5E, Break::Extend), (0x1A60, 0x1A60, Break::Extend),
+    (0x1A62, 0x1A62, Break::Extend), (0x1A65, 0x1A6C, Break::Extend), (0x1A6D, 0x1A72, Break::SpacingMark), (0x1A73, 0x1A7C, Break::Extend),
+    (0x1A7F, 0x1A7F, Break::Extend), (0x1AB0, 0x1ACE, Break::Extend), (0x1B00, 0x1B03, Break::Extend), (0x1B04, 0x1B04, Break::SpacingMark),
+    (0x1B34, 0x1B3A, Break::Extend), (0x1B3B, 0x1B3B, Break::SpacingMark), (0x1B3C, 0x1B3C, Break::Extend), (0x1B3D, 0x1B41, Break::SpacingMark),
+    (0x1B42, 0x1B42, Break::Extend), (0x1B43, 0x1B44, Break::SpacingMark), (0x1B6B, 0x1B73, Break::Extend), (0x1B80, 0x1B81, Break::Extend),
+    (0x1B82, 0x1B82, Break::SpacingMark), (0x1BA1, 0x1BA1, Break::SpacingMark), (0x1BA2, 0x1BA5, Break::Extend), (0x1BA6, 0x1BA7, Break::SpacingMark),
+    (0x1BA8, 0x1BA9, Break::Extend), (0x1BAA, 0x1BAA, Break::SpacingMark), (0x1BAB, 0x1BAD, Break::Extend), (0x1BE6, 0x1BE6, Break::Extend),
+    (0x1BE7, 0x1BE7, Break::SpacingMark), (0x1BE8, 0x1BE9, Break::Extend), (0x1BEA, 0x1BEC, Break::SpacingMark), (0x1BED, 0x1BED, Break::Extend),
+    (0x1BEE, 0x1BEE, Break::SpacingMark), (0x1BEF, 0x1BF1, Break::Extend), (0x1BF2, 0x1BF3, Break::SpacingMark), (0x1C24, 0x1C2B, Break::SpacingMark),
+    (0x1C2C, 0x1C33, Break::Extend), (0x1C34, 0x1C35, Break::SpacingMark), (0x1C36, 0x1C37, Break::Extend), (0x1CD0, 0x1CD2, Break::Extend),
+    (0x1CD4, 0x1CE0, Break::Extend), (0x1CE1, 0x1CE1, Break::SpacingMark), (0x1CE2, 0x1CE8, Break::Extend), (0x1CED, 0x1CED, Break::Extend),
+    (0x1CF4, 0x1CF4, Break::Extend), (0x1CF7, 0x1CF7, Break::SpacingMark), (0x1CF8, 0x1CF9, Break::Extend), (0x1DC0, 0x1DFF, Break::Extend),
+    (0x200B, 0x200B, Break::Control), (0x200C, 0x200C, Break::Extend), (0x200D, 0x200D, Break::Zwj), (0x200E, 0x200F, Break::Control),
+    (0x2028, 0x202E, Break::Control), (0x2060, 0x206F, Break::Control), (0x20D0, 0x20F0, Break::Extend), (0x2CEF, 0x2CF1, Break::Extend),
+    (0x2D7F, 0x2D7F, Break::Extend), (0x2DE0, 0x2DFF, Break::Extend), (0x302A, 0x302F, Break::Extend), (0x3099, 0x309A, Break::Extend),
+    (0xA66F, 0xA672, Break::Extend), (0xA674, 0xA67D, Break::Extend), (0xA69E, 0xA69F, Break::Extend), (0xA6F0, 0xA6F1, Break::Extend),
+    (0xA802, 0xA802, Break::Extend), (0xA806, 0xA806, Break::Extend), (0xA80B, 0xA80B, Break::Extend), (0xA823, 0xA824, Break::SpacingMark),
+    (0xA825, 0xA826, Break::Extend), (0xA827, 0xA827, Break::SpacingMark), (0xA82C, 0xA82C, Break::Extend), (0xA880, 0xA881, Break::SpacingMark),
+    (0xA8B4, 0xA8C3, Break::SpacingMark), (0xA8C4, 0xA8C5, Break::Extend), (0xA8E0, 0xA8F1, Break::Extend), (0xA8FF, 0xA8FF, Break::Extend),
+    (0xA926, 0xA92D, Break::Extend), (0xA947, 0xA951, Break::Extend), (0xA952, 0xA953, Break::SpacingMark), (0xA960, 0xA97C, Break::L),
+    (0xA980, 0xA982, Break::Extend), (0xA983, 0xA983, Break::SpacingMark), (0xA9B3, 0xA9B3, Break::Extend), (0xA9B4, 0xA9B5, Break::SpacingMark),
+    (0xA9B6, 0xA9B9, Break::Extend), (0xA9BA, 0xA9BB, Break::SpacingMark), (0xA9BC, 0xA9BD, Break::Extend), (0xA9BE, 0xA9C0, Break::SpacingMark),
+    (0xA9E5, 0xA9E5, Break::Extend), (0xAA29, 0xAA2E, Break::Extend), (0xAA2F, 0xAA30, Break::SpacingMark), (0xAA31, 0xAA32, Break::Extend),
+    (0xAA33, 0xAA34, Break::SpacingMark), (0xAA35, 0xAA36, Break::Extend), (0xAA43, 0xAA43, Break::Extend), (0xAA4C, 0xAA4C, Break::Extend),
+    (0xAA4D, 0xAA4D, Break::SpacingMark), (0xAA7C, 0xAA7C, Break::Extend), (0xAAB0, 0xAAB0, Break::Extend), (0xAAB2, 0xAAB4, Break::Extend),
+    (0xAAB7, 0xAAB8, Break::Extend), (0xAABE, 0xAABF, Break::Extend), (0xAAC1, 0xAAC1, Break::Extend), (0xAAEB, 0xAAEB, Break::SpacingMark),
+    (0xAAEC, 0xAAED, Break::Extend), (0xAAEE, 0xAAEF, Break::SpacingMark), (0xAAF5, 0xAAF5, Break::SpacingMark), (0xAAF6, 0xAAF6, Break::Extend),
+    (0xABE3, 0xABE4, Break::SpacingMark), (0xABE5, 0xABE5, Break::Extend), (0xABE6, 0xABE7, Break::SpacingMark), (0xABE8, 0xABE8, Break::Extend),
+    (0xABE9, 0xABEA, Break::SpacingMark), (0xABEC, 0xABEC, Break::SpacingMark), (0xABED, 0xABED, Break::Extend), (0xAC00, 0xAC00, Break::Lv),
+    (0xAC01, 0xAC1B, Break::Lvt), (0xAC1C, 0xAC1C, Break::Lv), (0xAC1D, 0xAC37, Break::Lvt), (0xAC38, 0xAC38, Break::Lv),
+    (0xAC39, 0xAC53, Break::Lvt), (0xAC54, 0xAC54, Break::Lv), (0xAC55, 0xAC6F, Break::Lvt), (0xAC70, 0xAC70, Break::Lv),
+    (0xAC71, 0xAC8B, Break::Lvt), (0xAC8C, 0xAC8C, Break::Lv), (0xAC8D, 0xACA7, Break::Lvt), (0xACA8, 0xACA8, Break::Lv),
+    (0xACA9, 0xACC3, Break::Lvt), (0xACC4, 0xACC4, Break::Lv), (0xACC5, 0xACDF, Break::Lvt), (0xACE0, 0xACE0, Break::Lv),
+    (0xACE1, 0xACFB, Break::Lvt), (0xACFC, 0xACFC, Break::Lv), (0xACFD, 0xAD17, Break::Lvt), (0xAD18, 0xAD18, Break::Lv),
+    (0xAD19, 0xAD33, Break::Lvt), (0xAD34, 0xAD34, Break::Lv), (0xAD35, 0xAD4F, Break::Lvt), (0xAD50, 0xAD50, Break::Lv),
+    (0xAD51, 0xAD6B, Break::Lvt), (0xAD6C, 0xAD6C, Break::Lv), (0xAD6D, 0xAD87, Break::Lvt), (0xAD88, 0xAD88, Break::Lv),
+    (0xAD89, 0xADA3, Break::Lvt), (0xADA4, 0xADA4, Break::Lv), (0xADA5, 0xADBF, Break::Lvt), (0xADC0, 0xADC0, Break::Lv),
+    (0xADC1, 0xADDB, Break::Lvt), (0xADDC, 0xADDC, Break::Lv), (0xADDD, 0xADF7, Break::Lvt), (0xADF8, 0xADF8, Break::Lv),
+    (0xADF9, 0xAE13, Break::Lvt), (0xAE14, 0xAE14, Break::Lv), (0xAE15, 0xAE2F, Break::Lvt), (0xAE30, 0xAE30, Break::Lv),
+    (0xAE31, 0xAE4B, Break::Lvt), (0xAE4C, 0xAE4C, Break::Lv), (0xAE4D, 0xAE67, Break::Lvt), (0xAE68, 0xAE68, Break::Lv),
+    (0xAE69, 0xAE83, Break::Lvt), (0xAE84, 0xAE84, Break::Lv), (0xAE85, 0xAE9F, Break::Lvt), (0xAEA0, 0xAEA0, Break::Lv),
+    (0xAEA1, 0xAEBB, Break::Lvt), (0xAEBC, 0xAEBC, Break::Lv), (0xAEBD, 0xAED7, Break::Lvt), (0xAED8, 0xAED8, Break::Lv),
+    (0xAED9, 0xAEF3, Break::Lvt), (0xAEF4, 0xAEF4, Break::Lv), (0xAEF5, 0xAF0F, Break::Lvt), (0xAF10, 0xAF10, Break::Lv),
+    (0xAF11, 0xAF2B, Break::Lvt), (0xAF2C, 0xAF2C, Break::Lv), (0xAF2D, 0xAF47, Break::Lvt), (0xAF48, 0xAF48, Break::Lv),
+    (0xAF49, 0xAF63, Break::Lvt), (0xAF64, 0xAF64, Break::Lv), (0xAF65, 0xAF7F, Break::Lvt), (0xAF80, 0xAF80, Break::Lv),
+    (0xAF81, 0xAF9B, Break::Lvt), (0xAF9C, 0xAF9C, Break::Lv), (0xAF9D, 0xAFB7, Break::Lvt), (0xAFB8, 0xAFB8, Break::Lv),
+    (0xAFB9, 0xAFD3, Break::Lvt), (0xAFD4, 0xAFD4, Break::Lv), (0xAFD5, 0xAFEF, Break::Lvt), (0xAFF0, 0xAFF0, Break::Lv),
+    (0xAFF1, 0xB00B, Break::Lvt), (0xB00C, 0xB00C, Break::Lv), (0xB00D, 0xB027, Break::Lvt), (0xB028, 0xB028, Break::Lv),
+    (0xB029, 0xB043, Break::Lvt), (0xB044, 0xB044, Break::Lv), (0xB045, 0xB05F, Break::Lvt), (0xB060, 0xB060, Break::Lv),
+    (0xB061, 0xB07B, Break::Lvt), (0xB07C, 0xB07C, Break::Lv), (0xB07D, 0xB097, Break::Lvt), (0xB098, 0xB098, Break::Lv),
+    (0xB099, 0xB0B3, Break::Lvt), (0xB0B4, 0xB0B4, Break::Lv), (0xB0B5, 0xB0CF, Break::Lvt), (0xB0D0, 0xB0D0, Break::Lv),
+    (0xB0D1, 0xB0EB, Break::Lvt), (0xB0EC, 0xB0EC, Break::Lv), (0xB0ED, 0xB107, Break::Lvt), (0xB108, 0xB108, Break::Lv),
+    (0xB109, 0xB123, Break::Lvt), (0xB124, 0xB124, Break::Lv), (0xB125, 0xB13F, Break::Lvt), (0xB140, 0xB140, Break::Lv),
+    (0xB141, 0xB15B, Break::Lvt), (0xB15C, 0xB15C, Break::Lv), (0xB15D, 0xB177, Break::Lvt), (0xB178, 0xB178, Break::Lv),
+    (0xB179, 0xB193, Break::Lvt), (0xB194, 0xB194, Break::Lv), (0xB195, 0xB1AF, Break::Lvt), (0xB1B0, 0xB1B0, Break::Lv),
+    (0xB1B1, 0xB1CB, Break::Lvt), (0xB1CC, 0xB1CC, Break::Lv), (0xB1CD, 0xB1E7, Break::Lvt), (0xB1E8, 0xB1E8, Break::Lv),
+    (0xB1E9, 0xB203, Break::Lvt), (0xB204, 0xB204, Break::Lv), (0xB205, 0xB21F, Break::Lvt), (0xB220, 0xB220, Break::Lv),
+    (0xB221, 0xB23B, Break::Lvt), (0xB23C, 0xB23C, Break::Lv), (0xB23D, 0xB257, Break::Lvt), (0xB258, 0xB258, Break::Lv),
+    (0xB259, 0xB273, Break::Lvt), (0xB274, 0xB274, Break::Lv), (0xB275, 0xB28F, Break::Lvt), (0xB290, 0xB290, Break::Lv),
+    (0xB291, 0xB2AB, Break::Lvt), (0xB2AC, 0xB2AC, Break::Lv), (0xB2AD, 0xB2C7, Break::Lvt), (0xB2C8, 0xB2C8, Break::Lv),
+    (0xB2C9, 0xB2E3, Break::Lvt), (0xB2E4, 0xB2E4, Break::Lv), (0xB2E5, 0xB2FF, Break::Lvt), (0xB300, 0xB300, Break::Lv),
+    (0xB301, 0xB31B, Break::Lvt), (0xB31C, 0xB31C, Break::Lv), (0xB31D, 0xB337, Break::Lvt), (0xB338, 0xB338, Break::Lv),
+    (0xB339, 0xB353, Break::Lvt), (0xB354, 0xB354, Break::Lv), (0xB355, 0xB36F, Break::Lvt), (0xB370, 0xB370, Break::Lv),
+    (0xB371, 0xB38B, Break::Lvt), (0xB38C, 0xB38C, Break::Lv), (0xB38D, 0xB3A7, Break::Lvt), (0xB3A8, 0xB3A8, Break::Lv),
+    (0xB3A9, 0xB3C3, Break::Lvt), (0xB3C4, 0xB3C4, Break::Lv), (0xB3C5, 0xB3DF, Break::Lvt), (0xB3E0, 0xB3E0, Break::Lv),
+    (0xB3E1, 0xB3FB, Break::Lvt), (0xB3FC, 0xB3FC, Break::Lv), (0xB3FD, 0xB417, Break::Lvt), (0xB418, 0xB418, Break::Lv),
+    (0xB419, 0xB433, Break::Lvt), (0xB434, 0xB434, Break::Lv), (0xB435, 0xB44F, Break::Lvt), (0xB450, 0xB450, Break::Lv),
+    (0xB451, 0xB46B, Break::Lvt), (0xB46C, 0xB46C, Break::Lv), (0xB46D, 0xB487, Break::Lvt), (0xB488, 0xB488, Break::Lv),
+    (0xB489, 0xB4A3, Break::Lvt), (0xB4A4, 0xB4A4, Break::Lv), (0xB4A5, 0xB4BF, Break::Lvt), (0xB4C0, 0xB4C0, Break::Lv),
+    (0xB4C1, 0xB4DB, Break::Lvt), (0xB4DC, 0xB4DC, Break::Lv), (0xB4DD, 0xB4F7, Break::Lvt), (0xB4F8, 0xB4F8, Break::Lv),
+    (0xB4F9, 0xB513, Break::Lvt), (0xB514, 0xB514, Break::Lv), (0xB515, 0xB52F, Break::Lvt), (0xB530, 0xB530, Break::Lv),
+    (0xB531, 0xB54B, Break::Lvt), (0xB54C, 0xB54C, Break::Lv), (0xB54D, 0xB567, Break::Lvt), (0xB568, 0xB568, Break::Lv),
+    (0xB569, 0xB583, Break::Lvt), (0xB584, 0xB584, Break::Lv), (0xB585, 0xB59F, Break::Lvt), (0xB5A0, 0xB5A0, Break::Lv),
+    (0xB5A1, 0xB5BB, Break::Lvt), (0xB5BC, 0xB5BC, Break::Lv), (0xB5BD, 0xB5D7, Break::Lvt), (0xB5D8, 0xB5D8, Break::Lv),
+    (0xB5D9, 0xB5F3, Break::Lvt), (0xB5F4, 0xB5F4, Break::Lv), (0xB5F5, 0xB60F, Break::Lvt), (0xB610, 0xB610, Break::Lv),
+    (0xB611, 0xB62B, Break::Lvt), (0xB62C, 0xB62C, Break::Lv), (0xB62D, 0xB647, Break::Lvt), (0xB648, 0xB648, Break::Lv),
+    (0xB649, 0xB663, Break::Lvt), (0xB664, 0xB664, Break::Lv), (0xB665, 0xB67F, Break::Lvt), (0xB680, 0xB680, Break::Lv),
+    (0xB681, 0xB69B, Break::Lvt), (0xB69C, 0xB69C, Break::Lv), (0xB69D, 0xB6B7, Break::Lvt), (0xB6B8, 0xB6B8, Break::Lv),
+    (0xB6B9, 0xB6D3, Break::Lvt), (0xB6D4, 0xB6D4, Break::Lv), (0xB6D5, 0xB6EF, Break::Lvt), (0xB6F0, 0xB6F0, Break::Lv),
+    (0xB6F1, 0xB70B, Break::Lvt), (0xB70C, 0xB70C, Break::Lv), (0xB70D, 0xB727, Break::Lvt), (0xB728, 0xB728, Break::Lv),
+    (0xB729, 0xB743, Break::Lvt), (0xB744, 0xB744, Break::Lv), (0xB745, 0xB75F, Break::Lvt), (0xB760, 0xB760, Break::Lv),
+    (0xB761, 0xB77B, Break::Lvt), (0xB77C, 0xB77C, Break::Lv), (0xB77D, 0xB797, Break::Lvt), (0xB798, 0xB798, Break::Lv),
+    (0xB799, 0xB7B3, Break::Lvt), (0xB7B4, 0xB7B4, Break::Lv), (0xB7B5, 0xB7CF, Break::Lvt), (0xB7D0, 0xB7D0, Break::Lv),
+    (0xB7D1, 0xB7EB, Break::Lvt), (0xB7EC, 0xB7EC, Break::Lv), (0xB7ED, 0xB807, Break::Lvt), (0xB808, 0xB808, Break::Lv),
+    (0xB809, 0xB823, Break::Lvt), (0xB824, 0xB824, Break::Lv), (0xB825, 0xB83F, Break::Lvt), (0xB840, 0xB840, Break::Lv),
+    (0xB841, 0xB85B, Break::Lvt), (0xB85C, 0xB85C, Break::Lv), (0xB85D, 0xB877, Break::Lvt), (0xB878, 0xB878, Break::Lv),
+    (0xB879, 0xB893, Break::Lvt), (0xB894, 0xB894, Break::Lv), (0xB895, 0xB8AF, Break::Lvt), (0xB8B0, 0xB8B0, Break::Lv),
+    (0xB8B1, 0xB8CB, Break::Lvt), (0xB8CC, 0xB8CC, Break::Lv), (0xB8CD, 0xB8E7, Break::Lvt), (0xB8E8, 0xB8E8, Break::Lv),
+    (0xB8E9, 0xB903, Break::Lvt), (0xB904, 0xB904, Break::Lv), (0xB905, 0xB91F, Break::Lvt), (0xB920, 0xB920, Break::Lv),
+    (0xB921, 0xB93B, Break::Lvt), (0xB93C, 0xB93C, Break::Lv), (0xB93D, 0xB957, Break::Lvt), (0xB958, 0xB958, Break::Lv),
+    (0xB959, 0xB973, Break::Lvt), (0xB974, 0xB974, Break::Lv), (0xB975, 0xB98F, Break::Lvt), (0xB990, 0xB990, Break::Lv),
+    (0xB991, 0xB9AB, Break::Lvt), (0xB9AC, 0xB9AC, Break::Lv), (0xB9AD, 0xB9C7, Break::Lvt), (0xB9C8, 0xB9C8, Break::Lv),
+    (0xB9C9, 0xB9E3, Break::Lvt), (0xB9E4, 0xB9E4, Break::Lv), (0xB9E5, 0xB9FF, Break::Lvt), (0xBA00, 0xBA00, Break::Lv),
+    (0xBA01, 0xBA1B, Break::Lvt), (0xBA1C, 0xBA1C, Break::Lv), (0xBA1D, 0xBA37, Break::Lvt), (0xBA38, 0xBA38, Break::Lv),
+    (0xBA39, 0xBA53, Break::Lvt), (0xBA54, 0xBA54, Break::Lv), (0xBA55, 0xBA6F, Break::Lvt), (0xBA70, 0xBA70, Break::Lv),
+    (0xBA71, 0xBA8B, Break::Lvt), (0xBA8C, 0xBA8C, Break::Lv), (0xBA8D, 0xBAA7, Break::Lvt), (0xBAA8, 0xBAA8, Break::Lv),
+    (0xBAA9, 0xBAC3, Break::Lvt), (0xBAC4, 0xBAC4, Break::Lv), (0xBAC5, 0xBADF, Break::Lvt), (0xBAE0, 0xBAE0, Break::Lv),
+    (0xBAE1, 0xBAFB, Break::Lvt), (0xBAFC, 0xBAFC, Break::Lv), (0xBAFD, 0xBB17, Break::Lvt), (0xBB18, 0xBB18, Break::Lv),
+    (0xBB19, 0xBB33, Break::Lvt), (0xBB34, 0xBB34, Break::Lv), (0xBB35, 0xBB4F, Break::Lvt), (0xBB50, 0xBB50, Break::Lv),
+    (0xBB51, 0xBB6B, Break::Lvt), (0xBB6C, 0xBB6C, Break::Lv), (0xBB6D, 0xBB87, Break::Lvt), (0xBB88, 0xBB88, Break::Lv),
+    (0xBB89, 0xBBA3, Break::Lvt), (0xBBA4, 0xBBA4, Break::Lv), (0xBBA5, 0xBBBF, Break::Lvt), (0xBBC0, 0xBBC0, Break::Lv),
+    (0xBBC1, 0xBBDB, Break::Lvt), (0xBBDC, 0xBBDC, Break::Lv), (0xBBDD, 0xBBF7, Break::Lvt), (0xBBF8, 0xBBF8, Break::Lv),
+    (0xBBF9, 0xBC13, Break::Lvt), (0xBC14, 0xBC14, Break::Lv), (0xBC15, 0xBC2F, Break::Lvt), (0xBC30, 0xBC30, Break::Lv),
+    (0xBC31, 0xBC4B, Break::Lvt), (0xBC4C, 0xBC4C, Break::Lv), (0xBC4D, 0xBC67, Break::Lvt), (0xBC68, 0xBC68, Break::Lv),
+    (0xBC69, 0xBC83, Break::Lvt), (0xBC84, 0xBC84, Break::Lv), (0xBC85, 0xBC9F, Break::Lvt), (0xBCA0, 0xBCA0, Break::Lv),
+    (0xBCA1, 0xBCBB, Break::Lvt), (0xBCBC, 0xBCBC, Break::Lv), (0xBCBD, 0xBCD7, Break::Lvt), (0xBCD8, 0xBCD8, Break::Lv),
+    (0xBCD9, 0xBCF3, Break::Lvt), (0xBCF4, 0xBCF4, Break::Lv), (0xBCF5, 0xBD0F, Break::Lvt), (0xBD10, 0xBD10, Break::Lv),
+    (0xBD11, 0xBD2B, Break::Lvt), (0xBD2C, 0xBD2C, Break::Lv), (0xBD2D, 0xBD47, Break::Lvt), (0xBD48, 0xBD48, Break::Lv),
+    (0xBD49, 0xBD63, Break::Lvt), (0xBD64, 0xBD64, Break::Lv), (0xBD65, 0xBD7F, Break::Lvt), (0xBD80, 0xBD80, Break::Lv),
+    (0xBD81, 0xBD9B, Break::Lvt), (0xBD9C, 0xBD9C, Break::Lv), (0xBD9D, 0xBDB7, Break::Lvt), (0xBDB8, 0xBDB8, Break::Lv),
+    (0xBDB9, 0xBDD3, Break::Lvt), (0xBDD4, 0xBDD4, Break::Lv), (0xBDD5, 0xBDEF, Break::Lvt), (0xBDF0, 0xBDF0, Break::Lv),
+    (0xBDF1, 0xBE0B, Break::Lvt), (0xBE0C, 0xBE0C, Break::Lv), (0xBE0D, 0xBE27, Break::Lvt), (0xBE28, 0xBE28, Break::Lv),
+    (0xBE29, 0xBE43, Break::Lvt), (0xBE44, 0xBE44, Break::Lv), (0xBE45, 0xBE5F, Break::Lvt), (0xBE60, 0xBE60, Break::Lv),
+    (0xBE61, 0xBE7B, Break::Lvt), (0xBE7C, 0xBE7C, Break::Lv), (0xBE7D, 0xBE97, Break::Lvt), (0xBE98, 0xBE98, Break::Lv),
+    (0xBE99, 0xBEB3, Break::Lvt), (0xBEB4, 0xBEB4, Break::Lv), (0xBEB5, 0xBECF, Break::Lvt), (0xBED0, 0xBED0, Break::Lv),
+    (0xBED1, 0xBEEB, Break::Lvt), (0xBEEC, 0xBEEC, Break::Lv), (0xBEED, 0xBF07, Break::Lvt), (0xBF08, 0xBF08, Break::Lv),
+    (0xBF09, 0xBF23, Break::Lvt), (0xBF24, 0xBF24, Break::Lv), (0xBF25, 0xBF3F, Break::Lvt), (0xBF40, 0xBF40, Break::Lv),
+    (0xBF41, 0xBF5B, Break::Lvt), (0xBF5C, 0xBF5C, Break::Lv), (0xBF5D, 0xBF77, Break::Lvt), (0xBF78, 0xBF78, Break::Lv),
+    (0xBF79, 0xBF93, Break::Lvt), (0xBF94, 0xBF94, Break::Lv), (0xBF95, 0xBFAF, Break::Lvt), (0xBFB0, 0xBFB0, Break::Lv),
+    (0xBFB1, 0xBFCB, Break::Lvt), (0xBFCC, 0xBFCC, Break::Lv), (0xBFCD, 0xBFE7, Break::Lvt), (0xBFE8, 0xBFE8, Break::Lv),
+    (0xBFE9, 0xC003, Break::Lvt), (0xC004, 0xC004, Break::Lv), (0xC005, 0xC01F, Break::Lvt), (0xC020, 0xC020, Break::Lv),
+    (0xC021, 0xC03B, Break::Lvt), (0xC03C, 0xC03C, Break::Lv), (0xC03D, 0xC057, Break::Lvt), (0xC058, 0xC058, Break::Lv),
+    (0xC059, 0xC073, Break::Lvt), (0xC074, 0xC074, Break::Lv), (0xC075, 0xC08F, Break::Lvt), (0xC090, 0xC090, Break::Lv),
+    (0xC091, 0xC0AB, Break::Lvt), (0xC0AC, 0xC0AC, Break::Lv), (0xC0AD, 0xC0C7, Break::Lvt), (0xC0C8, 0xC0C8, Break::Lv),
+    (0xC0C9, 0xC0E3, Break::Lvt), (0xC0E4, 0xC0E4, Break::Lv), (0xC0E5, 0xC0FF, Break::Lvt), (0xC100, 0xC100, Break::Lv),
+    (0xC101, 0xC11B, Break::Lvt), (0xC11C, 0xC11C, Break::Lv), (0xC11D, 0xC137, Break::Lvt), (0xC138, 0xC138, Break::Lv),
+    (0xC139, 0xC153, Break::Lvt), (0xC154, 0xC154, Break::Lv), (0xC155, 0xC16F, Break::Lvt), (0xC170, 0xC170, Break::Lv),
+    (0xC171, 0xC18B, Break::Lvt), (0xC18C, 0xC18C, Break::Lv), (0xC18D, 0xC1A7, Break::Lvt), (0xC1A8, 0xC1A8, Break::Lv),
+    (0xC1A9, 0xC1C3, Break::Lvt), (0xC1C4, 0xC1C4, Break::Lv), (0xC1C5, 0xC1DF, Break::Lvt), (0xC1E0, 0xC1E0, Break::Lv),
+    (0xC1E1, 0xC1FB, Break::Lvt), (0xC1FC, 0xC1FC, Break::Lv), (0xC1FD, 0xC217, Break::Lvt), (0xC218, 0xC218, Break::Lv),
+    (0xC219, 0xC233, Break::Lvt), (0xC234, 0xC234, Break::Lv), (0xC235, 0xC24F, Break::Lvt), (0xC250, 0xC250, Break::Lv),
+    (0xC251, 0xC26B, Break::Lvt), (0xC26C, 0xC26C, Break::Lv), (0xC26D, 0xC287, Break::Lvt), (0xC288, 0xC288, Break::Lv),
+    (0xC289, 0xC2A3, Break::Lvt), (0xC2A4, 0xC2A4, Break::Lv), (0xC2A5, 0xC2BF, Break::Lvt), (0xC2C0, 0xC2C0, Break::Lv),
+    (0xC2C1, 0xC2DB, Break::Lvt), (0xC2DC, 0xC2DC, Break::Lv), (0xC2DD, 0xC2F7, Break::Lvt), (0xC2F8, 0xC2F8, Break::Lv),
+    (0xC2F9, 0xC313, Break::Lvt), (0xC314, 0xC314, Break::Lv), (0xC315, 0xC32F, Break::Lvt), (0xC330, 0xC330, Break::Lv),
+    (0xC331, 0xC34B, Break::Lvt), (0xC34C, 0xC34C, Break::Lv), (0xC34D, 0xC367, Break::Lvt), (0xC368, 0xC368, Break::Lv),
+    (0xC369, 0xC383, Break::Lvt), (0xC384, 0xC384, Break::Lv), (0xC385, 0xC39F, Break::Lvt), (0xC3A0, 0xC3A0, Break::Lv),
+    (0xC3A1, 0xC3BB, Break::Lvt), (0xC3BC, 0xC3BC, Break::Lv), (0xC3BD, 0xC3D7, Break::Lvt), (0xC3D8, 0xC3D8, Break::Lv),
+    (0xC3D9, 0xC3F3, Break::Lvt), (0xC3F4, 0xC3F4, Break::Lv), (0xC3F5, 0xC40F, Break::Lvt), (0xC410, 0xC410, Break::Lv),
+    (0xC411, 0xC42B, Break::Lvt), (0xC42C, 0xC42C, Break::Lv), (0xC42D, 0xC447, Break::Lvt), (0xC448, 0xC448, Break::Lv),
+    (0xC449, 0xC463, Break::Lvt), (0xC464, 0xC464, Break::Lv), (0xC465, 0xC47F, Break::Lvt), (0xC480, 0xC480, Break::Lv),
+    (0xC481, 0xC49B, Break::Lvt), (0xC49C, 0xC49C, Break::Lv), (0xC49D, 0xC4B7, Break::Lvt), (0xC4B8, 0xC4B8, Break::Lv),
+    (0xC4B9, 0xC4D3, Break::Lvt), (0xC4D4, 0xC4D4, Break::Lv), (0xC4D5, 0xC4EF, Break::Lvt), (0xC4F0, 0xC4F0, Break::Lv),
+    (0xC4F1, 0xC50B, Break::Lvt), (0xC50C, 0xC50C, Break::Lv), (0xC50D, 0xC527, Break::Lvt), (0xC528, 0xC528, Break::Lv),
+    (0xC529, 0xC543, Break::Lvt), (0xC544, 0xC544, Break::Lv), (0xC545, 0xC55F, Break::Lvt), (0xC560, 0xC560, Break::Lv),
+    (0xC561, 0xC57B, Break::Lvt), (0xC57C, 0xC57C, Break::Lv), (0xC57D, 0xC597, Break::Lvt), (0xC598, 0xC598, Break::Lv),
+    (0xC599, 0xC5B3, Break::Lvt), (0xC5B4, 0xC5B4, Break::Lv), (0xC5B5, 0xC5CF, Break::Lvt), (0xC5D0, 0xC5D0, Break::Lv),
+    (0xC5D1, 0xC5EB, Break::Lvt), (0xC5EC, 0xC5EC, Break::Lv), (0xC5ED, 0xC607, Break::Lvt), (0xC608, 0xC608, Break::Lv),
+    (0xC609, 0xC623, Break::Lvt), (0xC624, 0xC624, Break::Lv), (0xC625, 0xC63F, Break::Lvt), (0xC640, 0xC640, Break::Lv),
+    (0xC641, 0xC65B, Break::Lvt), (0xC65C, 0xC65C, Break::Lv), (0xC65D, 0xC677, Break::Lvt), (0xC678, 0xC678, Break::Lv),
+    (0xC679, 0xC693, Break::Lvt), (0xC694, 0xC694, Break::Lv), (0xC695, 0xC6AF, Break::Lvt), (0xC6B0, 0xC6B0, Break::Lv),
+    (0xC6B1, 0xC6CB, Break::Lvt), (0xC6CC, 0xC6CC, Break::Lv), (0xC6CD, 0xC6E7, Break::Lvt), (0xC6E8, 0xC6E8, Break::Lv),
+    (0xC6E9, 0xC703, Break::Lvt), (0xC704, 0xC704, Break::Lv), (0xC705, 0xC71F, Break::Lvt), (0xC720, 0xC720, Break::Lv),
+    (0xC721, 0xC73B, Break::Lvt), (0xC73C, 0xC73C, Break::Lv), (0xC73D, 0xC757, Break::Lvt), (0xC758, 0xC758, Break::Lv),
+    (0xC759, 0xC773, Break::Lvt), (0xC774, 0xC774, Break::Lv), (0xC775, 0xC78F, Break::Lvt), (0xC790, 0xC790, Break::Lv),
+    (0xC791, 0xC7AB, Break::Lvt), (0xC7AC, 0xC7AC, Break::Lv), (0xC7AD, 0xC7C7, Break::Lvt), (0xC7C8, 0xC7C8, Break::Lv),
+    (0xC7C9, 0xC7E3, Break::Lvt), (0xC7E4, 0xC7E4, Break::Lv), (0xC7E5, 0xC7FF, Break::Lvt), (0xC800, 0xC800, Break::Lv),
+    (0xC801, 0xC81B, Break::Lvt), (0xC81C, 0xC81C, Break::Lv), (0xC81D, 0xC837, Break::Lvt), (0xC838, 0xC838, Break::Lv),
+    (0xC839, 0xC853, Break::Lvt), (0xC854, 0xC854, Break::Lv), (0xC855, 0xC86F, Break::Lvt), (0xC870, 0xC870, Break::Lv),
+    (0xC871, 0xC88B, Break::Lvt), (0xC88C, 0xC88C, Break::Lv), (0xC88D, 0xC8A7, Break::Lvt), (0xC8A8, 0xC8A8, Break::Lv),
+    (0xC8A9, 0xC8C3, Break::Lvt), (0xC8C4, 0xC8C4, Break::Lv), (0xC8C5, 0xC8DF, Break::Lvt), (0xC8E0, 0xC8E0, Break::Lv),
+    (0xC8E1, 0xC8FB, Break::Lvt), (0xC8FC, 0xC8FC, Break::Lv), (0xC8FD, 0xC917, Break::Lvt), (0xC918, 0xC918, Break::Lv),
+    (0xC919, 0xC933, Break::Lvt), (0xC934, 0xC934, Break::Lv), (0xC935, 0xC94F, Break::Lvt), (0xC950, 0xC950, Break::Lv),
+    (0xC951, 0xC96B, Break::Lvt), (0xC96C, 0xC96C, Break::Lv), (0xC96D, 0xC987, Break::Lvt), (0xC988, 0xC988, Break::Lv),
+    (0xC989, 0xC9A3, Break::Lvt), (0xC9A4, 0xC9A4, Break::Lv), (0xC9A5, 0xC9BF, Break::Lvt), (0xC9C0, 0xC9C0, Break::Lv),
+    (0xC9C1, 0xC9DB, Break::Lvt), (0xC9DC, 0xC9DC, Break::Lv), (0xC9DD, 0xC9F7, Break::Lvt), (0xC9F8, 0xC9F8, Break::Lv),
+    (0xC9F9, 0xCA13, Break::Lvt), (0xCA14, 0xCA14, Break::Lv), (0xCA15, 0xCA2F, Break::Lvt), (0xCA30, 0xCA30, Break::Lv),
+    (0xCA31, 0xCA4B, Break::Lvt), (0xCA4C, 0xCA4C, Break::Lv), (0xCA4D, 0xCA67, Break::Lvt), (0xCA68, 0xCA68, Break::Lv),
+    (0xCA69, 0xCA83, Break::Lvt), (0xCA84, 0xCA84, Break::Lv), (0xCA85, 0xCA9F, Break::Lvt), (0xCAA0, 0xCAA0, Break::Lv),
+    (0xCAA1, 0xCABB, Break::Lvt), (0xCABC, 0xCABC, Break::Lv), (0xCABD, 0xCAD7, Break::Lvt), (0xCAD8, 0xCAD8, Break::Lv),
+    (0xCAD9, 0xCAF3, Break::Lvt), (0xCAF4, 0xCAF4, Break::Lv), (0xCAF5, 0xCB0F, Break::Lvt), (0xCB10, 0xCB10, Break::Lv),
+    (0xCB11, 0xCB2B, Break::Lvt), (0xCB2C, 0xCB2C, Break::Lv), (0xCB2D, 0xCB47, Break::Lvt), (0xCB48, 0xCB48, Break::Lv),
+    (0xCB49, 0xCB63, Break::Lvt), (0xCB64, 0xCB64, Break::Lv), (0xCB65, 0xCB7F, Break::Lvt), (0xCB80, 0xCB80, Break::Lv),
+    (0xCB81, 0xCB9B, Break::Lvt), (0xCB9C, 0xCB9C, Break::Lv), (0xCB9D, 0xCBB7, Break::Lvt), (0xCBB8, 0xCBB8, Break::Lv),
+    (0xCBB9, 0xCBD3, Break::Lvt), (0xCBD4, 0xCBD4, Break::Lv), (0xCBD5, 0xCBEF, Break::Lvt), (0xCBF0, 0xCBF0, Break::Lv),
+    (0xCBF1, 0xCC0B, Break::Lvt), (0xCC0C, 0xCC0C, Break::Lv), (0xCC0D, 0xCC27, Break::Lvt), (0xCC28, 0xCC28, Break::Lv),
+    (0xCC29, 0xCC43, Break::Lvt), (0xCC44, 0xCC44, Break::Lv), (0xCC45, 0xCC5F, Break::Lvt), (0xCC60, 0xCC60, Break::Lv),
+    (0xCC61, 0xCC7B, Break::Lvt), (0xCC7C, 0xCC7C, Break::Lv), (0xCC7D, 0xCC97, Break::Lvt), (0xCC98, 0xCC98, Break::Lv),
+    (0xCC99, 0xCCB3, Break::Lvt), (0xCCB4, 0xCCB4, Break::Lv), (0xCCB5, 0xCCCF, Break::Lvt), (0xCCD0, 0xCCD0, Break::Lv),
+    (0xCCD1, 0xCCEB, Break::Lvt), (0xCCEC, 0xCCEC, Break::Lv), (0xCCED, 0xCD07, Break::Lvt), (0xCD08, 0xCD08, Break::Lv),
+    (0xCD09, 0xCD23, Break::Lvt), (0xCD24, 0xCD24, Break::Lv), (0xCD25, 0xCD3F, Break::Lvt), (0xCD40, 0xCD40, Break::Lv),
+    (0xCD41, 0xCD5B, Break::Lvt), (0xCD5C, 0xCD5C, Break::Lv), (0xCD5D, 0xCD77, Break::Lvt), (0xCD78, 0xCD78, Break::Lv),
+    (0xCD79, 0xCD93, Break::Lvt), (0xCD94, 0xCD94, Break::Lv), (0xCD95, 0xCDAF, Break::Lvt), (0xCDB0, 0xCDB0, Break::Lv),
+    (0xCDB1, 0xCDCB, Break::Lvt), (0xCDCC, 0xCDCC, Break::Lv), (0xCDCD, 0xCDE7, Break::Lvt), (0xCDE8, 0xCDE8, Break::Lv),
+    (0xCDE9, 0xCE03, Break::Lvt), (0xCE04, 0xCE04, Break::Lv), (0xCE05, 0xCE1F, Break::Lvt), (0xCE20, 0xCE20, Break::Lv),
+    (0xCE21, 0xCE3B, Break::Lvt), (0xCE3C, 0xCE3C, Break::Lv), (0xCE3D, 0xCE57, Break::Lvt), (0xCE58, 0xCE58, Break::Lv),
+    (0xCE59, 0xCE73, Break::Lvt), (0xCE74, 0xCE74, Break::Lv), (0xCE75, 0xCE8F, Break::Lvt), (0xCE90, 0xCE90, Break::Lv),
+    (0xCE91, 0xCEAB, Break::Lvt), (0xCEAC, 0xCEAC, Break::Lv), (0xCEAD, 0xCEC7, Break::Lvt), (0xCEC8, 0xCEC8, Break::Lv),
+    (0xCEC9, 0xCEE3, Break::Lvt), (0xCEE4, 0xCEE4, Break::Lv), (0xCEE5, 0xCEFF, Break::Lvt), (0xCF00, 0xCF00, Break::Lv),
+    (0xCF01, 0xCF1B, Break::Lvt), (0xCF1C, 0xCF1C, Break::Lv), (0xCF1D, 0xCF37, Break::Lvt), (0xCF38, 0xCF38, Break::Lv),
+    (0xCF39, 0xCF53, Break::Lvt), (0xCF54, 0xCF54, Break::Lv), (0xCF55, 0xCF6F, Break::Lvt), (0xCF70, 0xCF70, Break::Lv),
+    (0xCF71, 0xCF8B, Break::Lvt), (0xCF8C, 0xCF8C, Break::Lv), (0xCF8D, 0xCFA7, Break::Lvt), (0xCFA8, 0xCFA8, Break::Lv),
+    (0xCFA9, 0xCFC3, Break::Lvt), (0xCFC4, 0xCFC4, Break::Lv), (0xCFC5, 0xCFDF, Break::Lvt), (0xCFE0, 0xCFE0, Break::Lv),
+    (0xCFE1, 0xCFFB, Break::Lvt), (0xCFFC, 0xCFFC, Break::Lv), (0xCFFD, 0xD017, Break::Lvt), (0xD018, 0xD018, Break::Lv),
+    (0xD019, 0xD033, Break::Lvt), (0xD034, 0xD034, Break::Lv), (0xD035, 0xD04F, Break::Lvt), (0xD050, 0xD050, Break::Lv),
+    (0xD051, 0xD06B, Break::Lvt), (0xD06C, 0xD06C, Break::Lv), (0xD06D, 0xD087, Break::Lvt), (0xD088, 0xD088, Break::Lv),
+    (0xD089, 0xD0A3, Break::Lvt), (0xD0A4, 0xD0A4, Break::Lv), (0xD0A5, 0xD0BF, Break::Lvt), (0xD0C0, 0xD0C0, Break::Lv),
+    (0xD0C1, 0xD0DB, Break::Lvt), (0xD0DC, 0xD0DC, Break::Lv), (0xD0DD, 0xD0F7, Break::Lvt), (0xD0F8, 0xD0F8, Break::Lv),
+    (0xD0F9, 0xD113, Break::Lvt), (0xD114, 0xD114, Break::Lv), (0xD115, 0xD12F, Break::Lvt), (0xD130, 0xD130, Break::Lv),
+    (0xD131, 0xD14B, Break::Lvt), (0xD14C, 0xD14C, Break::Lv), (0xD14D, 0xD167, Break::Lvt), (0xD168, 0xD168, Break::Lv),
+    (0xD169, 0xD183, Break::Lvt), (0xD184, 0xD184, Break::Lv), (0xD185, 0xD19F, Break::Lvt), (0xD1A0, 0xD1A0, Break::Lv),
+    (0xD1A1, 0xD1BB, Break::Lvt), (0xD1BC, 0xD1BC, Break::Lv), (0xD1BD, 0xD1D7, Break::Lvt), (0xD1D8, 0xD1D8, Break::Lv),
+    (0xD1D9, 0xD1F3, Break::Lvt), (0xD1F4, 0xD1F4, Break::Lv), (0xD1F5, 0xD20F, Break::Lvt), (0xD210, 0xD210, Break::Lv),
+    (0xD211, 0xD22B, Break::Lvt), (0xD22C, 0xD22C, Break::Lv), (0xD22D, 0xD247, Break::Lvt), (0xD248, 0xD248, Break::Lv),
+    (0xD249, 0xD263, Break::Lvt), (0xD264, 0xD264, Break::Lv), (0xD265, 0xD27F, Break::Lvt), (0xD280, 0xD280, Break::Lv),
+    (0xD281, 0xD29B, Break::Lvt), (0xD29C, 0xD29C, Break::Lv), (0xD29D, 0xD2B7, Break::Lvt), (0xD2B8, 0xD2B8, Break::Lv),
+    (0xD2B9, 0xD2D3, Break::Lvt), (0xD2D4, 0xD2D4, Break::Lv), (0xD2D5, 0xD2EF, Break::Lvt), (0xD2F0, 0xD2F0, Break::Lv),
+    (0xD2F1, 0xD30B, Break::Lvt), (0xD30C, 0xD30C, Break::Lv), (0xD30D, 0xD327, Break::Lvt), (0xD328, 0xD328, Break::Lv),
+    (0xD329, 0xD343, Break::Lvt), (0xD344, 0xD344, Break::Lv), (0xD345, 0xD35F, Break::Lvt), (0xD360, 0xD360, Break::Lv),
+    (0xD361, 0xD37B, Break::Lvt), (0xD37C, 0xD37C, Break::Lv), (0xD37D, 0xD397, Break::Lvt), (0xD398, 0xD398, Break::Lv),
+    (0xD399, 0xD3B3, Break::Lvt), (0xD3B4, 0xD3B4, Break::Lv), (0xD3B5, 0xD3CF, Break::Lvt), (0xD3D0, 0xD3D0, Break::Lv),
+    (0xD3D1, 0xD3EB, Break::Lvt), (0xD3EC, 0xD3EC, Break::Lv), (0xD3ED, 0xD407, Break::Lvt), (0xD408, 0xD408, Break::Lv),
+    (0xD409, 0xD423, Break::Lvt), (0xD424, 0xD424, Break::Lv), (0xD425, 0xD43F, Break::Lvt), (0xD440, 0xD440, Break::Lv),
+    (0xD441, 0xD45B, Break::Lvt), (0xD45C, 0xD45C, Break::Lv), (0xD45D, 0xD477, Break::Lvt), (0xD478, 0xD478, Break::Lv),
+    (0xD479, 0xD493, Break::Lvt), (0xD494, 0xD494, Break::Lv), (0xD495, 0xD4AF, Break::Lvt), (0xD4B0, 0xD4B0, Break::Lv),
+    (0xD4B1, 0xD4CB, Break::Lvt), (0xD4CC, 0xD4CC, Break::Lv), (0xD4CD, 0xD4E7, Break::Lvt), (0xD4E8, 0xD4E8, Break::Lv),
+    (0xD4E9, 0xD503, Break::Lvt), (0xD504, 0xD504, Break::Lv), (0xD505, 0xD51F, Break::Lvt), (0xD520, 0xD520, Break::Lv),
+    (0xD521, 0xD53B, Break::Lvt), (0xD53C, 0xD53C, Break::Lv), (0xD53D, 0xD557, Break::Lvt), (0xD558, 0xD558, Break::Lv),
+    (0xD559, 0xD573, Break::Lvt), (0xD574, 0xD574, Break::Lv), (0xD575, 0xD58F, Break::Lvt), (0xD590, 0xD590, Break::Lv),
+    (0xD591, 0xD5AB, Break::Lvt), (0xD5AC, 0xD5AC, Break::Lv), (0xD5AD, 0xD5C7, Break::Lvt), (0xD5C8, 0xD5C8, Break::Lv),
+    (0xD5C9, 0xD5E3, Break::Lvt), (0xD5E4, 0xD5E4, Break::Lv), (0xD5E5, 0xD5FF, Break::Lvt), (0xD600, 0xD600, Break::Lv),
+    (0xD601, 0xD61B, Break::Lvt), (0xD61C, 0xD61C, Break::Lv), (0xD61D, 0xD637, Break::Lvt), (0xD638, 0xD638, Break::Lv),
+    (0xD639, 0xD653, Break::Lvt), (0xD654, 0xD654, Break::Lv), (0xD655, 0xD66F, Break::Lvt), (0xD670, 0xD670, Break::Lv),
+    (0xD671, 0xD68B, Break::Lvt), (0xD68C, 0xD68C, Break::Lv), (0xD68D, 0xD6A7, Break::Lvt), (0xD6A8, 0xD6A8, Break::Lv),
+    (0xD6A9, 0xD6C3, Break::Lvt), (0xD6C4, 0xD6C4, Break::Lv), (0xD6C5, 0xD6DF, Break::Lvt), (0xD6E0, 0xD6E0, Break::Lv),
+    (0xD6E1, 0xD6FB, Break::Lvt), (0xD6FC, 0xD6FC, Break::Lv), (0xD6FD, 0xD717, Break::Lvt), (0xD718, 0xD718, Break::Lv),
+    (0xD719, 0xD733, Break::Lvt), (0xD734, 0xD734, Break::Lv), (0xD735, 0xD74F, Break::Lvt), (0xD750, 0xD750, Break::Lv),
+    (0xD751, 0xD76B, Break::Lvt), (0xD76C, 0xD76C, Break::Lv), (0xD76D, 0xD787, Break::Lvt), (0xD788, 0xD788, Break::Lv),
+    (0xD789, 0xD7A3, Break::Lvt), (0xD7B0, 0xD7C6, Break::V), (0xD7CB, 0xD7FB, Break::T), (0xFB1E, 0xFB1E, Break::Extend),
+    (0xFE00, 0xFE0F, Break::Extend), (0xFE20, 0xFE2F, Break::Extend), (0xFEFF, 0xFEFF, Break::Control), (0xFF9E, 0xFF9F, Break::Extend),
+    (0xFFF0, 0xFFFB, Break::Control), (0x101FD, 0x101FD, Break::Extend), (0x102E0, 0x102E0, Break::Extend), (0x10376, 0x1037A, Break::Extend),
+    (0x10A01, 0x10A03, Break::Extend), (0x10A05, 0x10A06, Break::Extend), (0x10A0C, 0x10A0F, Break::Extend), (0x10A38, 0x10A3A, Break::Extend),
+    (0x10A3F, 0x10A3F, Break::Extend), (0x10AE5, 0x10AE6, Break::Extend), (0x10D24, 0x10D27, Break::Extend), (0x10EAB, 0x10EAC, Break::Extend),
+    (0x10F46, 0x10F50, Break::Extend), (0x10F82, 0x10F85, Break::Extend), (0x11000, 0x11000, Break::SpacingMark), (0x11001, 0x11001, Break::Extend),
+    (0x11002, 0x11002, Break::SpacingMark), (0x11038, 0x11046, Break::Extend), (0x11070, 0x11070, Break::Extend), (0x11073, 0x11074, Break::Extend),
+    (0x1107F, 0x11081, Break::Extend), (0x11082, 0x11082, Break::SpacingMark), (0x110B0, 0x110B2, Break::SpacingMark), (0x110B3, 0x110B6, Break::Extend),
+    (0x110B7, 0x110B8, Break::SpacingMark), (0x110B9, 0x110BA, Break::Extend), (0x110BD, 0x110BD, Break::Prepend), (0x110C2, 0x110C2, Break::Extend),
+    (0x110CD, 0x110CD, Break::Prepend), (0x11100, 0x11102, Break::Extend), (0x11127, 0x1112B, Break::Extend), (0x1112C, 0x1112C, Break::SpacingMark),
+    (0x1112D, 0x11134, Break::Extend), (0x11145, 0x11146, Break::SpacingMark), (0x11173, 0x11173, Break::Extend), (0x11180, 0x11181, Break::Extend),
+    (0x11182, 0x11182, Break::SpacingMark), (0x111B3, 0x111B5, Break::SpacingMark), (0x111B6, 0x111BE, Break::Extend), (0x111BF, 0x111C0, Break::SpacingMark),
+    (0x111C2, 0x111C3, Break::Prepend), (0x111C9, 0x111CC, Break::Extend), (0x111CE, 0x111CE, Break::SpacingMark), (0x111CF, 0x111CF, Break::Extend),
+    (0x1122C, 0x1122E, Break::SpacingMark), (0x1122F, 0x11231, Break::Extend), (0x11232, 0x11233, Break::SpacingMark), (0x11234, 0x11234, Break::Extend),
+    (0x11235, 0x11235, Break::SpacingMark), (0x11236, 0x11237, Break::Extend), (0x1123E, 0x1123E, Break::Extend), (0x112DF, 0x112DF, Break::Extend),
+    (0x112E0, 0x112E2, Break::SpacingMark), (0x112E3, 0x112EA, Break::Extend), (0x11300, 0x11301, Break::Extend), (0x11302, 0x11303, Break::SpacingMark),
+    (0x1133B, 0x1133C, Break::Extend), (0x1133E, 0x1133E, Break::Extend), (0x1133F, 0x1133F, Break::SpacingMark), (0x11340, 0x11340, Break::Extend),
+    (0x11341, 0x11344, Break::SpacingMark), (0x11347, 0x11348, Break::SpacingMark), (0x1134B, 0x1134D, Break::SpacingMark), (0x11357, 0x11357, Break::Extend),
+    (0x11362, 0x11363, Break::SpacingMark), (0x11366, 0x1136C, Break::Extend), (0x11370, 0x11374, Break::Extend), (0x11435, 0x11437, Break::SpacingMark),
+    (0x11438, 0x1143F, Break::Extend), (0x11440, 0x11441, Break::SpacingMark), (0x11442, 0x11444, Break::Extend), (0x11445, 0x11445, Break::SpacingMark),
+    (0x11446, 0x11446, Break::Extend), (0x1145E, 0x1145E, Break::Extend), (0x114B0, 0x114B0, Break::Extend), (0x114B1, 0x114B2, Break::SpacingMark),
+    (0x114B3, 0x114B8, Break::Extend), (0x114B9, 0x114B9, Break::SpacingMark), (0x114BA, 0x114BA, Break::Extend), (0x114BB, 0x114BC, Break::SpacingMark),
+    (0x114BD, 0x114BD, Break::Extend), (0x114BE, 0x114BE, Break::SpacingMark), (0x114BF, 0x114C0, Break::Extend), (0x114C1, 0x114C1, Break::SpacingMark),
+    (0x114C2, 0x114C3, Break::Extend), (0x115AF, 0x115AF, Break::Extend), (0x115B0, 0x115B1, Break::SpacingMark), (0x115B2, 0x115B5, Break::Extend),
+    (0x115B8, 0x115BB, Break::SpacingMark), (0x115BC, 0x115BD, Break::Extend), (0x115BE, 0x115BE, Break::SpacingMark), (0x115BF, 0x115C0, Break::Extend),
+    (0x115DC, 0x115DD, Break::Extend), (0x11630, 0x11632, Break::SpacingMark), (0x11633, 0x1163A, Break::Extend), (0x1163B, 0x1163C, Break::SpacingMark),
+    (0x1163D, 0x1163D, Break::Extend), (0x1163E, 0x1163E, Break::SpacingMark), (0x1163F, 0x11640, Break::Extend), (0x116AB, 0x116AB, Break::Extend),
+    (0x116AC, 0x116AC, Break::SpacingMark), (0x116AD, 0x116AD, Break::Extend), (0x116AE, 0x116AF, Break::SpacingMark), (0x116B0, 0x116B5, Break::Extend),
+    (0x116B6, 0x116B6, Break::SpacingMark), (0x116B7, 0x116B7, Break::Extend), (0x1171D, 0x1171F, Break::Extend), (0x11722, 0x11725, Break::Extend),
+    (0x11726, 0x11726, Break::SpacingMark), (0x11727, 0x1172B, Break::Extend), (0x1182C, 0x1182E, Break::SpacingMark), (0x1182F, 0x11837, Break::Extend),
+    (0x11838, 0x11838, Break::SpacingMark), (0x11839, 0x1183A, Break::Extend), (0x11930, 0x11930, Break::Extend), (0x11931, 0x11935, Break::SpacingMark),
+    (0x11937, 0x11938, Break::SpacingMark), (0x1193B, 0x1193C, Break::Extend), (0x1193D, 0x1193D, Break::SpacingMark), (0x1193E, 0x1193E, Break::Extend),
+    (0x1193F, 0x1193F, Break::Prepend), (0x11940, 0x11940, Break::SpacingMark), (0x11941, 0x11941, Break::Prepend), (0x11942, 0x11942, Break::SpacingMark),
+    (0x11943, 0x11943, Break::Extend), (0x119D1, 0x119D3, Break::SpacingMark), (0x119D4, 0x119D7, Break::Extend), (0x119DA, 0x119DB, Break::Extend),
+    (0x119DC, 0x119DF, Break::SpacingMark), (0x119E0, 0x119E0, Break::Extend), (0x119E4, 0x119E4, Break::SpacingMark), (0x11A01, 0x11A0A, Break::Extend),
+    (0x11A33, 0x11A38, Break::Extend), (0x11A39, 0x11A39, Break::SpacingMark), (0x11A3A, 0x11A3A, Break::Prepend), (0x11A3B, 0x11A3E, Break::Extend),
+    (0x11A47, 0x11A47, Break::Extend), (0x11A51, 0x11A56, Break::Extend), (0x11A57, 0x11A58, Break::SpacingMark), (0x11A59, 0x11A5B, Break::Extend),
+    (0x11A84, 0x11A89, Break::Prepend), (0x11A8A, 0x11A96, Break::Extend), (0x11A97, 0x11A97, Break::SpacingMark), (0x11A98, 0x11A99, Break::Extend),
+    (0x11C2F, 0x11C2F, Break::SpacingMark), (0x11C30, 0x11C36, Break::Extend), (0x11C38, 0x11C3D, Break::Extend), (0x11C3E, 0x11C3E, Break::SpacingMark),
+    (0x11C3F, 0x11C3F, Break::Extend), (0x11C92, 0x11CA7, Break::Extend), (0x11CA9, 0x11CA9, Break::SpacingMark), (0x11CAA, 0x11CB0, Break::Extend),
+    (0x11CB1, 0x11CB1, Break::SpacingMark), (0x11CB2, 0x11CB3, Break::Extend), (0x11CB4, 0x11CB4, Break::SpacingMark), (0x11CB5, 0x11CB6, Break::Extend),
+    (0x11D31, 0x11D36, Break::Extend), (0x11D3A, 0x11D3A, Break::Extend), (0x11D3C, 0x11D3D, Break::Extend), (0x11D3F, 0x11D45, Break::Extend),
+    (0x11D46, 0x11D46, Break::Prepend), (0x11D47, 0x11D47, Break::Extend), (0x11D8A, 0x11D8E, Break::SpacingMark), (0x11D90, 0x11D91, Break::Extend),
+    (0x11D93, 0x11D94, Break::SpacingMark), (0x11D95, 0x11D95, Break::Extend), (0x11D96, 0x11D96, Break::SpacingMark), (0x11D97, 0x11D97, Break::Extend),
+    (0x11EF3, 0x11EF4, Break::Extend), (0x11EF5, 0x11EF6, Break::SpacingMark), (0x13430, 0x13438, Break::Control), (0x16AF0, 0x16AF4, Break::Extend),
+    (0x16B30, 0x16B36, Break::Extend), (0x16F4F, 0x16F4F, Break::Extend), (0x16F51, 0x16F87, Break::SpacingMark), (0x16F8F, 0x16F92, Break::Extend),
+    (0x16FE4, 0x16FE4, Break::Extend), (0x16FF0, 0x16FF1, Break::SpacingMark), (0x1BC9D, 0x1BC9E, Break::Extend), (0x1BCA0, 0x1BCA3, Break::Control),
+    (0x1CF00, 0x1CF2D, Break::Extend), (0x1CF30, 0x1CF46, Break::Extend), (0x1D165, 0x1D165, Break::Extend), (0x1D166, 0x1D166, Break::SpacingMark),
+    (0x1D167, 0x1D169, Break::Extend), (0x1D16D, 0x1D16D, Break::SpacingMark), (0x1D16E, 0x1D172, Break::Extend), (0x1D173, 0x1D17A, Break::Control),
+    (0x1D17B, 0x1D182, Break::Extend), (0x1D185, 0x1D18B, Break::Extend), (0x1D1AA, 0x1D1AD, Break::Extend), (0x1D242, 0x1D244, Break::Extend),
+    (0x1DA00, 0x1DA36, Break::Extend), (0x1DA3B, 0x1DA6C, Break::Extend), (0x1DA75, 0x1DA75, Break::Extend), (0x1DA84, 0x1DA84, Break::Extend),
+    (0x1DA9B, 0x1DA9F, Break::Extend), (0x1DAA1, 0x1DAAF, Break::Extend), (0x1E000, 0x1E006, Break::Extend), (0x1E008, 0x1E018, Break::Extend),
+    (0x1E01B, 0x1E021, Break::Extend), (0x1E023, 0x1E024, Break::Extend), (0x1E026, 0x1E02A, Break::Extend), (0x1E130, 0x1E136, Break::Extend),
+    (0x1E2AE, 0x1E2AE, Break::Extend), (0x1E2EC, 0x1E2EF, Break::Extend), (0x1E8D0, 0x1E8D6, Break::Extend), (0x1E944, 0x1E94A, Break::Extend),
+    (0x1F1E6, 0x1F1FF, Break::RegionalIndicator), (0x1F3FB, 0x1F3FF, Break::Extend), (0xE0000, 0xE001F, Break::Control), (0xE0020, 0xE007F, Break::Extend),
+    (0xE0080, 0xE00FF, Break::Control), (0xE0100, 0xE01EF, Break::Extend), (0xE01F0, 0xE0FFF, Break::Control)
+];
@@ -4,20 +4,24 @@ use std::{any::{Any, TypeId}, collections::HashMap, process::Command};
 
 use alloc::{string::{String, ToString}, vec::Vec};
 
-use crate::runtime::values::{BooleanVal, NumberVal, StringVal, ValueType};
+use crate::{runtime::values::{BooleanVal, NumberVal, StringVal, ValueType}, unicode::{grapheme_break, is_extended_pictographic, is_xid_continue, is_xid_start, Break}};
 
 pub fn isint(character: String) -> bool {
-    let valid = character.parse::<i32>();
-    match valid {
-        Ok(_) => true,
-        Err(_) => false
-    }
+    character.parse::<i32>().is_ok()
+}
+// Identifiers follow UAX #31: an XID_Start character or an underscore, then XID_Continue characters.
+pub fn isidentstart(character: &str) -> bool {
+    character.chars().next().is_some_and(|ch| ch == '_' || is_xid_start(ch))
 }
-pub fn isalphabet(character: &String) -> bool {
-    character.to_uppercase() != character.to_lowercase() || character == "_"
+pub fn isidentcontinue(character: &str) -> bool {
+    character.chars().next().is_some_and(is_xid_continue)
+}
+pub fn isidentifier(word: &str) -> bool {
+    let mut chars = word.chars().map(|c| c.to_string());
+    chars.next().is_some_and(|first| isidentstart(&first)) && chars.all(|c| isidentcontinue(&c))
 }
 pub fn isempty(character: String) -> bool {
-    character == "" || character == " " || character == "\t" || character == "\n" || character == "\r"
+    character.is_empty() || character == " " || character == "\t" || character == "\n" || character == "\r"
 }
 
 pub fn shift<T>(array: &mut Vec<T>) -> Option<T> {
@@ -30,7 +34,7 @@ pub fn parse_value(value: &dyn Any) -> ValueType {
         typeid if typeid == TypeId::of::<f64>() => {
             ValueType::Number(NumberVal {
                 base: "NumberVal".to_string(),
-                value: value.downcast_ref::<f64>().unwrap_or(&0.0).clone()
+                value: *value.downcast_ref::<f64>().unwrap_or(&0.0)
             })
         }
         typeid if typeid == TypeId::of::<String>() => {
@@ -42,7 +46,7 @@ pub fn parse_value(value: &dyn Any) -> ValueType {
         typeid if typeid == TypeId::of::<bool>() => {
             ValueType::Boolean(BooleanVal {
                 base: "BooleanVal".to_string(),
-                value: value.downcast_ref::<bool>().unwrap_or(&true).clone()
+                value: *value.downcast_ref::<bool>().unwrap_or(&true)
             })
         }
 
@@ -52,6 +56,50 @@ pub fn parse_value(value: &dyn Any) -> ValueType {
     }
 }
 
+// Splits a string into user-perceived characters, the extended grapheme clusters of UAX #29.
+pub fn graphemes(string: &str) -> Vec<String> {
+    let mut clusters: Vec<String> = Vec::new();
+    let mut previous: Option<Break> = None;
+    // An emoji followed by Extend characters ends at the previous character.
+    let mut emoji: bool = false;
+    // The previous character is a zero width joiner right after such an emoji.
+    let mut emoji_zwj: bool = false;
+    // The previous character ends an odd run of regional indicators.
+    let mut regional: bool = false;
+
+    for ch in string.chars() {
+        let class: Break = grapheme_break(ch);
+        let pictographic: bool = is_extended_pictographic(ch);
+
+        match (clusters.last_mut(), previous) {
+            (Some(last), Some(prev)) if grapheme_joins(prev, class, emoji_zwj && pictographic, regional) => last.push(ch),
+            _ => clusters.push(ch.to_string())
+        }
+
+        emoji_zwj = class == Break::Zwj && emoji;
+        emoji = pictographic || (class == Break::Extend && emoji);
+        regional = class == Break::RegionalIndicator && !regional;
+        previous = Some(class);
+    }
+    clusters
+}
+
+// Whether no cluster boundary falls between two characters, rules GB3 to GB999.
+fn grapheme_joins(prev: Break, next: Break, emoji_sequence: bool, regional: bool) -> bool {
+    match (prev, next) {
+        (Break::Cr, Break::Lf) => true,
+        (Break::Cr | Break::Lf | Break::Control, _) | (_, Break::Cr | Break::Lf | Break::Control) => false,
+        (Break::L, Break::L | Break::V | Break::Lv | Break::Lvt) => true,
+        (Break::Lv | Break::V, Break::V | Break::T) => true,
+        (Break::Lvt | Break::T, Break::T) => true,
+        (_, Break::Extend | Break::Zwj | Break::SpacingMark) => true,
+        (Break::Prepend, _) => true,
+        (Break::Zwj, _) => emoji_sequence,
+        (Break::RegionalIndicator, Break::RegionalIndicator) => regional,
+        _ => false
+    }
+}
+
 pub fn clear_terminal() {
     #[cfg(target_os = "windows")]
     {
@@ -63,23 +111,113 @@ pub fn clear_terminal() {
     }
 }
 
-pub fn escape_seq(raw_str: &str) -> String {
+// Resolves the escape sequences of a string literal. On failure the index of the
+// offending backslash is returned together with the error message.
+pub fn escape_seq(raw_str: &str) -> Result<String, (usize, String)> {
     let mut seq_n: String = String::new();
-    let mut chars = raw_str.chars();
-    while let Some(ch) = chars.next() {
+    let mut chars = raw_str.chars().enumerate().peekable();
+    while let Some((indx, ch)) = chars.next() {
         if (ch == '\\')
         {
-            match chars.next() {
+            match chars.next().map(|(_, c)| c) {
                 Some('n') => seq_n.push('\n'),
                 Some('t') => seq_n.push('\t'),
                 Some('r') => seq_n.push('\r'),
+                Some('0') => seq_n.push('\0'),
                 Some('"') => seq_n.push('"'),
+                Some('\'') => seq_n.push('\''),
                 Some('\\') => seq_n.push('\\'),
-                _ => seq_n.push('\\')
+                Some('$') => seq_n.push('$'),
+                Some('x') => {
+                    let digits: String = (0..2).filter_map(|_| chars.next_if(|(_, c)| c.is_ascii_hexdigit()).map(|(_, c)| c)).collect();
+                    match u8::from_str_radix(&digits, 16) {
+                        Ok(code) if digits.len() == 2 && code <= 0x7F => seq_n.push(code as char),
+                        _ => return Err((indx, "Invalid escape sequence, '\\x' expects two hex digits up to 7F".to_string()))
+                    }
+                }
+                Some('u') => {
+                    if (chars.next_if(|(_, c)| *c == '{').is_none()) {
+                        return Err((indx, "Invalid escape sequence, '\\u' expects a code point written as \\u{...}".to_string()));
+                    }
+                    let digits: String = (0..6).filter_map(|_| chars.next_if(|(_, c)| c.is_ascii_hexdigit()).map(|(_, c)| c)).collect();
+                    if (chars.next_if(|(_, c)| *c == '}').is_none() || digits.is_empty()) {
+                        return Err((indx, "Invalid escape sequence, '\\u{...}' expects one to six hex digits".to_string()));
+                    }
+                    match u32::from_str_radix(&digits, 16).ok().and_then(char::from_u32) {
+                        Some(unicode) => seq_n.push(unicode),
+                        None => return Err((indx, format!("Invalid escape sequence, '\\u{{{}}}' is not a unicode scalar value", digits)))
+                    }
+                }
+                Some(other) => return Err((indx, format!("Invalid escape sequence '\\{}'", other))),
+                None => return Err((indx, "Incomplete escape sequence at the end of the string".to_string()))
             }
         } else {
             seq_n.push(ch);
         }
     }
-    seq_n
-}
\ No newline at end of file
+    Ok(seq_n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identifiers() {
+        assert!(isidentifier("_private"));
+        assert!(isidentifier("größe"));
+        assert!(isidentifier("переменная1"));
+        assert!(isidentifier("e\u{301}t\u{e9}"));
+        assert!(isidentifier("नमस्ते"));
+        assert!(!isidentifier("x\u{2081}"));
+        assert!(!isidentifier("x²"));
+        assert!(!isidentifier("1abc"));
+        assert!(!isidentifier("\u{301}abc"));
+        assert!(!isidentifier("a-b"));
+        assert!(!isidentifier("a\u{200D}b"));
+    }
+
+    #[test]
+    fn combining_marks_extend_a_cluster() {
+        assert_eq!(graphemes("e\u{301}a"), vec!["e\u{301}", "a"]);
+        assert_eq!(graphemes("\r\nx"), vec!["\r\n", "x"]);
+    }
+
+    #[test]
+    fn emoji_sequences_are_one_cluster() {
+        assert_eq!(graphemes("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}!"), vec!["\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}", "!"]);
+        assert_eq!(graphemes("\u{1F44D}\u{1F3FD}"), vec!["\u{1F44D}\u{1F3FD}"]);
+        assert_eq!(graphemes("\u{2764}\u{FE0F}"), vec!["\u{2764}\u{FE0F}"]);
+    }
+
+    #[test]
+    fn regional_indicators_pair_up() {
+        assert_eq!(graphemes("\u{1F1EB}\u{1F1F7}\u{1F1E9}\u{1F1EA}"), vec!["\u{1F1EB}\u{1F1F7}", "\u{1F1E9}\u{1F1EA}"]);
+        assert_eq!(graphemes("\u{1F1EB}\u{1F1F7}\u{1F1E9}"), vec!["\u{1F1EB}\u{1F1F7}", "\u{1F1E9}"]);
+    }
+
+    #[test]
+    fn hangul_syllables_are_one_cluster() {
+        assert_eq!(graphemes("\u{1100}\u{1161}\u{11A8}"), vec!["\u{1100}\u{1161}\u{11A8}"]);
+        assert_eq!(graphemes("\u{AC00}\u{11A8}\u{AC01}\u{1161}"), vec!["\u{AC00}\u{11A8}", "\u{AC01}", "\u{1161}"]);
+    }
+
+    #[test]
+    fn spacing_marks_and_prepend_join() {
+        assert_eq!(graphemes("नमस्ते"), vec!["न", "म", "स्", "ते"]);
+        assert_eq!(graphemes("\u{915}\u{93F}"), vec!["\u{915}\u{93F}"]);
+        assert_eq!(graphemes("\u{600}\u{661}a"), vec!["\u{600}\u{661}", "a"]);
+    }
+
+    #[test]
+    fn a_joiner_only_glues_emoji() {
+        assert_eq!(graphemes("a\u{200D}b"), vec!["a\u{200D}", "b"]);
+        assert_eq!(graphemes("\u{2764}\u{FE0F}\u{200D}\u{1F525}"), vec!["\u{2764}\u{FE0F}\u{200D}\u{1F525}"]);
+    }
+
+    #[test]
+    fn controls_always_break() {
+        assert_eq!(graphemes("\n\u{301}"), vec!["\n", "\u{301}"]);
+        assert_eq!(graphemes("\r\r\n"), vec!["\r", "\r\n"]);
+    }
+}
@@ -0,0 +1,30 @@
+// Array literals: spread, trailing commas and malformed elements.
+
+#![allow(unused_parens)]
+
+mod common;
+
+use common::{catching, lines, run_script};
+
+#[test]
+fn spread_and_trailing_commas() {
+    let code: &str = "let a = [1, 2]\nlet s = Set([3, 4])\nprint([...a, ...a], [0, ...a, 9,], [...[], ...s, ...\"hé\"], [\n    1,\n    2,\n], len([...a, ...a]))\nlet copy = [...a]\ncopy[0] = 5\nprint(a, copy)\n";
+    let run = run_script(code, &[]);
+    assert_eq!(run.code, 0, "{}", run.output);
+    assert_eq!(lines(&run), vec!["[1, 2, 1, 2] [0, 1, 2, 9] [3, 4, \"h\", \"é\"] [1, 2] 4", "[1, 2] [5, 2]"]);
+}
+
+#[test]
+fn only_iterables_spread_into_arrays() {
+    let run = run_script(&catching(&["[...5]"]), &[]);
+    assert_eq!(lines(&run).last(), Some(&"Cannot spread a value of type int, expected an array, set or string."));
+}
+
+#[test]
+fn malformed_elements_are_parse_errors() {
+    for (code, message) in [("print([,])\n", "Expected an array element before the comma (line 1, column 8)."), ("print([1 2])\n", "Expected a comma or closing bracket between array elements. (line 1, column 10)")] {
+        let run = run_script(code, &[]);
+        assert_eq!(run.code, 1, "{}", run.output);
+        assert!(run.output.contains(message), "{}", run.output);
+    }
+}
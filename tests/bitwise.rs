@@ -0,0 +1,33 @@
+// Bitwise and shift operators on ints and bigints.
+
+#![allow(unused_parens)]
+
+mod common;
+
+use common::{catching, lines, run_script};
+
+#[test]
+fn negative_ints_and_bigints_use_twos_complement() {
+    let code: &str = "print(-6 & 3, -6 | 3, -6 ^ 3, ~-6, ~0, -16 >> 2, -1 >> 63, -3 << 2, 5 & -1)\nprint(-6n & 3n, -6n | 3n, -6n ^ 3n, ~-6n, -16n >> 2n, 1n << 100n, (-1n << 64n) >> 60n, [-6n & 3])\n";
+    let run = run_script(code, &[]);
+    assert_eq!(run.code, 0, "{}", run.output);
+    assert_eq!(lines(&run), vec!["2 -5 -7 5 -1 -4 -1 -12 5", "2 -5 -7 5 -4 1267650600228229401496703205376 -16 [2n]"]);
+}
+
+#[test]
+fn precedence_sits_between_comparisons_and_addition() {
+    let run = run_script("print(1 | 2 ^ 3 & 4 << 1, 6 & 1 == 0, 1 << 1 + 1)\n", &[]);
+    assert_eq!(run.code, 0, "{}", run.output);
+    assert_eq!(lines(&run), vec!["3 true 4"]);
+}
+
+#[test]
+fn overflowing_and_negative_shifts_raise() {
+    let run = run_script(&catching(&["1 << 63", "1n << -1n", "-1 >> -1"]), &[]);
+    let output = lines(&run);
+    assert_eq!(output[output.len() - 3..], [
+        "Integer overflow while computing 1 << 63.",
+        "Cannot shift by a negative amount (-1).",
+        "Cannot shift by a negative amount (-1)."
+    ]);
+}
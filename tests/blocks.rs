@@ -0,0 +1,36 @@
+// Blocks, and the `{` that opens an object literal or a destructuring pattern instead.
+
+#![allow(unused_parens)]
+
+mod common;
+
+use common::{lines, run_script};
+
+#[test]
+fn a_block_can_assign() {
+    let run = run_script("let x = 1\n{ x = 5 }\nprint(x)\n", &[]);
+    assert_eq!(run.code, 0, "{}", run.output);
+    assert_eq!(lines(&run), vec!["5"]);
+}
+
+#[test]
+fn a_match_arm_can_assign() {
+    let run = run_script("let x = 1\nlet r = match 3 {\n    3 => { x = 2 },\n    _ => 0\n}\nprint(x, r)\n", &[]);
+    assert_eq!(run.code, 0, "{}", run.output);
+    assert_eq!(lines(&run), vec!["2 2"]);
+}
+
+#[test]
+fn blocks_evaluate_to_their_last_statement() {
+    let run = run_script("let t = 10\nlet b = { let t = 2; t * 2 }\nprint(b, t, {})\n", &[]);
+    assert_eq!(run.code, 0, "{}", run.output);
+    assert_eq!(lines(&run), vec!["4 10 {}"]);
+}
+
+#[test]
+fn patterns_keep_their_defaults() {
+    let code = "let { a = 1, b: { c = 2 } = {} } = {}\nlet m = 0\n{ m = 7 } = {}\nfn f({ k = 3 } = {}) {\n    k\n}\nprint(a, c, m, f(), f({ k: 4 }))\n";
+    let run = run_script(code, &[]);
+    assert_eq!(run.code, 0, "{}", run.output);
+    assert_eq!(lines(&run), vec!["1 2 7 3 4"]);
+}
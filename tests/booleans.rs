@@ -0,0 +1,35 @@
+// The true and false literals and how booleans take part in operators.
+
+#![allow(unused_parens)]
+
+mod common;
+
+use common::{catching, lines, run_script};
+
+#[test]
+fn booleans_in_equality_logic_and_printing() {
+    let code: &str = "print(true == true, true == 1, false != null, true && \"x\", false || 0, !true, [true, false], { ok: false })\nprint(true ? \"yes\" : \"no\", str(false) + \"!\", json(true), json({ ok: false, n: null }))\n";
+    let run = run_script(code, &[]);
+    assert_eq!(run.code, 0, "{}", run.output);
+    assert_eq!(lines(&run), vec![
+        "true false true x 0 false [true, false] { ok: false }",
+        "yes false! true {\"ok\":false,\"n\":null}"
+    ]);
+}
+
+#[test]
+fn booleans_are_not_numbers() {
+    let run = run_script(&catching(&["true + 1", "true < false"]), &[]);
+    let output = lines(&run);
+    assert_eq!(output[output.len() - 2..], [
+        "Cannot apply the '+' operator to bool and int.",
+        "Cannot compare bool with bool using '<'."
+    ]);
+}
+
+#[test]
+fn true_and_false_are_keywords() {
+    let run = run_script("let true = 1\n", &[]);
+    assert_eq!(run.code, 1);
+    assert!(run.output.contains("Expected an identifier while building a variable declaration. (line 1, column 5)"), "{}", run.output);
+}
@@ -0,0 +1,57 @@
+// The type checker: `henox check` fails on a problem, a normal run only warns about it.
+
+#![allow(unused_parens)]
+
+mod common;
+
+use common::{henox, lines, run_script, write_files};
+
+#[test]
+fn a_run_warns_and_keeps_going() {
+    let run = run_script("try {\n    let q = undefinedv\n} catch (e) {\n    print(e.kind)\n}\n", &[]);
+    assert_eq!(run.code, 0);
+    assert!(run.output.contains("'undefinedv' is used but never declared."));
+    assert!(run.output.contains("running it anyway."));
+    assert_eq!(lines(&run).last(), Some(&"ReferenceError"));
+}
+
+#[test]
+fn check_fails_on_a_problem() {
+    let dir = write_files(&[("main.va", "let x: int = \"a\"\n")]);
+    let run = henox(&["check", dir.join("main.va").to_str().unwrap()]);
+    assert_eq!(run.code, 1);
+    assert!(run.output.contains("Found 1 problem in"));
+}
+
+#[test]
+fn check_passes_a_clean_file() {
+    let dir = write_files(&[("main.va", "let x: int = 1\nprint(x)\n")]);
+    let run = henox(&["check", dir.join("main.va").to_str().unwrap()]);
+    assert_eq!(run.code, 0);
+    assert!(run.output.contains("No problems found in"));
+    assert!(!run.output.contains("\n1\n"));
+}
+
+#[test]
+fn missing_arguments_that_can_be_null_are_allowed() {
+    let code = "fn f(a, b = 2) {\n    a\n}\nfn g(a: int, b) {\n    a\n}\nfn h(a: int?, ...rest) {\n    a\n}\nprint(f())\nprint(g(1))\nprint(h())\n";
+    let dir = write_files(&[("main.va", code)]);
+    let run = henox(&["check", dir.join("main.va").to_str().unwrap()]);
+    assert_eq!(run.code, 0, "{}", run.output);
+}
+
+#[test]
+fn a_missing_typed_argument_is_reported() {
+    let dir = write_files(&[("main.va", "fn h(a, b: int) {\n    b\n}\nh(1)\n")]);
+    let run = henox(&["check", dir.join("main.va").to_str().unwrap()]);
+    assert_eq!(run.code, 1);
+    assert!(run.output.contains("'h' takes 2 arguments, but is called with 1"));
+}
+
+#[test]
+fn too_many_arguments_are_reported() {
+    let dir = write_files(&[("main.va", "fn f(a, b = 2) {\n    a\n}\nf(1, 2, 3)\n")]);
+    let run = henox(&["check", dir.join("main.va").to_str().unwrap()]);
+    assert_eq!(run.code, 1);
+    assert!(run.output.contains("'f' takes 0 to 2 arguments, but is called with 3"));
+}
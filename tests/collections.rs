@@ -0,0 +1,33 @@
+// Objects, maps and sets: key equality and insertion order.
+
+#![allow(unused_parens)]
+
+mod common;
+
+use common::{catching, lines, run_script};
+
+#[test]
+fn equal_numbers_are_one_key() {
+    let code: &str = "let m = Map([[1, \"int\"], [\"1\", \"str\"]])\nm.set(1.0, \"float\")\nm.set(1n, \"big\")\nm.set(true, \"bool\")\nm.set(null, \"null\")\nprint(m.get(1), m.get(\"1\"), m.get(true), m.get(null), m.size, m.keys())\nlet s = Set([3, 1, \"3\", 2, 3.0])\ns.add(1n)\nprint(s.size, s.has(1), s.has(\"1\"), s.values())\n";
+    let run = run_script(code, &[]);
+    assert_eq!(run.code, 0, "{}", run.output);
+    assert_eq!(lines(&run), vec!["big str bool null 4 [1, \"1\", true, null]", "4 true false [3, 1, \"3\", 2]"]);
+}
+
+#[test]
+fn entries_keep_their_insertion_order() {
+    let code: &str = "let order = Map()\norder.set(\"b\", 1)\norder.set(\"a\", 2)\norder.set(\"b\", 3)\norder.delete(\"a\")\norder.set(\"a\", 4)\nprint(order.keys(), order.values(), order)\nlet o = { z: 1, a: 2 }\no.m = 3\no.z = 4\nprint(o, Set([\"z\", \"a\", \"z\"]))\n";
+    let run = run_script(code, &[]);
+    assert_eq!(run.code, 0, "{}", run.output);
+    assert_eq!(lines(&run), vec!["[\"b\", \"a\"] [3, 4] Map { \"b\": 3, \"a\": 4 }", "{ z: 4, a: 2, m: 3 } Set { \"z\", \"a\" }"]);
+}
+
+#[test]
+fn only_hashable_values_are_keys() {
+    let run = run_script(&catching(&["Map().set([1], 2)", "Set([{}])"]), &[]);
+    let output = lines(&run);
+    assert_eq!(output[output.len() - 2..], [
+        "A value of type arr cannot be used as a key, keys must be numbers, strings, booleans or null.",
+        "A value of type obj cannot be used as a key, keys must be numbers, strings, booleans or null."
+    ]);
+}
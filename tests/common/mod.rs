@@ -0,0 +1,82 @@
+// Helpers for the tests that run the henox binary on scripts.
+
+#![allow(dead_code, unused_parens)]
+
+use std::{path::{Path, PathBuf}, process::Command, sync::atomic::{AtomicUsize, Ordering}};
+
+pub struct Run {
+    // Everything printed, without the colors and times of the logger.
+    pub output: String,
+    pub code: i32
+}
+
+static NEXT: AtomicUsize = AtomicUsize::new(0);
+
+// A fresh directory for the files of one test.
+pub fn scratch() -> PathBuf {
+    let dir: PathBuf = std::env::temp_dir().join(format!("henox-test-{}-{}", std::process::id(), NEXT.fetch_add(1, Ordering::SeqCst)));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+// Writes `files` as (relative path, code) into a scratch directory and returns it.
+pub fn write_files(files: &[(&str, &str)]) -> PathBuf {
+    let dir: PathBuf = scratch();
+    for (name, code) in files {
+        let path: PathBuf = dir.join(name);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, code).unwrap();
+    }
+    dir
+}
+
+pub fn henox(args: &[&str]) -> Run {
+    let output = Command::new(env!("CARGO_BIN_EXE_henox")).args(args).output().unwrap();
+    let text: String = String::from_utf8_lossy(&output.stdout).to_string() + &String::from_utf8_lossy(&output.stderr);
+    Run { output: clean(&text), code: output.status.code().unwrap_or(-1) }
+}
+
+// Runs `code` as the file main.va with `args` after it.
+pub fn run_script(code: &str, args: &[&str]) -> Run {
+    let dir: PathBuf = write_files(&[("main.va", code)]);
+    run_file(&dir.join("main.va"), args)
+}
+
+pub fn run_file(path: &Path, args: &[&str]) -> Run {
+    let path: String = path.display().to_string();
+    henox(&[&[path.as_str()], args].concat())
+}
+
+// Code that runs each expression in its own try and prints the message of what it throws.
+pub fn catching(expressions: &[&str]) -> String {
+    expressions.iter().map(|expression| format!("try {{\n    {}\n}} catch (e) {{\n    print(e.message)\n}}\n", expression)).collect()
+}
+
+// The output lines that are not empty.
+pub fn lines(run: &Run) -> Vec<&str> {
+    run.output.lines().map(str::trim_end).filter(|line| !line.is_empty()).collect()
+}
+
+fn clean(text: &str) -> String {
+    let mut result: String = String::new();
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        // Colors, ESC [ ... m.
+        if (c == '\x1b') {
+            for c in chars.by_ref() {
+                if (c == 'm') { break; }
+            }
+            continue;
+        }
+        // Times, [h:m:s AM] at the start of a log line.
+        if (c == '[' && (result.is_empty() || result.ends_with('\n'))) {
+            let rest: String = chars.clone().take_while(|c| *c != ']').collect();
+            if (rest.ends_with(" AM") || rest.ends_with(" PM")) {
+                chars.by_ref().take(rest.chars().count() + 2).for_each(drop);
+                continue;
+            }
+        }
+        result.push(c);
+    }
+    result
+}
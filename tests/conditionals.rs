@@ -0,0 +1,33 @@
+// The ternary, null-coalescing and optional chaining operators.
+
+#![allow(unused_parens)]
+
+mod common;
+
+use common::{lines, run_script};
+
+const COUNTER: &str = "let calls = 0\nfn hit() {\n    calls = calls + 1\n    \"hit\"\n}\n";
+
+#[test]
+fn optional_chaining_skips_the_rest_of_the_chain() {
+    let code: String = COUNTER.to_string() + "let user = { name: \"Ada\", address: null, greet() { \"hi\" } }\nlet none = null\nprint(none?.name, none?.[hit()], none?.greet(hit()), none?.a.b.c, calls)\nprint(user?.name, user.address?.city, user?.greet?.(), user.missing?.(), calls)\n";
+    let run = run_script(&code, &[]);
+    assert_eq!(run.code, 0, "{}", run.output);
+    assert_eq!(lines(&run), vec!["null null null null 0", "Ada null hi null 0"]);
+}
+
+#[test]
+fn coalescing_falls_back_only_on_null() {
+    let code: String = COUNTER.to_string() + "print(null ?? hit(), 0 ?? hit(), false ?? hit(), \"\" ?? hit(), calls)\n";
+    let run = run_script(&code, &[]);
+    assert_eq!(run.code, 0, "{}", run.output);
+    assert_eq!(lines(&run), vec!["hit 0 false  1"]);
+}
+
+#[test]
+fn the_ternary_evaluates_one_branch() {
+    let code: String = COUNTER.to_string() + "print(true ? \"a\" : hit(), false ? hit() : \"b\", calls)\nprint(1 > 2 ? \"x\" : 2 > 1 ? \"y\" : \"z\")\n";
+    let run = run_script(&code, &[]);
+    assert_eq!(run.code, 0, "{}", run.output);
+    assert_eq!(lines(&run), vec!["a b 0", "y"]);
+}
@@ -0,0 +1,46 @@
+// Destructuring declarations, assignments and parameters.
+
+#![allow(unused_parens)]
+
+mod common;
+
+use common::{catching, lines, run_script};
+
+#[test]
+fn defaults_fill_missing_values_and_rest_takes_the_others() {
+    let code: &str = r#"let [a, b = 2, c = 3, ...rest] = [1, null]
+let { x, y: why = "dy", z = "dz", ...others } = { x: 1, y: null, q: 5, r: 6 }
+print(a, b, c, rest, x, why, z, others)
+let [first, [inner = "in"], { deep = "d" } = {}] = [0, []]
+print(first, inner, deep)
+let m = 1
+let n = 2;
+[m, n] = [n, m]
+print(m, n)
+fn f({ name, age = 30 }, [h, ...t] = [9]) {
+    name + str(age) + str(h) + str(t)
+}
+print(f({ name: "A" }), f({ name: "B", age: 1 }, [1, 2, 3]))
+"#;
+    let run = run_script(code, &[]);
+    assert_eq!(run.code, 0, "{}", run.output);
+    assert_eq!(lines(&run), vec!["1 null 3 [] 1 null dz { q: 5, r: 6 }", "0 in d", "2 1", "A309[] B11[2, 3]"]);
+}
+
+#[test]
+fn mismatched_values_raise() {
+    let run = run_script(&catching(&["let [p, q] = [1]", "let { a } = null", "let [a] = 5"]), &[]);
+    let output = lines(&run);
+    assert_eq!(output[output.len() - 3..], [
+        "Cannot destructure a value for 'q', it is missing and has no default.",
+        "Cannot destructure null with an object pattern, expected an object.",
+        "Cannot destructure int with an array pattern, expected an array."
+    ]);
+}
+
+#[test]
+fn rest_must_come_last() {
+    let run = run_script("let [a, ...r, b] = [1]\n", &[]);
+    assert_eq!(run.code, 1);
+    assert!(run.output.contains("A rest element (...) must be the last element of a destructuring pattern."), "{}", run.output);
+}
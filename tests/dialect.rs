@@ -0,0 +1,56 @@
+// The vapor_script dialect, selected by a `//vapor_script` line at the top of a file.
+
+#![allow(unused_parens)]
+
+mod common;
+
+use common::{lines, run_file, run_script};
+
+#[test]
+fn keywords_are_aliases() {
+    let run = run_script("//vapor_script\nv_const a: int = 1;\nv_let b = 2;\nv_c c = 3;\nb = a + b + c;\nv_log(b);\nv_log(v_num(\"4\") + 1);\n", &[]);
+    assert_eq!(run.code, 0, "{}", run.output);
+    assert_eq!(lines(&run), vec!["6", "5"]);
+}
+
+#[test]
+fn comments_may_come_before_the_pragma() {
+    let run = run_script("# a comment\n\n//vapor_script\nv_let a = 1;\nv_log(a);\n", &[]);
+    assert_eq!(run.code, 0, "{}", run.output);
+    assert_eq!(lines(&run), vec!["1"]);
+}
+
+#[test]
+fn the_pragma_must_come_first() {
+    let run = run_script("let a = 1\n//vapor_script\nv_log(a)\n", &[]);
+    assert_ne!(run.code, 0);
+}
+
+#[test]
+fn switch_runs_one_arm() {
+    let code = "//vapor_script\nfn pick(word) {\n    switch (word) {\n        is(\"Hi\"):\n            v_ret \"Hello\";\n        is(\"Skip\"):\n            v_break;\n            v_ret \"unreachable\";\n        is(ndef):\n            v_ret \"Not defined\";\n    }\n    \"No\"\n}\nv_log(pick(\"Hi\"));\nv_log(pick(\"Skip\"));\nv_log(pick(\"Other\"));\n";
+    let run = run_script(code, &[]);
+    assert_eq!(run.code, 0, "{}", run.output);
+    assert_eq!(lines(&run), vec!["Hello", "No", "Not defined"]);
+}
+
+#[test]
+fn a_switch_without_a_match_does_nothing() {
+    let run = run_script("//vapor_script\nswitch (3) {\n    is(1):\n        v_log(\"one\");\n}\nv_log(\"done\");\n", &[]);
+    assert_eq!(run.code, 0, "{}", run.output);
+    assert_eq!(lines(&run), vec!["done"]);
+}
+
+#[test]
+fn the_entry_point_gets_the_arguments() {
+    let run = run_script("//vapor_script\nv_entry start(args: arr<str>) {\n    v_log(args);\n}\n", &["a", "b"]);
+    assert_eq!(run.code, 0, "{}", run.output);
+    assert_eq!(lines(&run), vec!["[\"a\", \"b\"]"]);
+}
+
+#[test]
+fn helloworld_runs() {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("lang").join("helloworld.va");
+    let run = run_file(&path, &["Vaporazation"]);
+    assert_eq!(lines(&run).first(), Some(&"[\"Vaporazation\"]"));
+}
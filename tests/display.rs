@@ -0,0 +1,32 @@
+// Printing values through the display protocol.
+
+#![allow(unused_parens)]
+
+mod common;
+
+use common::{lines, run_script};
+
+#[test]
+fn to_string_is_used_wherever_a_value_is_printed() {
+    let code: &str = r#"let point = { x: 1, y: 2, toString() { "(" + str(this.x) + ", " + str(this.y) + ")" } }
+print(point, [point, "s"], { p: point })
+print(str(point), "at ${point}")
+let plain = { a: "s", b: [1, null, true] }
+print(plain, str(plain))
+"#;
+    let run = run_script(code, &[]);
+    assert_eq!(run.code, 0, "{}", run.output);
+    assert_eq!(lines(&run), vec![
+        "(1, 2) [(1, 2), \"s\"] { p: (1, 2) }",
+        "(1, 2) at (1, 2)",
+        "{ a: \"s\", b: [1, null, true] } { a: \"s\", b: [1, null, true] }"
+    ]);
+}
+
+#[test]
+fn a_throwing_to_string_can_be_caught() {
+    let code: &str = "let bad = { toString() { throw Error(\"no\", \"ValueError\") } }\ntry {\n    print(bad)\n} catch (e) {\n    print(e.kind, e.message)\n}\n";
+    let run = run_script(code, &[]);
+    assert_eq!(run.code, 0, "{}", run.output);
+    assert_eq!(lines(&run), vec!["ValueError no"]);
+}
@@ -0,0 +1,94 @@
+// Throwing, catching and the stack of runtime errors.
+
+#![allow(unused_parens)]
+
+mod common;
+
+use common::{lines, run_script};
+
+#[test]
+fn finally_runs_last_and_can_override_the_outcome() {
+    let code: &str = r#"fn overrides() {
+    try {
+        return "try"
+    } finally {
+        return "finally"
+    }
+}
+fn keeps() {
+    try {
+        return "try"
+    } finally {
+        print("cleanup")
+    }
+}
+fn swallow() {
+    try {
+        throw Error("lost")
+    } finally {
+        return "recovered"
+    }
+}
+fn replaces() {
+    try {
+        return 1
+    } finally {
+        throw Error("from finally", "CleanupError")
+    }
+}
+print(overrides(), keeps(), swallow())
+try {
+    replaces()
+} catch ({ kind, message }) {
+    print(kind, message)
+}
+try {
+    throw { code: 7 }
+} catch (e) {
+    print(e.code)
+}
+try {
+    throw 5
+} catch {
+    print("no binding")
+}
+try {
+    [1][3]
+} catch (e) {
+    print(e.kind)
+} finally {
+    print("always")
+}
+"#;
+    let run = run_script(code, &[]);
+    assert_eq!(run.code, 0, "{}", run.output);
+    assert_eq!(lines(&run), vec!["cleanup", "finally try recovered", "CleanupError from finally", "7", "no binding", "RangeError", "always"]);
+}
+
+#[test]
+fn an_uncaught_throw_exits_with_1() {
+    let run = run_script("throw Error(\"boom\", \"CustomError\")\nprint(\"never\")\n", &[]);
+    assert_eq!(run.code, 1);
+    assert_eq!(lines(&run), vec!["Uncaught CustomError: boom"]);
+}
+
+const NESTED: &str = "fn inner(a, b) {\n    [1][a]\n}\nfn outer() {\n    inner(5, 2)\n}\n";
+
+#[test]
+fn errors_carry_the_active_calls() {
+    let code: String = NESTED.to_string() + "try {\n    outer()\n} catch (e) {\n    print(e.stack)\n}\nfn deep(n) {\n    deep(n + 1)\n}\ntry {\n    deep(0)\n} catch (e) {\n    print(e.kind, len(e.stack))\n}\n";
+    let run = run_script(&code, &[]);
+    assert_eq!(run.code, 0, "{}", run.output);
+    assert_eq!(lines(&run), vec!["[\"at inner (line 5, column 10, 2 args)\", \"at outer (line 8, column 10, 0 args)\"]", "RangeError 1000"]);
+}
+
+#[test]
+fn an_uncaught_error_prints_its_stack() {
+    let run = run_script(&(NESTED.to_string() + "outer()\n"), &[]);
+    assert_eq!(run.code, 1);
+    assert_eq!(lines(&run), vec![
+        "Uncaught RangeError: Index 5 is out of range, the array has a length of 1.",
+        "    at inner (line 5, column 10, 2 args)",
+        "    at outer (line 7, column 6, 0 args)"
+    ]);
+}
@@ -0,0 +1,63 @@
+// The exit code of `henox file.va`, set by what the entry point returns.
+
+#![allow(unused_parens)]
+
+mod common;
+
+use common::{lines, run_script};
+
+#[test]
+fn the_entry_point_result_is_the_exit_code() {
+    let run = run_script("fn main(args: arr<str>) {\n    print(\"hello\", args)\n    len(args)\n}\n", &["a", "b", "c"]);
+    assert_eq!(run.code, 3, "{}", run.output);
+    assert_eq!(lines(&run), vec!["hello [\"a\", \"b\", \"c\"]"]);
+}
+
+#[test]
+fn a_file_without_an_entry_point_exits_with_0() {
+    let run = run_script("print(1)\n", &[]);
+    assert_eq!(run.code, 0);
+    assert_eq!(lines(&run), vec!["1"]);
+}
+
+#[test]
+fn a_result_that_is_not_a_number_exits_with_0() {
+    let run = run_script("fn main(args) {\n    \"done\"\n}\n", &[]);
+    assert_eq!(run.code, 0, "{}", run.output);
+}
+
+#[test]
+fn the_top_level_runs_before_the_entry_point() {
+    let run = run_script("fn main(args) {\n    print(\"main\")\n    7\n}\nprint(\"top\")\n", &[]);
+    assert_eq!(run.code, 7, "{}", run.output);
+    assert_eq!(lines(&run), vec!["top", "main"]);
+}
+
+#[test]
+fn an_uncaught_error_exits_with_1() {
+    let run = run_script("fn main(args) {\n    throw 5\n}\n", &[]);
+    assert_eq!(run.code, 1);
+    assert!(run.output.contains("Uncaught"), "{}", run.output);
+
+    let run = run_script("let a = [1]\na[3]\n", &[]);
+    assert_eq!(run.code, 1);
+    assert!(run.output.contains("Uncaught RangeError"), "{}", run.output);
+}
+
+#[test]
+fn an_exit_code_must_fit_in_32_bits() {
+    let run = run_script("fn main(args) {\n    4294967296n\n}\n", &[]);
+    assert_eq!(run.code, 1);
+    assert!(run.output.contains("must fit in 32 bits"), "{}", run.output);
+}
+
+#[test]
+fn a_parse_error_exits_with_1() {
+    let run = run_script("let a = [1, , 2]\n", &[]);
+    assert_eq!(run.code, 1);
+    assert!(run.output.contains("Expected an array element before the comma (line 1, column"), "{}", run.output);
+
+    let run = run_script("let o = { a: 1 }\no?.a = 3\n", &[]);
+    assert_eq!(run.code, 1);
+    assert!(run.output.contains("Cannot assign to an optional chain"), "{}", run.output);
+}
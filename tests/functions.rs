@@ -0,0 +1,21 @@
+// Function values.
+
+#![allow(unused_parens)]
+
+mod common;
+
+use common::{lines, run_script};
+
+#[test]
+fn functions_are_compared_by_identity() {
+    let code = "fn make() {\n    fn f() {\n        1\n    }\n    f\n}\nlet a = make()\nlet b = make()\nlet c = a\nprint(a == c, a == b, a != b)\n";
+    let run = run_script(code, &[]);
+    assert_eq!(run.code, 0, "{}", run.output);
+    assert_eq!(lines(&run).last(), Some(&"true false true"));
+}
+
+#[test]
+fn natives_are_equal_to_themselves() {
+    let run = run_script("print(print == print, print == len)\n", &[]);
+    assert_eq!(lines(&run).last(), Some(&"true false"));
+}
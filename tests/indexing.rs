@@ -0,0 +1,41 @@
+// Array and string indexing with negative indices, and slices.
+
+#![allow(unused_parens)]
+
+mod common;
+
+use common::{lines, run_script};
+
+#[test]
+fn negative_indices_count_from_the_end() {
+    let run = run_script("let a = [1, 2, 3, 4]\nprint(a[-1], a[0], \"héllo\"[-4])\n", &[]);
+    assert_eq!(run.code, 0, "{}", run.output);
+    assert_eq!(lines(&run), vec!["4 1 é"]);
+}
+
+#[test]
+fn an_index_out_of_range_raises() {
+    let run = run_script("let a = [1]\ntry {\n    a[-2]\n} catch (e) {\n    print(e.kind)\n}\n", &[]);
+    assert_eq!(lines(&run), vec!["RangeError"]);
+}
+
+#[test]
+fn slices_clamp_their_bounds() {
+    let run = run_script("let a = [1, 2, 3, 4]\nprint(a[1:], a[:2], a[:], a[1:-1], a[3:1], a[-10:10], \"hello\"[1:3])\n", &[]);
+    assert_eq!(run.code, 0, "{}", run.output);
+    assert_eq!(lines(&run), vec!["[2, 3, 4] [1, 2] [1, 2, 3, 4] [2, 3] [] [1, 2, 3, 4] el"]);
+}
+
+#[test]
+fn malformed_slices_are_parse_errors() {
+    for (code, message) in [
+        ("a[::]", "Expected the end of the slice or a closing bracket, a slice is written [start:end] (line 2, column 10)."),
+        ("a[1:2:3]", "Expected a closing bracket after a slice, a slice is written [start:end]. (line 2, column 12)"),
+        ("a[]", "Expected an index or a slice inside the brackets (line 2, column 9).")
+    ] {
+        let run = run_script(&format!("let a = [1, 2, 3]\nprint({})\n", code), &[]);
+        assert_eq!(run.code, 1, "{}", code);
+        assert!(run.output.contains(message), "{}: {}", code, run.output);
+        assert!(!run.output.contains("Unexpected token"), "{}", run.output);
+    }
+}
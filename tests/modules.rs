@@ -0,0 +1,110 @@
+// import and export between files.
+
+#![allow(unused_parens)]
+
+mod common;
+
+use common::{henox, lines, run_file, write_files};
+
+const COUNTER: &str = "let count = 0
+fn helper(n) {
+    n * 10
+}
+export fn scaled(n) {
+    helper(n)
+}
+export fn bump() {
+    count = count + 1
+    count
+}
+export let total = 0
+export fn add(n) {
+    total = total + n
+}
+export fn apply(f, x) {
+    f(x)
+}
+";
+
+fn run_main(main: &str, others: &[(&str, &str)]) -> common::Run {
+    let dir = write_files(&[&[("main.va", main)], others].concat());
+    run_file(&dir.join("main.va"), &[])
+}
+
+#[test]
+fn an_export_can_call_a_private_helper() {
+    let run = run_main("import { scaled } from \"./counter.va\"\nprint(scaled(2))\n", &[("counter.va", COUNTER)]);
+    assert_eq!(run.code, 0, "{}", run.output);
+    assert_eq!(lines(&run).last(), Some(&"20"));
+}
+
+#[test]
+fn an_export_can_change_module_state() {
+    let run = run_main("import { bump } from \"./counter\"\nbump()\nprint(bump())\n", &[("counter.va", COUNTER)]);
+    assert_eq!(run.code, 0, "{}", run.output);
+    assert_eq!(lines(&run), vec!["2"]);
+}
+
+#[test]
+fn imported_names_are_live() {
+    let main = "import { total, add } from \"./counter\"\nimport * as counter from \"./counter\"\nadd(3)\nadd(4)\nprint(total, counter.total)\n";
+    let run = run_main(main, &[("counter.va", COUNTER)]);
+    assert_eq!(run.code, 0, "{}", run.output);
+    assert_eq!(lines(&run).last(), Some(&"7 7"));
+}
+
+#[test]
+fn an_imported_name_cannot_be_assigned() {
+    let main = "import { total } from \"./counter\"\ntry {\n    total = 1\n} catch (e) {\n    print(e.kind)\n}\n";
+    let run = run_main(main, &[("counter.va", COUNTER)]);
+    assert_eq!(lines(&run).last(), Some(&"TypeError"));
+}
+
+#[test]
+fn a_callback_runs_in_its_own_module() {
+    let main = "import { apply } from \"./counter\"\nlet factor = 3\nfn triple(x) {\n    x * factor\n}\nprint(apply(triple, 5))\n";
+    let run = run_main(main, &[("counter.va", COUNTER)]);
+    assert_eq!(run.code, 0, "{}", run.output);
+    assert_eq!(lines(&run).last(), Some(&"15"));
+}
+
+#[test]
+fn private_names_are_not_imported() {
+    let run = run_main("import { helper } from \"./counter\"\n", &[("counter.va", COUNTER)]);
+    assert_eq!(run.code, 1);
+    assert!(run.output.contains("Uncaught ImportError: The module './counter' does not export 'helper'"), "{}", run.output);
+}
+
+#[test]
+fn a_module_runs_once() {
+    let others = [
+        ("lib/log.va", "print(\"loading\")\nexport const name = \"log\"\n"),
+        ("lib/user.va", "import { name } from \"./log\"\nexport fn who() {\n    name\n}\n")
+    ];
+    let run = run_main("import { name } from \"./lib/log.va\"\nimport { who } from \"./lib/user\"\nprint(name, who())\n", &others);
+    assert_eq!(run.code, 0, "{}", run.output);
+    assert_eq!(lines(&run), vec!["loading", "log log"]);
+}
+
+#[test]
+fn cycles_are_reported() {
+    let others = [("a.va", "import { b } from \"./b\"\nexport let a = 1\n"), ("b.va", "import { a } from \"./a\"\nexport let b = 2\n")];
+    let run = run_main("import { a } from \"./a\"\n", &others);
+    assert_eq!(run.code, 1);
+    assert!(run.output.contains("Cyclic import:"), "{}", run.output);
+    assert!(run.output.contains("a.va -> ") && run.output.contains("b.va -> "), "{}", run.output);
+}
+
+#[test]
+fn import_only_works_at_the_top_level() {
+    let run = run_main("fn f() {\n    import { a } from \"./a\"\n}\ntry {\n    f()\n} catch (e) {\n    print(e.kind)\n}\n", &[("a.va", "export let a = 1\n")]);
+    assert_eq!(lines(&run).last(), Some(&"SyntaxError"));
+}
+
+#[test]
+fn imported_modules_are_checked() {
+    let dir = write_files(&[("main.va", "import { f } from \"./bad\"\n"), ("bad.va", "let x: int = \"a\"\nexport fn f() {\n    1\n}\n")]);
+    let run = henox(&["check", dir.join("main.va").to_str().unwrap()]);
+    assert_eq!(run.code, 1);
+    assert!(run.output.contains("bad.va: Expected 'x' to be of type int, got str."), "{}", run.output);
+}
@@ -0,0 +1,46 @@
+// Ints, floats and bigints and the operators between them.
+
+#![allow(unused_parens)]
+
+mod common;
+
+use common::{catching, lines, run_script};
+
+#[test]
+fn ints_stay_ints_until_mixed_with_floats() {
+    let run = run_script("print(2 ** 40, 7 / 2, 1 + 0.5, 2147483647 + 1, 6 / 3)\n", &[]);
+    assert_eq!(run.code, 0, "{}", run.output);
+    assert_eq!(lines(&run), vec!["1099511627776 3.5 1.5 2147483648 2"]);
+}
+
+#[test]
+fn operator_errors_name_both_types() {
+    let run = run_script(&catching(&["1.5 & 2", "1n + 1.5", "1 < \"a\"", "1 - true", "~1.5"]), &[]);
+    let output = lines(&run);
+    assert_eq!(output[output.len() - 5..], [
+        "The '&' operator needs ints or bigints, got float and int.",
+        "Cannot apply the '+' operator to bigint and float, convert one side with bigint() or float() first.",
+        "Cannot compare int with str using '<'.",
+        "Cannot apply the '-' operator to int and bool.",
+        "The '~' operator needs an int or bigint, got float."
+    ]);
+}
+
+#[test]
+fn the_checker_names_both_types() {
+    let run = run_script("let a = 1 - true\n", &[]);
+    assert!(run.output.contains("Cannot apply the '-' operator to int and bool."), "{}", run.output);
+}
+
+#[test]
+fn floor_division_and_powers_with_negative_operands() {
+    let run = run_script("print(-7 // 2, 7 // -2, -7 // -2, -7.5 // 2, -7 % 2, 7 % -2)\nprint(-2 ** 2, (-2) ** 2, 2 ** -1, (-2) ** 3, 2 ** 3 ** 2, -8n // 3n, (-2n) ** 3n)\n", &[]);
+    assert_eq!(run.code, 0, "{}", run.output);
+    assert_eq!(lines(&run), vec!["-4 -4 3 -4 -1 1", "-4 4 0.5 -8 512 -3 -8"]);
+}
+
+#[test]
+fn dividing_by_zero_can_be_caught() {
+    let run = run_script(&catching(&["1 // 0"]), &[]);
+    assert_eq!(lines(&run), vec!["Cannot divide by 0"]);
+}
@@ -0,0 +1,29 @@
+// Object literals: string and computed keys, spread and methods.
+
+#![allow(unused_parens)]
+
+mod common;
+
+use common::{catching, lines, run_script};
+
+#[test]
+fn keys_spread_and_methods() {
+    let code: &str = r#"let k = "dyn"
+let base = { a: 1, b: 2 }
+let o = { ...base, "quoted key": 3, [k + "amic"]: 4, b: 5, greet(name) { "hi " + name + str(this.a) } }
+print(o, o.greet("A"), o["quoted key"], o.dynamic)
+print({ b: 0, ...base }, { ...null, ...{} })
+"#;
+    let run = run_script(code, &[]);
+    assert_eq!(run.code, 0, "{}", run.output);
+    assert_eq!(lines(&run), vec![
+        "{ a: 1, b: 5, \"quoted key\": 3, dynamic: 4, greet: <fn greet(name)> } hi A1 3 4",
+        "{ b: 2, a: 1 } {}"
+    ]);
+}
+
+#[test]
+fn only_objects_spread_into_objects() {
+    let run = run_script(&catching(&["{ ...[1, 2] }"]), &[]);
+    assert_eq!(lines(&run).last(), Some(&"Cannot spread a value of type arr into an object."));
+}
@@ -0,0 +1,32 @@
+// Newlines and semicolons between statements.
+
+#![allow(unused_parens)]
+
+mod common;
+
+use common::{lines, run_script};
+
+#[test]
+fn a_semicolon_lets_the_next_line_start_with_a_bracket() {
+    let code: &str = "let a = [1, 2];\n[a[0], a[1]] = [a[1], a[0]]\nlet c = 1;\n(c) = 5\nprint(a, c)\n";
+    let run = run_script(code, &[]);
+    assert_eq!(run.code, 0, "{}", run.output);
+    assert_eq!(lines(&run), vec!["[2, 1] 5"]);
+}
+
+#[test]
+fn unfinished_lines_continue_and_semicolons_separate() {
+    let code: &str = "let total = 1 +\n    2\nlet o = { n: 4 }\nlet chained = o\n    .n\nlet d = 3; let e = 4; print(d + e)\nprint(total, chained,\n    \"done\")\n";
+    let run = run_script(code, &[]);
+    assert_eq!(run.code, 0, "{}", run.output);
+    assert_eq!(lines(&run), vec!["7", "3 4 done"]);
+}
+
+#[test]
+fn a_line_starting_with_a_bracket_is_ambiguous() {
+    for (code, bracket) in [("let f = print\n(1)\n", '('), ("let a = [1]\n[0]\n", '[')] {
+        let run = run_script(code, &[]);
+        assert_eq!(run.code, 1, "{}", run.output);
+        assert!(run.output.contains(&format!("A line cannot start with '{}' (line 2, column 1), it would continue the expression on the line before.", bracket)), "{}", run.output);
+    }
+}
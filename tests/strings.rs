@@ -0,0 +1,62 @@
+// Strings: literals, escapes, interpolation and unicode.
+
+#![allow(unused_parens)]
+
+mod common;
+
+use common::{lines, run_script};
+
+#[test]
+fn identifiers_use_any_script() {
+    let run = run_script("let नमस्ते = 1\nlet größe = 2\nprint(नमस्ते + größe)\n", &[]);
+    assert_eq!(run.code, 0, "{}", run.output);
+    assert_eq!(lines(&run), vec!["3"]);
+}
+
+#[test]
+fn superscripts_are_not_part_of_a_name() {
+    let run = run_script("let x² = 1\n", &[]);
+    assert_eq!(run.code, 1);
+}
+
+#[test]
+fn graphemes_split_user_perceived_characters() {
+    let run = run_script("print(len(graphemes(\"नमस्ते\")), len(graphemes(\"\\u{1F468}\\u{200D}\\u{1F469}\")), len(graphemes(\"\\u{1100}\\u{1161}\")))\n", &[]);
+    assert_eq!(run.code, 0, "{}", run.output);
+    assert_eq!(lines(&run), vec!["4 1 1"]);
+}
+
+#[test]
+fn interpolation_nests_quotes_and_braces() {
+    let code: &str = r#"let user = { name: "Ada", tags: ["x", "y"] }
+print("hi ${user.name}, ${ { a: 1 }.a + 1 } and ${"quoted ${user.tags[1]}"}")
+print("braces: ${"}"} ${ "{" } \${literal} $name")
+"#;
+    let run = run_script(code, &[]);
+    assert_eq!(run.code, 0, "{}", run.output);
+    assert_eq!(lines(&run), vec!["hi Ada, 2 and quoted y", "braces: } { ${literal} $name"]);
+}
+
+#[test]
+fn raw_and_triple_quoted_strings() {
+    let code: &str = r##"let user = 1
+print(r"raw \n ${user}", r#"has "quotes" inside"#)
+let t = """
+    first "line"
+      indented ${1 + 1}
+    last\tline
+    """
+print(t)
+print(len("\x41\u{1F600}\\\"\'\0"))
+"##;
+    let run = run_script(code, &[]);
+    assert_eq!(run.code, 0, "{}", run.output);
+    assert_eq!(lines(&run), vec!["raw \\n ${user} has \"quotes\" inside", "first \"line\"", "  indented 2", "last\tline", "6"]);
+}
+
+#[test]
+fn unknown_escapes_are_parse_errors() {
+    let run = run_script("print(\"bad \\q\")\n", &[]);
+    assert_eq!(run.code, 1);
+    assert!(run.output.contains("Invalid escape sequence '\\q' (line 1, column"), "{}", run.output);
+}
@@ -0,0 +1,32 @@
+// Type annotations and the type patterns of match.
+
+#![allow(unused_parens)]
+
+mod common;
+
+use common::{lines, run_script};
+
+#[test]
+fn short_and_long_type_names_are_the_same() {
+    let code = "let a: string = \"x\"\nlet b: str = a\nfn kind(v) {\n    match v {\n        string(s) => \"string \" + s,\n        str(s) => \"never\",\n        num(n) => \"number\",\n        _ => \"other\"\n    }\n}\nprint(kind(b), kind(1), kind(true))\n";
+    let run = run_script(code, &[]);
+    assert_eq!(run.code, 0, "{}", run.output);
+    assert_eq!(lines(&run).last(), Some(&"string x number other"));
+}
+
+#[test]
+fn a_mismatch_names_the_short_spelling() {
+    let run = run_script("fn f(a: bool) {\n    a\n}\ntry {\n    f(\"x\")\n} catch (e) {\n    print(e.message)\n}\n", &[]);
+    assert_eq!(lines(&run).last(), Some(&"Expected the parameter 'a' of f to be of type bool, got a value of type str."));
+}
+
+#[test]
+fn runtime_errors_use_the_annotation_names() {
+    let run = run_script("try {\n    bigint({})\n} catch (e) {\n    print(e.message)\n}\ntry {\n    [1, 2][true]\n} catch (e) {\n    print(e.message)\n}\ntry {\n    let x: int = 1.5\n} catch (e) {\n    print(e.message)\n}\n", &[]);
+    let output = lines(&run);
+    assert_eq!(output[output.len() - 3..], [
+        "Cannot convert a value of type obj to a bigint.",
+        "Expected a number while indexing the array, got a value of type bool.",
+        "Expected 'x' to be of type int, got a value of type float."
+    ]);
+}